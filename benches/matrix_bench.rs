@@ -5,7 +5,7 @@ extern crate test;
 
 use std::time::Instant;
 
-use linearalgebra::matrix::Matrix;
+use linearalgebra::matrix::{Matrix, MulAlgorithm};
 use rand::Rng;
 use test::Bencher;
 
@@ -74,6 +74,73 @@ fn matrix_multiplication_384_bench(b: &mut Bencher) {
     b.iter(|| matrix1.product_matrix(&matrix2))
 }
 
+#[bench]
+fn matrix_multiplication_1k_bench(b: &mut Bencher) {
+    let (m1, m2) = setup(1000 * 1000);
+
+    let matrix1 = Matrix::new(1000, 1000, m1).unwrap();
+    let matrix2 = Matrix::new(1000, 1000, m2).unwrap();
+
+    b.iter(|| matrix1.product_matrix(&matrix2))
+}
+
+/**
+ * Runs an n x n product a few times and returns the best rate, counting the
+ * 2n^3 additions and multiplications of the naive algorithm
+ */
+fn gflops(n: usize, product: impl Fn(&Matrix<f64>, &Matrix<f64>)) -> f64 {
+    let (m1, m2) = setup(n * n);
+    let matrix1 = Matrix::new(n, n, m1).unwrap();
+    let matrix2 = Matrix::new(n, n, m2).unwrap();
+
+    let best = (0..3)
+        .map(|_| {
+            let start = Instant::now();
+            product(&matrix1, &matrix2);
+            start.elapsed().as_secs_f64()
+        })
+        .fold(f64::INFINITY, f64::min);
+
+    2.0 * (n * n * n) as f64 / best / 1e9
+}
+
+// Prints a GFLOP/s table before timing the 512 x 512 product. Strassen and
+// Winograd do fewer flops, their rates count 2n^3 like the rest. Run with
+// cargo +nightly bench --features simd -- gflops --nocapture
+#[bench]
+fn matrix_multiplication_gflops_bench(b: &mut Bencher) {
+    println!();
+    for n in [128, 256, 512, 1000, 2000, 4000] {
+        let blocked = gflops(n, |a, b| {
+            a.product_matrix(b).unwrap();
+        });
+        let trivial = if n <= 1000 {
+            #[allow(deprecated)]
+            let rate = gflops(n, |a, b| {
+                a.trivial_product_matrix(b).unwrap();
+            });
+            format!("{:.2}", rate)
+        } else {
+            String::from("-")
+        };
+        let strassen = gflops(n, |a, b| {
+            a.product_matrix_with(b, MulAlgorithm::Strassen).unwrap();
+        });
+        let winograd = gflops(n, |a, b| {
+            a.product_matrix_with(b, MulAlgorithm::Winograd).unwrap();
+        });
+        println!(
+            "{:>5} x {:<5} product_matrix {:>7.2} GFLOP/s, trivial_product_matrix {:>7} GFLOP/s, Strassen {:>7.2} GFLOP/s, Winograd {:>7.2} GFLOP/s",
+            n, n, blocked, trivial, strassen, winograd
+        );
+    }
+
+    let (m1, m2) = setup(512 * 512);
+    let matrix1 = Matrix::new(512, 512, m1).unwrap();
+    let matrix2 = Matrix::new(512, 512, m2).unwrap();
+    b.iter(|| matrix1.product_matrix(&matrix2))
+}
+
 #[test]
 fn big_matrix_multiplication_test() {
     let pre_random = Instant::now();
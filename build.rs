@@ -0,0 +1,13 @@
+use std::env;
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=LINEARALGEBRA_BLAS_LIBS");
+    if env::var_os("CARGO_FEATURE_BLAS").is_none() {
+        return;
+    }
+
+    let libs = env::var("LINEARALGEBRA_BLAS_LIBS").unwrap_or_else(|_| "openblas".to_string());
+    for lib in libs.split(',').map(str::trim).filter(|lib| !lib.is_empty()) {
+        println!("cargo:rustc-link-lib={lib}");
+    }
+}
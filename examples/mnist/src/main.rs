@@ -5,7 +5,8 @@ use std::time::SystemTime;
 use std::{fs::File, io::stdin};
 
 use linearalgebra::vector::Axis;
-use linearalgebra::{matrix::Matrix, vector::Vector};
+use linearalgebra::matrix::{Matrix, Op};
+use linearalgebra::vector::Vector;
 use rand::Rng;
 
 const ITERATIONS: i32 = 100;
@@ -26,13 +27,16 @@ fn main() {
         let (dw_1, db_1, dw_2, db_2) =
             back_prop(&z_1, &a_1, &z_2, &a_2, &weights_2, &x_train, &y_train);
 
-        weights_1 = weights_1.sub(&dw_1.scale(LEARNING_RATE)).unwrap();
-        base_1 = base_1.sub(&db_1.scale(LEARNING_RATE)).unwrap();
-        weights_2 = weights_2.sub(&dw_2.scale(LEARNING_RATE)).unwrap();
-        base_2 = base_2.sub(&db_2.scale(LEARNING_RATE)).unwrap();
+        weights_1 -= dw_1 * LEARNING_RATE;
+        base_1 -= db_1 * LEARNING_RATE;
+        weights_2 -= dw_2 * LEARNING_RATE;
+        base_2 -= db_2 * LEARNING_RATE;
     }
     println!("Took {:?} to train", now.elapsed().unwrap());
 
+    let (_, _, _, a_2) = forward_prop(&weights_1, &base_1, &weights_2, &base_2, &x_test);
+    println!("Accuracy: {:.2}%", get_accuracy(&a_2, &y_test) * 100f64);
+
     let stdin = stdin();
     let mut buffer = String::new();
     let mut rng = rand::thread_rng();
@@ -46,13 +50,8 @@ fn main() {
             &base_2,
             &Matrix::new(1, 784, data.as_vec().to_vec()).unwrap(),
         );
-        let mut max_chance = *a_2
-            .as_vec()
-            .iter()
-            .max_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap();
-        let number = a_2.as_vec().iter().position(|&x| x == max_chance).unwrap();
-        max_chance = max_chance * 100f64;
+        let number = a_2.argmax_axis(Axis::Column).unwrap().as_vec()[0];
+        let max_chance = a_2[(number, 0)] * 100f64;
         println!("Guessed: {number} with {max_chance:.2}%");
         println!("Answer: {}", y_test.as_vec()[i]);
         let _ = stdin.read_line(&mut buffer).unwrap();
@@ -110,38 +109,21 @@ fn forward_prop(
     b_2: &Vector<f64>,
     x: &Matrix<f64>,
 ) -> (Matrix<f64>, Matrix<f64>, Matrix<f64>, Matrix<f64>) {
-    let z_1 = w_1
-        .simd_product_matrix(&x).unwrap()
-        .add(&b_1.expand(x.width(), Axis::Column)).unwrap();
+    let z_1 = w_1.simd_product_matrix(x).unwrap().add_col_broadcast(b_1).unwrap();
 
     let mut a_1 = z_1.clone();
     a_1.apply(|&x| if x > 0f64 { x } else { 0f64 }); // ReLU
 
-    let z_2 = w_2
-        .simd_product_matrix(&a_1).unwrap()
-        .add(&b_2.expand(a_1.width(), Axis::Column)).unwrap();
+    let z_2 = w_2.simd_product_matrix(&a_1).unwrap().add_col_broadcast(b_2).unwrap();
 
     let mut a_2 = z_2.clone();
-    let max = a_2.max();
+    let max = a_2.max().unwrap_or(0f64);
     a_2.apply(|&x| 2.718281f64.powf(x - max)); // Softmax
     let a_2_sum = a_2.sum_columns();
-    let a_2_sum_ref = a_2_sum.as_vec();
-
-    a_2 = Matrix::new(
-        a_2.width(),
-        a_2.height(),
-        a_2.as_vec()
-            .chunks(a_2_sum.width())
-            .map(|c| {
-                c.iter()
-                    .zip(a_2_sum_ref)
-                    .map(|(a, b)| a / b)
-                    .collect::<Vec<f64>>()
-            })
-            .flatten()
-            .collect(),
-    )
-    .unwrap();
+
+    a_2 = a_2
+        .elementwise_div(&a_2_sum.get_row(0).unwrap().expand(a_2.height(), Axis::Row))
+        .unwrap();
 
     (z_1, a_1, z_2, a_2)
 }
@@ -154,6 +136,18 @@ fn one_hot(y: &Vector<i32>) -> Matrix<f64> {
     zeroes.transpose()
 }
 
+fn get_accuracy(a_2: &Matrix<f64>, y: &Vector<i32>) -> f64 {
+    let predictions = a_2.argmax_axis(Axis::Column).unwrap();
+    let correct = predictions
+        .as_vec()
+        .iter()
+        .zip(y.as_vec())
+        .filter(|(&prediction, &label)| prediction as i32 == label)
+        .count();
+
+    correct as f64 / y.len() as f64
+}
+
 fn deriv_re_lu(matrix: &Matrix<f64>) -> Matrix<f64> {
     let mut der = matrix.clone();
     der.apply(|&x| if x > 0f64 { 1f64 } else { 0f64 });
@@ -172,27 +166,20 @@ fn back_prop(
     let m = y.len();
     let one_hot_y = one_hot(y);
 
-    let dz_2 = a_2.sub(&one_hot_y).unwrap();
-    let mut dw_2 = dz_2.simd_product_matrix(&a_1.transpose()).unwrap();
-    dw_2.apply(|&x| x / (m as f64));
+    let dz_2 = a_2 - one_hot_y;
+    // dw_2 = dz_2 a_1^T / m, without copying a_1 into its transpose
+    let mut dw_2 = Matrix::new_of_element(a_1.height(), dz_2.height(), 0f64).unwrap();
+    Matrix::gemm(1f64 / m as f64, &dz_2, Op::NoTranspose, a_1, Op::Transpose, 0f64, &mut dw_2).unwrap();
     let mut db_2 = dz_2.sum_rows();
     db_2.apply(|&x| x / (m as f64));
 
     let deriv_z_1 = deriv_re_lu(z_1);
-    let mut dz_1 = w_2.transpose().simd_product_matrix(&dz_2).unwrap();
-    dz_1 = Matrix::new(
-        dz_1.width(),
-        dz_1.height(),
-        dz_1.as_vec()
-            .iter()
-            .zip(deriv_z_1.as_vec())
-            .map(|(&i1, &i2)| i1 * i2)
-            .collect(),
-    )
-    .unwrap();
-
-    let mut dw_1 = dz_1.simd_product_matrix(&x.transpose()).unwrap();
-    dw_1.apply(|&x| x / (m as f64));
+    let mut dz_1 = Matrix::new_of_element(dz_2.width(), w_2.width(), 0f64).unwrap();
+    Matrix::gemm(1f64, w_2, Op::Transpose, &dz_2, Op::NoTranspose, 0f64, &mut dz_1).unwrap();
+    dz_1 = dz_1.hadamard(&deriv_z_1).unwrap();
+
+    let mut dw_1 = Matrix::new_of_element(x.height(), dz_1.height(), 0f64).unwrap();
+    Matrix::gemm(1f64 / m as f64, &dz_1, Op::NoTranspose, x, Op::Transpose, 0f64, &mut dw_1).unwrap();
     let mut db_1 = dz_1.sum_rows();
     db_1.apply(|&x| x / (m as f64));
 
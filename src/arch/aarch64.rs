@@ -0,0 +1,66 @@
+use std::arch::aarch64::*;
+
+use super::{MR, NR};
+
+/**
+ * vfmaq computes a + b * c, the kernels expect a * b + c
+ */
+#[inline]
+#[target_feature(enable = "neon")]
+unsafe fn mul_add_f64(a: float64x2_t, b: float64x2_t, c: float64x2_t) -> float64x2_t {
+    vfmaq_f64(c, a, b)
+}
+
+#[inline]
+#[target_feature(enable = "neon")]
+unsafe fn mul_add_f32(a: float32x4_t, b: float32x4_t, c: float32x4_t) -> float32x4_t {
+    vfmaq_f32(c, a, b)
+}
+
+pub(crate) mod pd {
+    pub(crate) mod neon {
+        use super::super::*;
+
+        simd_elementwise!(f64, "neon", 2, {
+            load: vld1q_f64,
+            store: vst1q_f64,
+            splat: vdupq_n_f64,
+            add: vaddq_f64,
+            sub: vsubq_f64,
+            mul: vmulq_f64,
+            div: vdivq_f64,
+            fma: mul_add_f64,
+        });
+
+        simd_micro_kernel!(f64, "neon", 2, {
+            load: vld1q_f64,
+            store: vst1q_f64,
+            splat: vdupq_n_f64,
+            fma: mul_add_f64,
+        });
+    }
+}
+
+pub(crate) mod ps {
+    pub(crate) mod neon {
+        use super::super::*;
+
+        simd_elementwise!(f32, "neon", 4, {
+            load: vld1q_f32,
+            store: vst1q_f32,
+            splat: vdupq_n_f32,
+            add: vaddq_f32,
+            sub: vsubq_f32,
+            mul: vmulq_f32,
+            div: vdivq_f32,
+            fma: mul_add_f32,
+        });
+
+        simd_micro_kernel!(f32, "neon", 4, {
+            load: vld1q_f32,
+            store: vst1q_f32,
+            splat: vdupq_n_f32,
+            fma: mul_add_f32,
+        });
+    }
+}
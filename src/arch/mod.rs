@@ -0,0 +1,422 @@
+use std::any::TypeId;
+
+use crate::numlib::{Field, Ring};
+
+/**
+ * Shape of the tile of C the matrix product micro-kernel keeps in registers
+ */
+pub(crate) const MR: usize = 4;
+pub(crate) const NR: usize = 8;
+
+/**
+ * Generates the elementwise kernels of one instruction set for one precision
+ */
+macro_rules! simd_elementwise {
+    ($t:ty, $feature:literal, $lanes:literal, {
+        load: $load:path,
+        store: $store:path,
+        splat: $splat:path,
+        add: $add:path,
+        sub: $sub:path,
+        mul: $mul:path,
+        div: $div:path,
+        fma: $fma:path $(,)?
+    }) => {
+        const LANES: usize = $lanes;
+
+        #[target_feature(enable = $feature)]
+        pub(crate) unsafe fn add_assign(a: &mut [$t], b: &[$t]) {
+            let n = a.len().min(b.len());
+            let end = n - n % LANES;
+            for i in (0..end).step_by(LANES) {
+                let sum = $add($load(a.as_ptr().add(i)), $load(b.as_ptr().add(i)));
+                $store(a.as_mut_ptr().add(i), sum);
+            }
+            for (x, &y) in a[end..n].iter_mut().zip(&b[end..n]) {
+                *x += y;
+            }
+        }
+
+        #[target_feature(enable = $feature)]
+        pub(crate) unsafe fn sub_assign(a: &mut [$t], b: &[$t]) {
+            let n = a.len().min(b.len());
+            let end = n - n % LANES;
+            for i in (0..end).step_by(LANES) {
+                let difference = $sub($load(a.as_ptr().add(i)), $load(b.as_ptr().add(i)));
+                $store(a.as_mut_ptr().add(i), difference);
+            }
+            for (x, &y) in a[end..n].iter_mut().zip(&b[end..n]) {
+                *x -= y;
+            }
+        }
+
+        #[target_feature(enable = $feature)]
+        pub(crate) unsafe fn mul_assign(a: &mut [$t], b: &[$t]) {
+            let n = a.len().min(b.len());
+            let end = n - n % LANES;
+            for i in (0..end).step_by(LANES) {
+                let product = $mul($load(a.as_ptr().add(i)), $load(b.as_ptr().add(i)));
+                $store(a.as_mut_ptr().add(i), product);
+            }
+            for (x, &y) in a[end..n].iter_mut().zip(&b[end..n]) {
+                *x *= y;
+            }
+        }
+
+        #[target_feature(enable = $feature)]
+        pub(crate) unsafe fn div_assign(a: &mut [$t], b: &[$t]) {
+            let n = a.len().min(b.len());
+            let end = n - n % LANES;
+            for i in (0..end).step_by(LANES) {
+                let quotient = $div($load(a.as_ptr().add(i)), $load(b.as_ptr().add(i)));
+                $store(a.as_mut_ptr().add(i), quotient);
+            }
+            for (x, &y) in a[end..n].iter_mut().zip(&b[end..n]) {
+                *x /= y;
+            }
+        }
+
+        #[target_feature(enable = $feature)]
+        pub(crate) unsafe fn scale(a: &mut [$t], scalar: $t) {
+            let n = a.len();
+            let end = n - n % LANES;
+            let factor = $splat(scalar);
+            for i in (0..end).step_by(LANES) {
+                $store(
+                    a.as_mut_ptr().add(i),
+                    $mul($load(a.as_ptr().add(i)), factor),
+                );
+            }
+            for x in &mut a[end..] {
+                *x *= scalar;
+            }
+        }
+
+        #[target_feature(enable = $feature)]
+        pub(crate) unsafe fn dot(a: &[$t], b: &[$t]) -> $t {
+            let n = a.len().min(b.len());
+            let end = n - n % LANES;
+            let mut acc = $splat(0.0);
+            for i in (0..end).step_by(LANES) {
+                acc = $fma($load(a.as_ptr().add(i)), $load(b.as_ptr().add(i)), acc);
+            }
+
+            let mut lanes = [0.0; LANES];
+            $store(lanes.as_mut_ptr(), acc);
+            let tail: $t = a[end..n].iter().zip(&b[end..n]).map(|(&x, &y)| x * y).sum();
+            lanes.iter().sum::<$t>() + tail
+        }
+
+        #[target_feature(enable = $feature)]
+        pub(crate) unsafe fn sum(a: &[$t]) -> $t {
+            let end = a.len() - a.len() % LANES;
+            let mut acc = $splat(0.0);
+            for i in (0..end).step_by(LANES) {
+                acc = $add($load(a.as_ptr().add(i)), acc);
+            }
+
+            let mut lanes = [0.0; LANES];
+            $store(lanes.as_mut_ptr(), acc);
+            lanes.iter().sum::<$t>() + a[end..].iter().sum::<$t>()
+        }
+    };
+}
+
+/**
+ * Generates the MR x NR matrix product micro-kernel, which needs NR to be a
+ * multiple of the number of lanes
+ */
+macro_rules! simd_micro_kernel {
+    ($t:ty, $feature:literal, $lanes:literal, {
+        load: $load:path,
+        store: $store:path,
+        splat: $splat:path,
+        fma: $fma:path $(,)?
+    }) => {
+        const CHUNKS: usize = NR / $lanes;
+
+        /**
+         * tile = A B for an MR x kc sliver of A stored column by column and a
+         * kc x NR sliver of B stored row by row
+         */
+        #[target_feature(enable = $feature)]
+        pub(crate) unsafe fn micro_kernel(a: &[$t], b: &[$t], kc: usize, tile: &mut [$t; MR * NR]) {
+            assert!(a.len() >= kc * MR && b.len() >= kc * NR);
+
+            let mut acc = [[$splat(0.0); CHUNKS]; MR];
+            for p in 0..kc {
+                let mut row = [$splat(0.0); CHUNKS];
+                for (chunk, value) in row.iter_mut().enumerate() {
+                    *value = $load(b.as_ptr().add(p * NR + chunk * $lanes));
+                }
+                for (i, acc) in acc.iter_mut().enumerate() {
+                    let factor = $splat(*a.get_unchecked(p * MR + i));
+                    for (acc, &value) in acc.iter_mut().zip(&row) {
+                        *acc = $fma(factor, value, *acc);
+                    }
+                }
+            }
+
+            for (i, acc) in acc.iter().enumerate() {
+                for (chunk, &value) in acc.iter().enumerate() {
+                    $store(tile.as_mut_ptr().add(i * NR + chunk * $lanes), value);
+                }
+            }
+        }
+    };
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86;
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64;
+
+/**
+ * Calls the kernel of the best instruction set available at runtime
+ */
+macro_rules! dispatch {
+    ($precision:ident, $op:ident($($arg:expr),*)) => {{
+        // The AVX-512 f32 product borrows the AVX2 micro-kernel, so that arm
+        // needs avx2 and fma detected as well to call it soundly
+        #[cfg(target_arch = "x86_64")]
+        return unsafe {
+            if is_x86_feature_detected!("avx512f")
+                && is_x86_feature_detected!("avx2")
+                && is_x86_feature_detected!("fma")
+            {
+                x86::$precision::avx512::$op($($arg),*)
+            } else if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+                x86::$precision::avx2::$op($($arg),*)
+            } else {
+                x86::$precision::sse2::$op($($arg),*)
+            }
+        };
+
+        // NEON is part of every aarch64 target
+        #[cfg(target_arch = "aarch64")]
+        return unsafe { aarch64::$precision::neon::$op($($arg),*) };
+
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        return scalar::$op($($arg),*);
+    }};
+}
+
+macro_rules! float_kernels {
+    ($precision:ident, $t:ty) => {
+        mod $precision {
+            #[allow(unused_imports)]
+            use super::*;
+
+            pub(super) fn add_assign(a: &mut [$t], b: &[$t]) {
+                dispatch!($precision, add_assign(a, b))
+            }
+
+            pub(super) fn sub_assign(a: &mut [$t], b: &[$t]) {
+                dispatch!($precision, sub_assign(a, b))
+            }
+
+            pub(super) fn mul_assign(a: &mut [$t], b: &[$t]) {
+                dispatch!($precision, mul_assign(a, b))
+            }
+
+            pub(super) fn div_assign(a: &mut [$t], b: &[$t]) {
+                dispatch!($precision, div_assign(a, b))
+            }
+
+            pub(super) fn scale(a: &mut [$t], scalar: $t) {
+                dispatch!($precision, scale(a, scalar))
+            }
+
+            pub(super) fn dot(a: &[$t], b: &[$t]) -> $t {
+                dispatch!($precision, dot(a, b))
+            }
+
+            pub(super) fn sum(a: &[$t]) -> $t {
+                dispatch!($precision, sum(a))
+            }
+
+            pub(super) fn micro_kernel(a: &[$t], b: &[$t], kc: usize, tile: &mut [$t; MR * NR]) {
+                dispatch!($precision, micro_kernel(a, b, kc, tile))
+            }
+        }
+    };
+}
+
+float_kernels!(pd, f64);
+float_kernels!(ps, f32);
+
+/**
+ * The portable loops every other type uses
+ */
+mod scalar {
+    use super::{MR, NR};
+    use crate::numlib::{Field, Ring};
+
+    pub(super) fn add_assign<T: Ring>(a: &mut [T], b: &[T]) {
+        for (x, &y) in a.iter_mut().zip(b) {
+            *x = *x + y;
+        }
+    }
+
+    pub(super) fn sub_assign<T: Ring>(a: &mut [T], b: &[T]) {
+        for (x, &y) in a.iter_mut().zip(b) {
+            *x = *x - y;
+        }
+    }
+
+    pub(super) fn mul_assign<T: Ring>(a: &mut [T], b: &[T]) {
+        for (x, &y) in a.iter_mut().zip(b) {
+            *x = *x * y;
+        }
+    }
+
+    pub(super) fn div_assign<T: Field>(a: &mut [T], b: &[T]) {
+        for (x, &y) in a.iter_mut().zip(b) {
+            *x = *x / y;
+        }
+    }
+
+    pub(super) fn scale<T: Ring>(a: &mut [T], scalar: T) {
+        for x in a {
+            *x = *x * scalar;
+        }
+    }
+
+    pub(super) fn dot<T: Ring>(a: &[T], b: &[T]) -> T {
+        a.iter().zip(b).fold(T::zero(), |acc, (&x, &y)| acc + x * y)
+    }
+
+    pub(super) fn dotc<T: Ring>(a: &[T], b: &[T]) -> T {
+        a.iter()
+            .zip(b)
+            .fold(T::zero(), |acc, (&x, &y)| acc + x.conj() * y)
+    }
+
+    pub(super) fn sum<T: Ring>(a: &[T]) -> T {
+        a.iter().fold(T::zero(), |acc, &x| acc + x)
+    }
+
+    pub(super) fn micro_kernel<T: Ring>(a: &[T], b: &[T], kc: usize, tile: &mut [T; MR * NR]) {
+        *tile = [T::zero(); MR * NR];
+        for (a, b) in a.chunks_exact(MR).zip(b.chunks_exact(NR)).take(kc) {
+            for (i, &factor) in a.iter().enumerate() {
+                for (entry, &value) in tile[i * NR..(i + 1) * NR].iter_mut().zip(b) {
+                    *entry = *entry + factor * value;
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn same<T: 'static, U: 'static>() -> bool {
+    TypeId::of::<T>() == TypeId::of::<U>()
+}
+
+/**
+ * Safety: T and U have to be the same type
+ */
+unsafe fn cast<T, U>(slice: &[T]) -> &[U] {
+    &*(slice as *const [T] as *const [U])
+}
+
+/**
+ * Safety: T and U have to be the same type
+ */
+unsafe fn cast_mut<T, U>(slice: &mut [T]) -> &mut [U] {
+    &mut *(slice as *mut [T] as *mut [U])
+}
+
+/**
+ * Safety: T and U have to be the same type
+ */
+pub(crate) unsafe fn cast_value<T, U>(value: T) -> U {
+    std::mem::transmute_copy(&value)
+}
+
+/**
+ * Forwards to the f64 or f32 kernels when T is one of them, and to the scalar
+ * loop otherwise
+ */
+macro_rules! specialize {
+    ($op:ident($($arg:tt)*) -> $t:ty) => {
+        if same::<$t, f64>() {
+            unsafe { specialize!(@cast pd, f64, $op($($arg)*)) }
+        } else if same::<$t, f32>() {
+            unsafe { specialize!(@cast ps, f32, $op($($arg)*)) }
+        } else {
+            specialize!(@plain $op($($arg)*))
+        }
+    };
+    (@cast $precision:ident, $float:ty, $op:ident($($kind:ident $arg:expr),*)) => {
+        cast_value($precision::$op($(specialize!(@arg $kind, $float, $arg)),*))
+    };
+    (@arg slice, $float:ty, $arg:expr) => { cast::<_, $float>($arg) };
+    (@arg slice_mut, $float:ty, $arg:expr) => { cast_mut::<_, $float>($arg) };
+    (@arg value, $float:ty, $arg:expr) => { cast_value::<_, $float>($arg) };
+    (@arg tile, $float:ty, $arg:expr) => { &mut *($arg as *mut [_; MR * NR] as *mut [$float; MR * NR]) };
+    (@arg plain, $float:ty, $arg:expr) => { $arg };
+    (@plain $op:ident($($kind:ident $arg:expr),*)) => {
+        scalar::$op($($arg),*)
+    };
+}
+
+/**
+ * a += b entry by entry, over the shorter of the two
+ */
+pub(crate) fn add_assign<T: Ring>(a: &mut [T], b: &[T]) {
+    specialize!(add_assign(slice_mut a, slice b) -> T)
+}
+
+/**
+ * a -= b entry by entry, over the shorter of the two
+ */
+pub(crate) fn sub_assign<T: Ring>(a: &mut [T], b: &[T]) {
+    specialize!(sub_assign(slice_mut a, slice b) -> T)
+}
+
+/**
+ * a *= b entry by entry, over the shorter of the two
+ */
+pub(crate) fn mul_assign<T: Ring>(a: &mut [T], b: &[T]) {
+    specialize!(mul_assign(slice_mut a, slice b) -> T)
+}
+
+/**
+ * a /= b entry by entry, over the shorter of the two
+ */
+pub(crate) fn div_assign<T: Field>(a: &mut [T], b: &[T]) {
+    specialize!(div_assign(slice_mut a, slice b) -> T)
+}
+
+pub(crate) fn scale<T: Ring>(a: &mut [T], scalar: T) {
+    specialize!(scale(slice_mut a, value scalar) -> T)
+}
+
+pub(crate) fn dot<T: Ring>(a: &[T], b: &[T]) -> T {
+    specialize!(dot(slice a, slice b) -> T)
+}
+
+/**
+ * The sum of the products conjugating a. Conjugating f32 and f64 does nothing,
+ * so they share the kernels of dot.
+ */
+pub(crate) fn dotc<T: Ring>(a: &[T], b: &[T]) -> T {
+    if same::<T, f64>() || same::<T, f32>() {
+        dot(a, b)
+    } else {
+        scalar::dotc(a, b)
+    }
+}
+
+pub(crate) fn sum<T: Ring>(a: &[T]) -> T {
+    specialize!(sum(slice a) -> T)
+}
+
+/**
+ * tile = A B for an MR x kc sliver of A stored column by column and a kc x NR
+ * sliver of B stored row by row
+ */
+pub(crate) fn micro_kernel<T: Ring>(a: &[T], b: &[T], kc: usize, tile: &mut [T; MR * NR]) {
+    specialize!(micro_kernel(slice a, slice b, plain kc, tile tile) -> T)
+}
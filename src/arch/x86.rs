@@ -0,0 +1,152 @@
+use std::arch::x86_64::*;
+
+use super::{MR, NR};
+
+/**
+ * SSE2 has no fused multiply-add, a * b + c in two steps
+ */
+#[inline]
+#[target_feature(enable = "sse2")]
+unsafe fn mul_add_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    _mm_add_pd(_mm_mul_pd(a, b), c)
+}
+
+#[inline]
+#[target_feature(enable = "sse2")]
+unsafe fn mul_add_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    _mm_add_ps(_mm_mul_ps(a, b), c)
+}
+
+pub(crate) mod pd {
+    pub(crate) mod sse2 {
+        use super::super::*;
+
+        simd_elementwise!(f64, "sse2", 2, {
+            load: _mm_loadu_pd,
+            store: _mm_storeu_pd,
+            splat: _mm_set1_pd,
+            add: _mm_add_pd,
+            sub: _mm_sub_pd,
+            mul: _mm_mul_pd,
+            div: _mm_div_pd,
+            fma: mul_add_pd,
+        });
+
+        simd_micro_kernel!(f64, "sse2", 2, {
+            load: _mm_loadu_pd,
+            store: _mm_storeu_pd,
+            splat: _mm_set1_pd,
+            fma: mul_add_pd,
+        });
+    }
+
+    pub(crate) mod avx2 {
+        use super::super::*;
+
+        simd_elementwise!(f64, "avx2,fma", 4, {
+            load: _mm256_loadu_pd,
+            store: _mm256_storeu_pd,
+            splat: _mm256_set1_pd,
+            add: _mm256_add_pd,
+            sub: _mm256_sub_pd,
+            mul: _mm256_mul_pd,
+            div: _mm256_div_pd,
+            fma: _mm256_fmadd_pd,
+        });
+
+        simd_micro_kernel!(f64, "avx2,fma", 4, {
+            load: _mm256_loadu_pd,
+            store: _mm256_storeu_pd,
+            splat: _mm256_set1_pd,
+            fma: _mm256_fmadd_pd,
+        });
+    }
+
+    pub(crate) mod avx512 {
+        use super::super::*;
+
+        simd_elementwise!(f64, "avx512f", 8, {
+            load: _mm512_loadu_pd,
+            store: _mm512_storeu_pd,
+            splat: _mm512_set1_pd,
+            add: _mm512_add_pd,
+            sub: _mm512_sub_pd,
+            mul: _mm512_mul_pd,
+            div: _mm512_div_pd,
+            fma: _mm512_fmadd_pd,
+        });
+
+        simd_micro_kernel!(f64, "avx512f", 8, {
+            load: _mm512_loadu_pd,
+            store: _mm512_storeu_pd,
+            splat: _mm512_set1_pd,
+            fma: _mm512_fmadd_pd,
+        });
+    }
+}
+
+pub(crate) mod ps {
+    pub(crate) mod sse2 {
+        use super::super::*;
+
+        simd_elementwise!(f32, "sse2", 4, {
+            load: _mm_loadu_ps,
+            store: _mm_storeu_ps,
+            splat: _mm_set1_ps,
+            add: _mm_add_ps,
+            sub: _mm_sub_ps,
+            mul: _mm_mul_ps,
+            div: _mm_div_ps,
+            fma: mul_add_ps,
+        });
+
+        simd_micro_kernel!(f32, "sse2", 4, {
+            load: _mm_loadu_ps,
+            store: _mm_storeu_ps,
+            splat: _mm_set1_ps,
+            fma: mul_add_ps,
+        });
+    }
+
+    pub(crate) mod avx2 {
+        use super::super::*;
+
+        simd_elementwise!(f32, "avx2,fma", 8, {
+            load: _mm256_loadu_ps,
+            store: _mm256_storeu_ps,
+            splat: _mm256_set1_ps,
+            add: _mm256_add_ps,
+            sub: _mm256_sub_ps,
+            mul: _mm256_mul_ps,
+            div: _mm256_div_ps,
+            fma: _mm256_fmadd_ps,
+        });
+
+        simd_micro_kernel!(f32, "avx2,fma", 8, {
+            load: _mm256_loadu_ps,
+            store: _mm256_storeu_ps,
+            splat: _mm256_set1_ps,
+            fma: _mm256_fmadd_ps,
+        });
+    }
+
+    pub(crate) mod avx512 {
+        use super::super::*;
+
+        // Sixteen lanes are wider than a row of the tile, so products keep using
+        // the AVX2 micro-kernel. dispatch only comes here once avx2 and fma are
+        // detected too.
+        pub(crate) use super::avx2::micro_kernel;
+
+        simd_elementwise!(f32, "avx512f", 16, {
+            load: _mm512_loadu_ps,
+            store: _mm512_storeu_ps,
+            splat: _mm512_set1_ps,
+            add: _mm512_add_ps,
+            sub: _mm512_sub_ps,
+            mul: _mm512_mul_ps,
+            div: _mm512_div_ps,
+            fma: _mm512_fmadd_ps,
+        });
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt::Display;
+
+/**
+ * The error type returned by every fallible operation in this crate.
+ * Shapes are always reported as (height, width), the same as Matrix::shape.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// A length didn't match, e.g. the data given to a constructor or the inner
+    /// dimension of a product
+    DimensionMismatch { expected: usize, found: usize },
+    /// Two operands were required to have the same shape
+    ShapeMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    /// Two shapes that can't be stretched to a common one, every side has to
+    /// match or be 1
+    NotBroadcastable {
+        lhs: (usize, usize),
+        rhs: (usize, usize),
+    },
+    /// A row, column or element index outside of the valid range
+    IndexOutOfBounds { index: usize, bound: usize },
+    /// The matrix has no inverse
+    Singular,
+    /// The operation is only defined for square matrices
+    NotSquare { shape: (usize, usize) },
+    /// A Cholesky factorization hit a pivot that isn't positive
+    NotPositiveDefinite,
+    /// An iterative algorithm didn't converge within its iteration limit
+    NoConvergence,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::DimensionMismatch { expected, found } => {
+                write!(
+                    f,
+                    "Dimensions don't match: expected {}, found {}",
+                    expected, found
+                )
+            }
+            Error::ShapeMismatch { expected, found } => write!(
+                f,
+                "Shapes don't match: expected {:?}, found {:?}",
+                expected, found
+            ),
+            Error::NotBroadcastable { lhs, rhs } => write!(
+                f,
+                "Shapes {:?} and {:?} can't be broadcast together",
+                lhs, rhs
+            ),
+            Error::IndexOutOfBounds { index, bound } => {
+                write!(f, "Index {} is out of bounds for length {}", index, bound)
+            }
+            Error::Singular => write!(f, "Matrix is singular"),
+            Error::NotSquare { shape } => {
+                write!(f, "Matrix of shape {:?} is not square", shape)
+            }
+            Error::NotPositiveDefinite => write!(f, "Matrix is not positive definite"),
+            Error::NoConvergence => write!(f, "Iteration did not converge"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,63 @@
+mod quaternion;
+mod rotation;
+mod transform;
+
+pub use quaternion::Quaternion;
+pub use transform::{Isometry, Transform};
+
+use crate::{
+    error::Error,
+    numlib::Ring,
+    vector::{SVector, Vector},
+};
+
+// Products of 3D vectors. The fixed-size versions are the real ones, the
+// dynamic ones check for a length of 3 and go through them.
+
+impl<T: Ring> SVector<T, 3> {
+    /**
+     * The vector perpendicular to both with length |a||b|sin(θ), following the
+     * right hand rule
+     */
+    pub fn cross(&self, other: &SVector<T, 3>) -> SVector<T, 3> {
+        let (a, b) = (self, other);
+        SVector::new([
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ])
+    }
+
+    /**
+     * a · (b × c), the signed volume of the parallelepiped the three span and
+     * the determinant of the matrix with them as rows
+     */
+    pub fn scalar_triple(&self, b: &SVector<T, 3>, c: &SVector<T, 3>) -> T {
+        self.hadamard(&b.cross(c)).sum()
+    }
+
+    /**
+     * a × (b × c), which is b(a · c) - c(a · b)
+     */
+    pub fn vector_triple(&self, b: &SVector<T, 3>, c: &SVector<T, 3>) -> SVector<T, 3> {
+        self.cross(&b.cross(c))
+    }
+}
+
+fn three<T: Copy>(vector: &Vector<T>) -> Result<SVector<T, 3>, Error> {
+    SVector::try_from(vector)
+}
+
+impl<T: Ring> Vector<T> {
+    pub fn cross(&self, other: &Vector<T>) -> Result<Vector<T>, Error> {
+        Ok(three(self)?.cross(&three(other)?).into())
+    }
+
+    pub fn scalar_triple(&self, b: &Vector<T>, c: &Vector<T>) -> Result<T, Error> {
+        Ok(three(self)?.scalar_triple(&three(b)?, &three(c)?))
+    }
+
+    pub fn vector_triple(&self, b: &Vector<T>, c: &Vector<T>) -> Result<Vector<T>, Error> {
+        Ok(three(self)?.vector_triple(&three(b)?, &three(c)?).into())
+    }
+}
@@ -0,0 +1,279 @@
+use std::ops::{Mul, Neg};
+
+use crate::{matrix::SMatrix, numlib::RealField, vector::SVector};
+
+/**
+ * A unit quaternion w + xi + yj + zk, standing for a rotation of 3D space.
+ * Every constructor scales to length 1, and q and -q are the same rotation.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion<T> {
+    w: T,
+    x: T,
+    y: T,
+    z: T,
+}
+
+impl<T: RealField> Quaternion<T> {
+    /**
+     * The quaternion scaled to length 1. A zero quaternion has no direction
+     * and gives NaN.
+     */
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        let length = SVector::new([w, x, y, z]).norm_l2();
+        Quaternion {
+            w: w / length,
+            x: x / length,
+            y: y / length,
+            z: z / length,
+        }
+    }
+
+    /**
+     * The rotation that does nothing
+     */
+    pub fn identity() -> Self {
+        Quaternion {
+            w: T::one(),
+            x: T::zero(),
+            y: T::zero(),
+            z: T::zero(),
+        }
+    }
+
+    pub fn from_axis_angle(axis: &SVector<T, 3>, angle: T) -> Self {
+        let axis = axis.normalized();
+        let half = angle / (T::one() + T::one());
+        let sin = half.sin();
+        Quaternion {
+            w: half.cos(),
+            x: axis[0] * sin,
+            y: axis[1] * sin,
+            z: axis[2] * sin,
+        }
+    }
+
+    /**
+     * The same rotation as SMatrix::from_euler_angles
+     */
+    pub fn from_euler_angles(roll: T, pitch: T, yaw: T) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Self::from_axis_angle(&SVector::new([zero, zero, one]), yaw)
+            * Self::from_axis_angle(&SVector::new([zero, one, zero]), pitch)
+            * Self::from_axis_angle(&SVector::new([one, zero, zero]), roll)
+    }
+
+    /**
+     * The quaternion of a rotation matrix by Shepperd's method, which pivots on
+     * the largest of |w|, |x|, |y| and |z| so it stays accurate for every
+     * angle. Each branch is the quaternion times 4 times the pivot, which new
+     * scales away.
+     */
+    pub fn from_rotation_matrix(matrix: &SMatrix<T, 3, 3>) -> Self {
+        let m = |row, col| matrix[(row, col)];
+        let one = T::one();
+        let trace = m(0, 0) + m(1, 1) + m(2, 2);
+
+        if trace > T::zero() {
+            Quaternion::new(
+                one + trace,
+                m(2, 1) - m(1, 2),
+                m(0, 2) - m(2, 0),
+                m(1, 0) - m(0, 1),
+            )
+        } else if m(0, 0) > m(1, 1) && m(0, 0) > m(2, 2) {
+            Quaternion::new(
+                m(2, 1) - m(1, 2),
+                one + m(0, 0) - m(1, 1) - m(2, 2),
+                m(0, 1) + m(1, 0),
+                m(0, 2) + m(2, 0),
+            )
+        } else if m(1, 1) > m(2, 2) {
+            Quaternion::new(
+                m(0, 2) - m(2, 0),
+                m(0, 1) + m(1, 0),
+                one + m(1, 1) - m(0, 0) - m(2, 2),
+                m(1, 2) + m(2, 1),
+            )
+        } else {
+            Quaternion::new(
+                m(1, 0) - m(0, 1),
+                m(0, 2) + m(2, 0),
+                m(1, 2) + m(2, 1),
+                one + m(2, 2) - m(0, 0) - m(1, 1),
+            )
+        }
+    }
+
+    pub fn w(&self) -> T {
+        self.w
+    }
+
+    pub fn x(&self) -> T {
+        self.x
+    }
+
+    pub fn y(&self) -> T {
+        self.y
+    }
+
+    pub fn z(&self) -> T {
+        self.z
+    }
+
+    /**
+     * The imaginary part (x, y, z)
+     */
+    pub fn vector(&self) -> SVector<T, 3> {
+        SVector::new([self.x, self.y, self.z])
+    }
+
+    /**
+     * (w, x, y, z)
+     */
+    pub fn coords(&self) -> SVector<T, 4> {
+        SVector::new([self.w, self.x, self.y, self.z])
+    }
+
+    pub fn to_rotation_matrix(&self) -> SMatrix<T, 3, 3> {
+        let Quaternion { w, x, y, z } = *self;
+        let (one, two) = (T::one(), T::one() + T::one());
+        SMatrix::new([
+            [
+                one - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            [
+                two * (x * y + w * z),
+                one - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                one - two * (x * x + y * y),
+            ],
+        ])
+    }
+
+    /**
+     * The unit axis and the angle in [0, pi] about it. The identity has no
+     * axis of its own and gives the x axis.
+     */
+    pub fn to_axis_angle(&self) -> (SVector<T, 3>, T) {
+        let q = if self.w < T::zero() { -*self } else { *self };
+        let sin = q.vector().norm_l2();
+        if sin == T::zero() {
+            return (SVector::new([T::one(), T::zero(), T::zero()]), T::zero());
+        }
+
+        let angle = sin.atan2(q.w) * (T::one() + T::one());
+        (q.vector() * (T::one() / sin), angle)
+    }
+
+    pub fn to_euler_angles(&self) -> (T, T, T) {
+        self.to_rotation_matrix().to_euler_angles()
+    }
+
+    /**
+     * The rotation back, which for a unit quaternion is the conjugate
+     */
+    pub fn inverse(&self) -> Self {
+        Quaternion {
+            w: self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+
+    pub fn dot(&self, other: &Quaternion<T>) -> T {
+        self.coords().dot(&other.coords())
+    }
+
+    /**
+     * The angle in [0, pi] of the rotation taking self to other
+     */
+    pub fn angle_to(&self, other: &Quaternion<T>) -> T {
+        (self.inverse() * *other).to_axis_angle().1
+    }
+
+    pub fn rotate(&self, vector: &SVector<T, 3>) -> SVector<T, 3> {
+        // v + 2w(u × v) + 2u × (u × v), without building the matrix
+        let u = self.vector();
+        let t = u.cross(vector) * (T::one() + T::one());
+        *vector + t * self.w + u.cross(&t)
+    }
+
+    /**
+     * Spherical linear interpolation, turning at a constant rate along the
+     * shorter arc from self at t = 0 to other at t = 1
+     */
+    pub fn slerp(&self, other: &Quaternion<T>, t: T) -> Self {
+        let one = T::one();
+        let (mut cos, mut end) = (self.dot(other), *other);
+        if cos < T::zero() {
+            cos = -cos;
+            end = -end;
+        }
+
+        // Too close for sin(θ) to be divided by, where a straight line is
+        // just as good
+        let (a, b) = if cos > one - T::epsilon().sqrt() {
+            (one - t, t)
+        } else {
+            let sin = (one - cos * cos).sqrt();
+            let theta = sin.atan2(cos);
+            (((one - t) * theta).sin() / sin, (t * theta).sin() / sin)
+        };
+
+        Quaternion::new(
+            a * self.w + b * end.w,
+            a * self.x + b * end.x,
+            a * self.y + b * end.y,
+            a * self.z + b * end.z,
+        )
+    }
+}
+
+impl<T: RealField> Neg for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    /**
+     * The same rotation the other way around the sphere
+     */
+    fn neg(self) -> Quaternion<T> {
+        Quaternion {
+            w: -self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+/**
+ * The Hamilton product, rotating by rhs first and then by self
+ */
+impl<T: RealField> Mul for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn mul(self, rhs: Quaternion<T>) -> Quaternion<T> {
+        let (a, b) = (self, rhs);
+        Quaternion {
+            w: a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+            x: a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            y: a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            z: a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        }
+    }
+}
+
+impl<T: RealField> Mul<SVector<T, 3>> for Quaternion<T> {
+    type Output = SVector<T, 3>;
+
+    fn mul(self, vector: SVector<T, 3>) -> SVector<T, 3> {
+        self.rotate(&vector)
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{matrix::SMatrix, numlib::RealField, vector::SVector};
+
+// Rotations of 3D space as 3 x 3 matrices, with angles in radians and
+// counterclockwise when looking down the axis at the origin. Euler angles are
+// roll, pitch and yaw about x, y and z, applied in that order to the vector
+// (so R = Rz(yaw) Ry(pitch) Rx(roll)) the way aerospace and ROS use them.
+
+impl<T: RealField> SMatrix<T, 3, 3> {
+    pub fn rotation_x(angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let (zero, one) = (T::zero(), T::one());
+        SMatrix::new([[one, zero, zero], [zero, cos, -sin], [zero, sin, cos]])
+    }
+
+    pub fn rotation_y(angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let (zero, one) = (T::zero(), T::one());
+        SMatrix::new([[cos, zero, sin], [zero, one, zero], [-sin, zero, cos]])
+    }
+
+    pub fn rotation_z(angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let (zero, one) = (T::zero(), T::one());
+        SMatrix::new([[cos, -sin, zero], [sin, cos, zero], [zero, zero, one]])
+    }
+
+    /**
+     * The rotation by angle about axis, by Rodrigues' formula. The axis doesn't
+     * have to be of length 1, but a zero axis has no direction and gives NaN.
+     */
+    pub fn from_axis_angle(axis: &SVector<T, 3>, angle: T) -> Self {
+        let k = axis.normalized();
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let cross = SMatrix::new([
+            [T::zero(), -k[2], k[1]],
+            [k[2], T::zero(), -k[0]],
+            [-k[1], k[0], T::zero()],
+        ]);
+        let outer = SMatrix::from_fn(|row, col| k[row] * k[col]);
+
+        SMatrix::identity() * cos + cross * sin + outer * (T::one() - cos)
+    }
+
+    pub fn from_euler_angles(roll: T, pitch: T, yaw: T) -> Self {
+        Self::rotation_z(yaw) * Self::rotation_y(pitch) * Self::rotation_x(roll)
+    }
+
+    /**
+     * (roll, pitch, yaw) with pitch in [-pi/2, pi/2]. Looking straight up or
+     * down only the difference or sum of roll and yaw is defined, and all of it
+     * goes to yaw.
+     */
+    pub fn to_euler_angles(&self) -> (T, T, T) {
+        let r = self;
+        let cos_pitch = r[(0, 0)].hypot(r[(1, 0)]);
+        let pitch = (-r[(2, 0)]).atan2(cos_pitch);
+        if cos_pitch <= T::epsilon() * T::from_usize(16) {
+            return (T::zero(), pitch, (-r[(0, 1)]).atan2(r[(1, 1)]));
+        }
+
+        (
+            r[(2, 1)].atan2(r[(2, 2)]),
+            pitch,
+            r[(1, 0)].atan2(r[(0, 0)]),
+        )
+    }
+}
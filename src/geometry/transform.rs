@@ -0,0 +1,179 @@
+use std::ops::Mul;
+
+use super::Quaternion;
+use crate::{error::Error, matrix::SMatrix, numlib::RealField, vector::SVector};
+
+// Points and vectors transform differently: a point is a position and moves
+// with the translation, a vector is a direction or difference of points and
+// only turns. In homogeneous coordinates a point gets a 1 as fourth entry and a
+// vector a 0.
+
+/**
+ * A rotation followed by a translation, the rigid motions that keep distances
+ * and handedness, like the pose of a robot link
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Isometry<T> {
+    rotation: Quaternion<T>,
+    translation: SVector<T, 3>,
+}
+
+impl<T: RealField> Isometry<T> {
+    pub fn new(rotation: Quaternion<T>, translation: SVector<T, 3>) -> Self {
+        Isometry {
+            rotation,
+            translation,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Isometry::new(Quaternion::identity(), SVector::zeroes())
+    }
+
+    pub fn from_rotation(rotation: Quaternion<T>) -> Self {
+        Isometry::new(rotation, SVector::zeroes())
+    }
+
+    pub fn from_translation(translation: SVector<T, 3>) -> Self {
+        Isometry::new(Quaternion::identity(), translation)
+    }
+
+    pub fn rotation(&self) -> Quaternion<T> {
+        self.rotation
+    }
+
+    pub fn translation(&self) -> SVector<T, 3> {
+        self.translation
+    }
+
+    pub fn transform_point(&self, point: &SVector<T, 3>) -> SVector<T, 3> {
+        self.rotation.rotate(point) + self.translation
+    }
+
+    pub fn transform_vector(&self, vector: &SVector<T, 3>) -> SVector<T, 3> {
+        self.rotation.rotate(vector)
+    }
+
+    /**
+     * The motion back, which always exists
+     */
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        Isometry::new(rotation, -rotation.rotate(&self.translation))
+    }
+
+    /**
+     * The 4 x 4 matrix with the rotation in the top left and the translation
+     * in the last column
+     */
+    pub fn to_homogeneous(&self) -> SMatrix<T, 4, 4> {
+        let rotation = self.rotation.to_rotation_matrix();
+        SMatrix::from_fn(|row, col| match (row, col) {
+            (3, 3) => T::one(),
+            (3, _) => T::zero(),
+            (_, 3) => self.translation[row],
+            _ => rotation[(row, col)],
+        })
+    }
+}
+
+/**
+ * Applying rhs first and then self
+ */
+impl<T: RealField> Mul for Isometry<T> {
+    type Output = Isometry<T>;
+
+    fn mul(self, rhs: Isometry<T>) -> Isometry<T> {
+        Isometry::new(
+            self.rotation * rhs.rotation,
+            self.transform_point(&rhs.translation),
+        )
+    }
+}
+
+/**
+ * Any 4 x 4 homogeneous transform, which besides isometries takes in scaling,
+ * shearing and projections
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform<T> {
+    matrix: SMatrix<T, 4, 4>,
+}
+
+impl<T: RealField> Transform<T> {
+    pub fn new(matrix: SMatrix<T, 4, 4>) -> Self {
+        Transform { matrix }
+    }
+
+    pub fn identity() -> Self {
+        Transform::new(SMatrix::identity())
+    }
+
+    pub fn from_translation(translation: SVector<T, 3>) -> Self {
+        Isometry::from_translation(translation).into()
+    }
+
+    /**
+     * Scaling every axis by its own factor
+     */
+    pub fn from_scale(scale: SVector<T, 3>) -> Self {
+        Transform::new(SMatrix::from_fn(|row, col| match (row, col) {
+            (3, 3) => T::one(),
+            _ if row == col => scale[row],
+            _ => T::zero(),
+        }))
+    }
+
+    pub fn matrix(&self) -> &SMatrix<T, 4, 4> {
+        &self.matrix
+    }
+
+    pub fn into_matrix(self) -> SMatrix<T, 4, 4> {
+        self.matrix
+    }
+
+    /**
+     * The point with a 1 appended, transformed and divided by its fourth
+     * entry again
+     */
+    pub fn transform_point(&self, point: &SVector<T, 3>) -> SVector<T, 3> {
+        let [x, y, z] = *point.as_array();
+        let res = self.matrix * SVector::new([x, y, z, T::one()]);
+        SVector::from_fn(|i| res[i] / res[3])
+    }
+
+    /**
+     * The vector with a 0 appended, so only the top left 3 x 3 block acts on it
+     */
+    pub fn transform_vector(&self, vector: &SVector<T, 3>) -> SVector<T, 3> {
+        let [x, y, z] = *vector.as_array();
+        let res = self.matrix * SVector::new([x, y, z, T::zero()]);
+        SVector::from_fn(|i| res[i])
+    }
+
+    /**
+     * The transform back through the LU decomposition of the dynamic matrix,
+     * an error if the matrix is singular
+     */
+    pub fn inverse(&self) -> Result<Self, Error> {
+        let inverse = self.matrix.to_matrix().inverse()?;
+        Ok(Transform::new(SMatrix::try_from(&inverse)?))
+    }
+}
+
+impl<T: RealField> From<Isometry<T>> for Transform<T> {
+    fn from(isometry: Isometry<T>) -> Self {
+        Transform::new(isometry.to_homogeneous())
+    }
+}
+
+/**
+ * Applying rhs first and then self
+ */
+impl<T: RealField> Mul for Transform<T> {
+    type Output = Transform<T>;
+
+    fn mul(self, rhs: Transform<T>) -> Transform<T> {
+        Transform::new(self.matrix * rhs.matrix)
+    }
+}
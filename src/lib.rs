@@ -1,6 +1,33 @@
 #![cfg_attr(feature = "simd", feature(portable_simd))]
 
-mod numlib;
+pub mod numlib;
+mod error;
+
+/**
+ * Hand written SIMD kernels for f32 and f64 that build on stable, picking
+ * AVX-512, AVX2 or SSE2 at runtime on x86_64 and NEON on aarch64
+ */
+mod arch;
+
+pub use error::{Error, Result};
+pub use numlib::Complex;
+
+/**
+ * Control over the threads operations run on, and below what size they stay on
+ * one
+ */
+pub mod parallel;
 
 pub mod vector;
-pub mod matrix;
\ No newline at end of file
+pub mod matrix;
+
+/**
+ * Vector and matrix norms, and normalizing vectors
+ */
+pub mod norm;
+
+/**
+ * 3D geometry: cross products, rotations, quaternions and homogeneous
+ * transforms
+ */
+pub mod geometry;
@@ -0,0 +1,397 @@
+use std::borrow::Cow;
+use std::os::raw::{c_char, c_int};
+
+use crate::{
+    arch::{cast_value, same},
+    matrix::MatrixView,
+    numlib::Scalar,
+};
+
+const ROW_MAJOR: c_int = 101;
+const NO_TRANS: c_int = 111;
+const TRANS: c_int = 112;
+
+// CBLAS for the products, the Fortran symbols of LAPACK for the rest. The
+// library providing them is chosen by the build script.
+extern "C" {
+    fn cblas_dgemm(
+        layout: c_int,
+        trans_a: c_int,
+        trans_b: c_int,
+        m: c_int,
+        n: c_int,
+        k: c_int,
+        alpha: f64,
+        a: *const f64,
+        lda: c_int,
+        b: *const f64,
+        ldb: c_int,
+        beta: f64,
+        c: *mut f64,
+        ldc: c_int,
+    );
+    fn cblas_sgemm(
+        layout: c_int,
+        trans_a: c_int,
+        trans_b: c_int,
+        m: c_int,
+        n: c_int,
+        k: c_int,
+        alpha: f32,
+        a: *const f32,
+        lda: c_int,
+        b: *const f32,
+        ldb: c_int,
+        beta: f32,
+        c: *mut f32,
+        ldc: c_int,
+    );
+    fn cblas_dgemv(
+        layout: c_int,
+        trans: c_int,
+        m: c_int,
+        n: c_int,
+        alpha: f64,
+        a: *const f64,
+        lda: c_int,
+        x: *const f64,
+        incx: c_int,
+        beta: f64,
+        y: *mut f64,
+        incy: c_int,
+    );
+    fn cblas_sgemv(
+        layout: c_int,
+        trans: c_int,
+        m: c_int,
+        n: c_int,
+        alpha: f32,
+        a: *const f32,
+        lda: c_int,
+        x: *const f32,
+        incx: c_int,
+        beta: f32,
+        y: *mut f32,
+        incy: c_int,
+    );
+    fn dgetrf_(
+        m: *const c_int,
+        n: *const c_int,
+        a: *mut f64,
+        lda: *const c_int,
+        ipiv: *mut c_int,
+        info: *mut c_int,
+    );
+    fn sgetrf_(
+        m: *const c_int,
+        n: *const c_int,
+        a: *mut f32,
+        lda: *const c_int,
+        ipiv: *mut c_int,
+        info: *mut c_int,
+    );
+    fn dpotrf_(
+        uplo: *const c_char,
+        n: *const c_int,
+        a: *mut f64,
+        lda: *const c_int,
+        info: *mut c_int,
+    );
+    fn spotrf_(
+        uplo: *const c_char,
+        n: *const c_int,
+        a: *mut f32,
+        lda: *const c_int,
+        info: *mut c_int,
+    );
+    fn dgesv_(
+        n: *const c_int,
+        nrhs: *const c_int,
+        a: *mut f64,
+        lda: *const c_int,
+        ipiv: *mut c_int,
+        b: *mut f64,
+        ldb: *const c_int,
+        info: *mut c_int,
+    );
+    fn sgesv_(
+        n: *const c_int,
+        nrhs: *const c_int,
+        a: *mut f32,
+        lda: *const c_int,
+        ipiv: *mut c_int,
+        b: *mut f32,
+        ldb: *const c_int,
+        info: *mut c_int,
+    );
+}
+
+/**
+ * The routines of one precision, so the wrappers below are written once
+ */
+trait Float: Scalar {
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn gemm(
+        trans_a: c_int,
+        trans_b: c_int,
+        dims: [c_int; 3],
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        b: *const Self,
+        ldb: c_int,
+        c: *mut Self,
+        ldc: c_int,
+    );
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn gemv(
+        trans: c_int,
+        m: c_int,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        y: *mut Self,
+    );
+    unsafe fn getrf(n: c_int, a: *mut Self, ipiv: *mut c_int) -> c_int;
+    unsafe fn potrf(uplo: u8, n: c_int, a: *mut Self) -> c_int;
+    unsafe fn gesv(n: c_int, nrhs: c_int, a: *mut Self, ipiv: *mut c_int, b: *mut Self) -> c_int;
+}
+
+macro_rules! float {
+    ($t:ty, $gemm:ident, $gemv:ident, $getrf:ident, $potrf:ident, $gesv:ident) => {
+        impl Float for $t {
+            unsafe fn gemm(
+                trans_a: c_int,
+                trans_b: c_int,
+                [m, n, k]: [c_int; 3],
+                alpha: $t,
+                a: *const $t,
+                lda: c_int,
+                b: *const $t,
+                ldb: c_int,
+                c: *mut $t,
+                ldc: c_int,
+            ) {
+                $gemm(
+                    ROW_MAJOR, trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, 1.0, c, ldc,
+                )
+            }
+
+            unsafe fn gemv(
+                trans: c_int,
+                m: c_int,
+                n: c_int,
+                alpha: $t,
+                a: *const $t,
+                lda: c_int,
+                x: *const $t,
+                y: *mut $t,
+            ) {
+                $gemv(ROW_MAJOR, trans, m, n, alpha, a, lda, x, 1, 1.0, y, 1)
+            }
+
+            unsafe fn getrf(n: c_int, a: *mut $t, ipiv: *mut c_int) -> c_int {
+                let mut info = 0;
+                $getrf(&n, &n, a, &n, ipiv, &mut info);
+                info
+            }
+
+            unsafe fn potrf(uplo: u8, n: c_int, a: *mut $t) -> c_int {
+                let mut info = 0;
+                $potrf(&(uplo as c_char), &n, a, &n, &mut info);
+                info
+            }
+
+            unsafe fn gesv(
+                n: c_int,
+                nrhs: c_int,
+                a: *mut $t,
+                ipiv: *mut c_int,
+                b: *mut $t,
+            ) -> c_int {
+                let mut info = 0;
+                $gesv(&n, &nrhs, a, &n, ipiv, b, &n, &mut info);
+                info
+            }
+        }
+    };
+}
+
+float!(f64, cblas_dgemm, cblas_dgemv, dgetrf_, dpotrf_, dgesv_);
+float!(f32, cblas_sgemm, cblas_sgemv, sgetrf_, spotrf_, sgesv_);
+
+/**
+ * Calls the f64 or f32 version of a wrapper when T is one of them, every other
+ * type gets None and stays on the pure Rust path
+ */
+macro_rules! specialize {
+    ($t:ty, $f:ident($($arg:expr),*)) => {
+        if same::<$t, f64>() {
+            unsafe { $f::<f64>($(cast_value($arg)),*) }
+        } else if same::<$t, f32>() {
+            unsafe { $f::<f32>($(cast_value($arg)),*) }
+        } else {
+            None
+        }
+    };
+}
+
+fn int(n: usize) -> Option<c_int> {
+    c_int::try_from(n).ok()
+}
+
+/**
+ * The data of a view as BLAS reads a row-major operand, with its transpose flag
+ * and leading dimension. Strides BLAS can't describe are copied out first.
+ */
+fn operand<'a, T: Scalar>(view: &MatrixView<'a, T>) -> Option<(Cow<'a, [T]>, c_int, c_int)> {
+    if view.col_stride == 1 && view.row_stride >= view.width {
+        Some((Cow::Borrowed(view.data), NO_TRANS, int(view.row_stride)?))
+    } else if view.row_stride == 1 && view.col_stride >= view.height {
+        Some((Cow::Borrowed(view.data), TRANS, int(view.col_stride)?))
+    } else {
+        Some((view.row_major(), NO_TRANS, int(view.width)?))
+    }
+}
+
+/**
+ * C += alpha AB where C is stored row by row with ldc entries between rows
+ */
+pub(crate) fn gemm<T: Scalar>(
+    alpha: T,
+    a: MatrixView<'_, T>,
+    b: MatrixView<'_, T>,
+    c: &mut [T],
+    ldc: usize,
+) -> Option<()> {
+    fn gemm<F: Float>(
+        alpha: F,
+        a: MatrixView<'_, F>,
+        b: MatrixView<'_, F>,
+        c: &mut [F],
+        ldc: usize,
+    ) -> Option<()> {
+        let dims = [int(a.height)?, int(b.width)?, int(a.width)?];
+        let (a, trans_a, lda) = operand(&a)?;
+        let (b, trans_b, ldb) = operand(&b)?;
+        let ldc = int(ldc)?;
+        unsafe {
+            F::gemm(
+                trans_a,
+                trans_b,
+                dims,
+                alpha,
+                a.as_ptr(),
+                lda,
+                b.as_ptr(),
+                ldb,
+                c.as_mut_ptr(),
+                ldc,
+            )
+        };
+
+        Some(())
+    }
+
+    if a.height == 0 || b.width == 0 || a.width == 0 {
+        return None;
+    }
+    specialize!(T, gemm(alpha, a, b, c, ldc))
+}
+
+/**
+ * y += alpha Ax
+ */
+pub(crate) fn gemv<T: Scalar>(alpha: T, a: MatrixView<'_, T>, x: &[T], y: &mut [T]) -> Option<()> {
+    fn gemv<F: Float>(alpha: F, a: MatrixView<'_, F>, x: &[F], y: &mut [F]) -> Option<()> {
+        let (m, n) = (int(a.height)?, int(a.width)?);
+        let (data, trans, lda) = operand(&a)?;
+        // A transposed operand is stored n x m, which is how gemv wants its shape
+        let (m, n) = if trans == TRANS { (n, m) } else { (m, n) };
+        unsafe {
+            F::gemv(
+                trans,
+                m,
+                n,
+                alpha,
+                data.as_ptr(),
+                lda,
+                x.as_ptr(),
+                y.as_mut_ptr(),
+            )
+        };
+
+        Some(())
+    }
+
+    if a.height == 0 || a.width == 0 {
+        return None;
+    }
+    specialize!(T, gemv(alpha, a, x, y))
+}
+
+/**
+ * LU factorization with partial pivoting of the n x n matrix a, stored column
+ * by column. Row k was swapped with row pivots[k] - 1. The info of LAPACK is
+ * returned, positive when a pivot is exactly zero.
+ */
+pub(crate) fn getrf<T: Scalar>(n: usize, a: &mut [T], pivots: &mut [c_int]) -> Option<c_int> {
+    fn getrf<F: Float>(n: usize, a: &mut [F], pivots: &mut [c_int]) -> Option<c_int> {
+        let n = int(n)?;
+        Some(unsafe { F::getrf(n, a.as_mut_ptr(), pivots.as_mut_ptr()) })
+    }
+
+    if n == 0 {
+        return None;
+    }
+    specialize!(T, getrf(n, a, pivots))
+}
+
+/**
+ * Cholesky factorization of the n x n matrix a, stored row by row. Afterwards its
+ * lower triangle holds L, the strict upper triangle is left untouched. Nonzero
+ * when a is not positive definite.
+ */
+pub(crate) fn cholesky<T: Scalar>(n: usize, a: &mut [T]) -> Option<c_int> {
+    fn cholesky<F: Float>(n: usize, a: &mut [F]) -> Option<c_int> {
+        let n = int(n)?;
+        // Read column by column the rows of A are the columns of A^T, so its
+        // upper triangle is our lower one and U^T U comes out as L L^T
+        Some(unsafe { F::potrf(b'U', n, a.as_mut_ptr()) })
+    }
+
+    if n == 0 {
+        return None;
+    }
+    specialize!(T, cholesky(n, a))
+}
+
+/**
+ * Solves AX = B, both stored column by column, overwriting A with its LU
+ * factorization and B with X. Positive when a pivot is exactly zero.
+ */
+pub(crate) fn gesv<T: Scalar>(
+    n: usize,
+    nrhs: usize,
+    a: &mut [T],
+    pivots: &mut [c_int],
+    b: &mut [T],
+) -> Option<c_int> {
+    fn gesv<F: Float>(
+        n: usize,
+        nrhs: usize,
+        a: &mut [F],
+        pivots: &mut [c_int],
+        b: &mut [F],
+    ) -> Option<c_int> {
+        let (n, nrhs) = (int(n)?, int(nrhs)?);
+        Some(unsafe { F::gesv(n, nrhs, a.as_mut_ptr(), pivots.as_mut_ptr(), b.as_mut_ptr()) })
+    }
+
+    if n == 0 || nrhs == 0 {
+        return None;
+    }
+    specialize!(T, gesv(n, nrhs, a, pivots, b))
+}
@@ -0,0 +1,166 @@
+use std::cmp::Ordering;
+
+#[cfg(feature = "blas")]
+use crate::matrix::{blas, Layout};
+use crate::{error::Error, matrix::Matrix, numlib::{ComplexField, RealField, Zero}, parallel, vector::Vector};
+
+/**
+ * Cholesky decomposition of a Hermitian positive definite matrix, A = LL^H,
+ * which is A = LL^T for real matrices. Only the lower triangle of A is read.
+ */
+#[derive(Debug, Clone)]
+pub struct Cholesky<T> {
+    l: Matrix<T>,
+}
+
+impl<T: ComplexField> Cholesky<T> {
+    pub fn new(matrix: &Matrix<T>) -> Result<Cholesky<T>, Error> {
+        if matrix.width != matrix.height {
+            return Err(Error::NotSquare {
+                shape: matrix.shape(),
+            });
+        }
+
+        #[cfg(feature = "blas")]
+        if let Some(cholesky) = Cholesky::lapack(matrix) {
+            return cholesky;
+        }
+
+        let n = matrix.height;
+        let mut l = Matrix::new_of_element(n, n, T::zero()).unwrap();
+        for col in 0..n {
+            // The diagonal of a Hermitian matrix is real
+            let mut diagonal = matrix[(col, col)].re();
+            for k in 0..col {
+                diagonal = diagonal - l[(col, k)].norm_sqr();
+            }
+            // Also catches NaN, which isn't ordered
+            if diagonal.partial_cmp(&T::Real::zero()) != Some(Ordering::Greater) {
+                return Err(Error::NotPositiveDefinite);
+            }
+            let diagonal = T::from_real(diagonal.sqrt());
+            l[(col, col)] = diagonal;
+
+            // Every row below the diagonal only reads itself and row col
+            let (upper, lower) = l.data.split_at_mut((col + 1) * n);
+            let col_row = &upper[col * n..];
+            parallel::run((n - col - 1) * col, |parallel| {
+                parallel::for_each_chunk(parallel, lower, n, |index, row| {
+                    let mut value = matrix[(col + 1 + index, col)];
+                    for (&x, &y) in row[..col].iter().zip(&col_row[..col]) {
+                        value = value - x * y.conj();
+                    }
+                    row[col] = value / diagonal;
+                })
+            });
+        }
+
+        Ok(Cholesky { l })
+    }
+
+    /**
+     * The same factorization by potrf
+     */
+    #[cfg(feature = "blas")]
+    fn lapack(matrix: &Matrix<T>) -> Option<Result<Cholesky<T>, Error>> {
+        let n = matrix.height;
+        let mut l = matrix.to_layout(Layout::RowMajor);
+        if blas::cholesky(n, &mut l.data)? != 0 {
+            return Some(Err(Error::NotPositiveDefinite));
+        }
+
+        // The strict upper triangle still holds A
+        for row in 0..n {
+            for col in row + 1..n {
+                l[(row, col)] = T::zero();
+            }
+        }
+
+        Some(Ok(Cholesky { l }))
+    }
+
+    pub fn l(&self) -> &Matrix<T> {
+        &self.l
+    }
+
+    pub fn determinant(&self) -> T {
+        let mut det = T::one();
+        for k in 0..self.l.height {
+            det = det * self.l[(k, k)] * self.l[(k, k)];
+        }
+
+        det
+    }
+
+    /**
+     * ln(det(A)), which doesn't under- or overflow for large matrices like the
+     * determinant itself does
+     */
+    pub fn log_determinant(&self) -> T::Real {
+        let mut log_det = T::Real::zero();
+        for k in 0..self.l.height {
+            log_det = log_det + self.l[(k, k)].re().ln();
+        }
+
+        log_det + log_det
+    }
+
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, Error> {
+        let rhs = Matrix::new(1, b.len(), b.as_vec().to_vec())?;
+
+        Ok(Vector::new(self.solve_matrix(&rhs)?.data))
+    }
+
+    pub fn solve_matrix(&self, b: &Matrix<T>) -> Result<Matrix<T>, Error> {
+        let n = self.l.height;
+        if b.height != n {
+            return Err(Error::DimensionMismatch {
+                expected: n,
+                found: b.height,
+            });
+        }
+
+        let width = b.width;
+        let mut x = b.clone();
+
+        // Solve Ly = b
+        for row in 0..n {
+            for k in 0..row {
+                let factor = self.l[(row, k)];
+                for col in 0..width {
+                    x[(row, col)] = x[(row, col)] - factor * x[(k, col)];
+                }
+            }
+            let pivot = self.l[(row, row)];
+            for col in 0..width {
+                x[(row, col)] = x[(row, col)] / pivot;
+            }
+        }
+
+        // Solve L^H x = y
+        for row in (0..n).rev() {
+            for k in row + 1..n {
+                let factor = self.l[(k, row)].conj();
+                for col in 0..width {
+                    x[(row, col)] = x[(row, col)] - factor * x[(k, col)];
+                }
+            }
+            let pivot = self.l[(row, row)];
+            for col in 0..width {
+                x[(row, col)] = x[(row, col)] / pivot;
+            }
+        }
+
+        Ok(x)
+    }
+
+    pub fn inverse(&self) -> Matrix<T> {
+        self.solve_matrix(&Matrix::identity(self.l.height)).unwrap()
+    }
+}
+
+impl<T: ComplexField> Matrix<T> {
+    pub fn cholesky(&self) -> Result<Cholesky<T>, Error> {
+        Cholesky::new(self)
+    }
+}
@@ -0,0 +1,688 @@
+use crate::{
+    error::Error,
+    matrix::Matrix,
+    numlib::{Complex, RealField},
+    vector::Vector,
+};
+
+const MAX_ITERATIONS_PER_EIGENVALUE: usize = 60;
+
+/**
+ * Orthogonal reduction to upper Hessenberg form, A = QHQ^T
+ */
+#[derive(Debug, Clone)]
+pub struct Hessenberg<T> {
+    h: Matrix<T>,
+    q: Matrix<T>,
+}
+
+/**
+ * Real Schur decomposition, A = ZTZ^T. T is quasi upper triangular: complex
+ * conjugate eigenvalue pairs show up as 2x2 blocks on its diagonal.
+ */
+#[derive(Debug, Clone)]
+pub struct Schur<T> {
+    t: Matrix<T>,
+    z: Matrix<T>,
+    eigenvalues: Vector<Complex<T>>,
+}
+
+/**
+ * Eigendecomposition of a general real matrix. Eigenvalues are listed in the
+ * order they appear on the diagonal of the Schur form, with conjugate pairs next
+ * to each other and the one with the positive imaginary part first.
+ */
+#[derive(Debug, Clone)]
+pub struct Eigen<T> {
+    eigenvalues: Vector<Complex<T>>,
+    eigenvectors: Matrix<Complex<T>>,
+}
+
+impl<T: RealField> Hessenberg<T> {
+    pub fn new(matrix: &Matrix<T>) -> Result<Hessenberg<T>, Error> {
+        if matrix.width != matrix.height {
+            return Err(Error::NotSquare {
+                shape: matrix.shape(),
+            });
+        }
+
+        let (h, q) = reduce_to_hessenberg(matrix);
+
+        Ok(Hessenberg { h, q })
+    }
+
+    pub fn h(&self) -> &Matrix<T> {
+        &self.h
+    }
+
+    pub fn q(&self) -> &Matrix<T> {
+        &self.q
+    }
+}
+
+impl<T: RealField> Schur<T> {
+    pub fn new(matrix: &Matrix<T>) -> Result<Schur<T>, Error> {
+        let Hessenberg { mut h, mut q } = Hessenberg::new(matrix)?;
+        let (d, e) = francis_qr(&mut h, &mut q)?;
+
+        Ok(Schur {
+            t: h,
+            z: q,
+            eigenvalues: to_complex(&d, &e),
+        })
+    }
+
+    pub fn t(&self) -> &Matrix<T> {
+        &self.t
+    }
+
+    pub fn z(&self) -> &Matrix<T> {
+        &self.z
+    }
+
+    pub fn eigenvalues(&self) -> &Vector<Complex<T>> {
+        &self.eigenvalues
+    }
+}
+
+impl<T: RealField> Eigen<T> {
+    pub fn new(matrix: &Matrix<T>) -> Result<Eigen<T>, Error> {
+        let Hessenberg { mut h, mut q } = Hessenberg::new(matrix)?;
+        let (d, e) = francis_qr(&mut h, &mut q)?;
+        back_substitute(&mut h, &mut q, &d, &e);
+
+        let n = d.len();
+        let mut vectors = vec![Complex::from_real(T::zero()); n * n];
+        let mut col = 0;
+        while col < n {
+            if e[col] == T::zero() {
+                for row in 0..n {
+                    vectors[row * n + col] = Complex::from_real(q[(row, col)]);
+                }
+                col += 1;
+            } else {
+                // The pair is stored as real part and imaginary part in two columns
+                for row in 0..n {
+                    let re = q[(row, col)];
+                    let im = q[(row, col + 1)];
+                    vectors[row * n + col] = Complex::new(re, im);
+                    vectors[row * n + col + 1] = Complex::new(re, -im);
+                }
+                col += 2;
+            }
+        }
+
+        // Scale to unit length
+        for col in 0..n {
+            let mut norm = T::zero();
+            for row in 0..n {
+                norm = norm + vectors[row * n + col].norm_sqr();
+            }
+            let norm = Complex::from_real(norm.sqrt());
+            if norm.re != T::zero() {
+                for row in 0..n {
+                    vectors[row * n + col] = vectors[row * n + col] / norm;
+                }
+            }
+        }
+
+        Ok(Eigen {
+            eigenvalues: to_complex(&d, &e),
+            eigenvectors: Matrix::new(n, n, vectors).unwrap(),
+        })
+    }
+
+    pub fn eigenvalues(&self) -> &Vector<Complex<T>> {
+        &self.eigenvalues
+    }
+
+    /**
+     * The right eigenvectors as unit length columns, column i belongs to eigenvalue i
+     */
+    pub fn eigenvectors(&self) -> &Matrix<Complex<T>> {
+        &self.eigenvectors
+    }
+}
+
+impl<T: RealField> Matrix<T> {
+    pub fn hessenberg(&self) -> Result<Hessenberg<T>, Error> {
+        Hessenberg::new(self)
+    }
+
+    pub fn schur(&self) -> Result<Schur<T>, Error> {
+        Schur::new(self)
+    }
+
+    pub fn eigen(&self) -> Result<Eigen<T>, Error> {
+        Eigen::new(self)
+    }
+
+    pub fn eigenvalues(&self) -> Result<Vector<Complex<T>>, Error> {
+        Ok(Schur::new(self)?.eigenvalues)
+    }
+}
+
+fn to_complex<T: RealField>(d: &[T], e: &[T]) -> Vector<Complex<T>> {
+    Vector::new(
+        d.iter()
+            .zip(e.iter())
+            .map(|(&re, &im)| Complex::new(re, im))
+            .collect(),
+    )
+}
+
+/**
+ * Householder reduction to Hessenberg form, based on orthes and ortran from
+ * EISPACK by way of JAMA
+ */
+fn reduce_to_hessenberg<T: RealField>(matrix: &Matrix<T>) -> (Matrix<T>, Matrix<T>) {
+    let n = matrix.height;
+    let mut h = matrix.clone();
+    let mut ort = vec![T::zero(); n];
+
+    for m in 1..n.saturating_sub(1) {
+        let mut scale = T::zero();
+        for i in m..n {
+            scale = scale + h[(i, m - 1)].abs();
+        }
+        if scale == T::zero() {
+            continue;
+        }
+
+        let mut sum = T::zero();
+        for i in (m..n).rev() {
+            ort[i] = h[(i, m - 1)] / scale;
+            sum = sum + ort[i] * ort[i];
+        }
+        let mut g = sum.sqrt();
+        if ort[m] > T::zero() {
+            g = -g;
+        }
+        sum = sum - ort[m] * g;
+        ort[m] = ort[m] - g;
+
+        // H = (I - u u^T / sum) H (I - u u^T / sum)
+        for j in m..n {
+            let mut f = T::zero();
+            for i in (m..n).rev() {
+                f = f + ort[i] * h[(i, j)];
+            }
+            f = f / sum;
+            for i in m..n {
+                h[(i, j)] = h[(i, j)] - f * ort[i];
+            }
+        }
+        for i in 0..n {
+            let mut f = T::zero();
+            for j in (m..n).rev() {
+                f = f + ort[j] * h[(i, j)];
+            }
+            f = f / sum;
+            for j in m..n {
+                h[(i, j)] = h[(i, j)] - f * ort[j];
+            }
+        }
+        ort[m] = scale * ort[m];
+        h[(m, m - 1)] = scale * g;
+    }
+
+    // Accumulate the transformations
+    let mut q = Matrix::identity(n);
+    for m in (1..n.saturating_sub(1)).rev() {
+        if h[(m, m - 1)] == T::zero() {
+            continue;
+        }
+        for i in m + 1..n {
+            ort[i] = h[(i, m - 1)];
+        }
+        for j in m..n {
+            let mut g = T::zero();
+            for i in m..n {
+                g = g + ort[i] * q[(i, j)];
+            }
+            // Double division avoids possible underflow
+            g = (g / ort[m]) / h[(m, m - 1)];
+            for i in m..n {
+                q[(i, j)] = q[(i, j)] + g * ort[i];
+            }
+        }
+    }
+
+    // Clear the Householder vectors stored below the subdiagonal
+    for row in 2..n {
+        for col in 0..row - 1 {
+            h[(row, col)] = T::zero();
+        }
+    }
+
+    (h, q)
+}
+
+/**
+ * Francis double shift QR iterations, turning the Hessenberg matrix h into real
+ * Schur form while accumulating the transformations into v. Returns the real and
+ * imaginary parts of the eigenvalues. Based on hqr2 from EISPACK by way of JAMA.
+ */
+fn francis_qr<T: RealField>(h: &mut Matrix<T>, v: &mut Matrix<T>) -> Result<(Vec<T>, Vec<T>), Error> {
+    let size = h.height;
+    let mut d = vec![T::zero(); size];
+    let mut e = vec![T::zero(); size];
+    if size == 0 {
+        return Ok((d, e));
+    }
+
+    let eps = T::epsilon();
+    let two = T::from_usize(2);
+    let mut exshift = T::zero();
+    let (mut p, mut q, mut r, mut s, mut z, mut w, mut x, mut y);
+
+    let mut norm = T::zero();
+    for i in 0..size {
+        for j in i.saturating_sub(1)..size {
+            norm = norm + h[(i, j)].abs();
+        }
+    }
+
+    // n is the last row of the active block, signed so it can run past zero
+    let mut n = size as isize - 1;
+    let mut iterations = 0;
+    while n >= 0 {
+        let nu = n as usize;
+
+        // Look for a single small subdiagonal element
+        let mut l = nu;
+        while l > 0 {
+            s = h[(l - 1, l - 1)].abs() + h[(l, l)].abs();
+            if s == T::zero() {
+                s = norm;
+            }
+            if h[(l, l - 1)].abs() < eps * s {
+                break;
+            }
+            l -= 1;
+        }
+
+        if l == nu {
+            // One root found
+            h[(nu, nu)] = h[(nu, nu)] + exshift;
+            d[nu] = h[(nu, nu)];
+            e[nu] = T::zero();
+            n -= 1;
+            iterations = 0;
+        } else if l + 1 == nu {
+            // Two roots found
+            w = h[(nu, nu - 1)] * h[(nu - 1, nu)];
+            p = (h[(nu - 1, nu - 1)] - h[(nu, nu)]) / two;
+            q = p * p + w;
+            z = q.abs().sqrt();
+            h[(nu, nu)] = h[(nu, nu)] + exshift;
+            h[(nu - 1, nu - 1)] = h[(nu - 1, nu - 1)] + exshift;
+            x = h[(nu, nu)];
+
+            if q >= T::zero() {
+                // Real pair, rotate the block to upper triangular
+                z = if p >= T::zero() { p + z } else { p - z };
+                d[nu - 1] = x + z;
+                d[nu] = d[nu - 1];
+                if z != T::zero() {
+                    d[nu] = x - w / z;
+                }
+                e[nu - 1] = T::zero();
+                e[nu] = T::zero();
+                x = h[(nu, nu - 1)];
+                s = x.abs() + z.abs();
+                p = x / s;
+                q = z / s;
+                r = (p * p + q * q).sqrt();
+                p = p / r;
+                q = q / r;
+
+                for j in nu - 1..size {
+                    z = h[(nu - 1, j)];
+                    h[(nu - 1, j)] = q * z + p * h[(nu, j)];
+                    h[(nu, j)] = q * h[(nu, j)] - p * z;
+                }
+                for i in 0..=nu {
+                    z = h[(i, nu - 1)];
+                    h[(i, nu - 1)] = q * z + p * h[(i, nu)];
+                    h[(i, nu)] = q * h[(i, nu)] - p * z;
+                }
+                for i in 0..size {
+                    z = v[(i, nu - 1)];
+                    v[(i, nu - 1)] = q * z + p * v[(i, nu)];
+                    v[(i, nu)] = q * v[(i, nu)] - p * z;
+                }
+            } else {
+                // Complex pair
+                d[nu - 1] = x + p;
+                d[nu] = x + p;
+                e[nu - 1] = z;
+                e[nu] = -z;
+            }
+            n -= 2;
+            iterations = 0;
+        } else {
+            iterations += 1;
+            if iterations > MAX_ITERATIONS_PER_EIGENVALUE {
+                return Err(Error::NoConvergence);
+            }
+
+            // Form the shift
+            x = h[(nu, nu)];
+            y = h[(nu - 1, nu - 1)];
+            w = h[(nu, nu - 1)] * h[(nu - 1, nu)];
+
+            // Wilkinson's original ad hoc shift
+            if iterations == 10 {
+                exshift = exshift + x;
+                for i in 0..=nu {
+                    h[(i, i)] = h[(i, i)] - x;
+                }
+                s = h[(nu, nu - 1)].abs() + h[(nu - 1, nu - 2)].abs();
+                x = T::from_f64(0.75) * s;
+                y = x;
+                w = T::from_f64(-0.4375) * s * s;
+            }
+
+            // MATLAB's ad hoc shift
+            if iterations == 30 {
+                s = (y - x) / two;
+                s = s * s + w;
+                if s > T::zero() {
+                    s = s.sqrt();
+                    if y < x {
+                        s = -s;
+                    }
+                    s = x - w / ((y - x) / two + s);
+                    for i in 0..=nu {
+                        h[(i, i)] = h[(i, i)] - s;
+                    }
+                    exshift = exshift + s;
+                    x = T::from_f64(0.964);
+                    y = x;
+                    w = x;
+                }
+            }
+
+            // Look for two consecutive small subdiagonal elements
+            let mut m = nu - 2;
+            loop {
+                z = h[(m, m)];
+                r = x - z;
+                s = y - z;
+                p = (r * s - w) / h[(m + 1, m)] + h[(m, m + 1)];
+                q = h[(m + 1, m + 1)] - z - r - s;
+                r = h[(m + 2, m + 1)];
+                s = p.abs() + q.abs() + r.abs();
+                p = p / s;
+                q = q / s;
+                r = r / s;
+                if m == l {
+                    break;
+                }
+                if h[(m, m - 1)].abs() * (q.abs() + r.abs())
+                    < eps
+                        * (p.abs() * (h[(m - 1, m - 1)].abs() + z.abs() + h[(m + 1, m + 1)].abs()))
+                {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in m + 2..=nu {
+                h[(i, i - 2)] = T::zero();
+                if i > m + 2 {
+                    h[(i, i - 3)] = T::zero();
+                }
+            }
+
+            // Double QR step involving rows l..=n and columns m..=n
+            for k in m..nu {
+                let not_last = k != nu - 1;
+                if k != m {
+                    p = h[(k, k - 1)];
+                    q = h[(k + 1, k - 1)];
+                    r = if not_last {
+                        h[(k + 2, k - 1)]
+                    } else {
+                        T::zero()
+                    };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x == T::zero() {
+                        continue;
+                    }
+                    p = p / x;
+                    q = q / x;
+                    r = r / x;
+                }
+
+                s = (p * p + q * q + r * r).sqrt();
+                if p < T::zero() {
+                    s = -s;
+                }
+                if s == T::zero() {
+                    continue;
+                }
+
+                if k != m {
+                    h[(k, k - 1)] = -s * x;
+                } else if l != m {
+                    h[(k, k - 1)] = -h[(k, k - 1)];
+                }
+                p = p + s;
+                x = p / s;
+                y = q / s;
+                z = r / s;
+                q = q / p;
+                r = r / p;
+
+                // Row modification
+                for j in k..size {
+                    p = h[(k, j)] + q * h[(k + 1, j)];
+                    if not_last {
+                        p = p + r * h[(k + 2, j)];
+                        h[(k + 2, j)] = h[(k + 2, j)] - p * z;
+                    }
+                    h[(k, j)] = h[(k, j)] - p * x;
+                    h[(k + 1, j)] = h[(k + 1, j)] - p * y;
+                }
+
+                // Column modification
+                for i in 0..=nu.min(k + 3) {
+                    p = x * h[(i, k)] + y * h[(i, k + 1)];
+                    if not_last {
+                        p = p + z * h[(i, k + 2)];
+                        h[(i, k + 2)] = h[(i, k + 2)] - p * r;
+                    }
+                    h[(i, k)] = h[(i, k)] - p;
+                    h[(i, k + 1)] = h[(i, k + 1)] - p * q;
+                }
+
+                // Accumulate transformations
+                for i in 0..size {
+                    p = x * v[(i, k)] + y * v[(i, k + 1)];
+                    if not_last {
+                        p = p + z * v[(i, k + 2)];
+                        v[(i, k + 2)] = v[(i, k + 2)] - p * r;
+                    }
+                    v[(i, k)] = v[(i, k)] - p;
+                    v[(i, k + 1)] = v[(i, k + 1)] - p * q;
+                }
+            }
+        }
+    }
+
+    // Clear the negligible entries below the quasi triangular structure
+    for row in 1..size {
+        let starts_block = e[row - 1] > T::zero();
+        for col in 0..row {
+            if col + 1 != row || !starts_block {
+                h[(row, col)] = T::zero();
+            }
+        }
+    }
+
+    Ok((d, e))
+}
+
+fn complex_div<T: RealField>(xr: T, xi: T, yr: T, yi: T) -> (T, T) {
+    let quotient = Complex::new(xr, xi) / Complex::new(yr, yi);
+    (quotient.re, quotient.im)
+}
+
+/**
+ * Computes the eigenvectors of the Schur form h by back substitution and
+ * transforms them back with the Schur vectors in v. Afterwards the columns of v
+ * hold the eigenvectors, complex ones as a real and imaginary column.
+ */
+fn back_substitute<T: RealField>(h: &mut Matrix<T>, v: &mut Matrix<T>, d: &[T], e: &[T]) {
+    let size = h.height;
+    let eps = T::epsilon();
+    let two = T::from_usize(2);
+
+    let mut norm = T::zero();
+    for i in 0..size {
+        for j in i.saturating_sub(1)..size {
+            norm = norm + h[(i, j)].abs();
+        }
+    }
+    if norm == T::zero() {
+        return;
+    }
+
+    let (mut r, mut s, mut z) = (T::zero(), T::zero(), T::zero());
+    for n in (0..size).rev() {
+        let p = d[n];
+        let q = e[n];
+
+        if q == T::zero() {
+            // Real vector
+            let mut l = n;
+            h[(n, n)] = T::one();
+            for i in (0..n).rev() {
+                let w = h[(i, i)] - p;
+                r = T::zero();
+                for j in l..=n {
+                    r = r + h[(i, j)] * h[(j, n)];
+                }
+                if e[i] < T::zero() {
+                    z = w;
+                    s = r;
+                    continue;
+                }
+
+                l = i;
+                if e[i] == T::zero() {
+                    h[(i, n)] = if w != T::zero() {
+                        -r / w
+                    } else {
+                        -r / (eps * norm)
+                    };
+                } else {
+                    // Solve the real equations
+                    let x = h[(i, i + 1)];
+                    let y = h[(i + 1, i)];
+                    let q = (d[i] - p) * (d[i] - p) + e[i] * e[i];
+                    let t = (x * s - z * r) / q;
+                    h[(i, n)] = t;
+                    h[(i + 1, n)] = if x.abs() > z.abs() {
+                        (-r - w * t) / x
+                    } else {
+                        (-s - y * t) / z
+                    };
+                }
+
+                // Overflow control
+                let t = h[(i, n)].abs();
+                if (eps * t) * t > T::one() {
+                    for j in i..=n {
+                        h[(j, n)] = h[(j, n)] / t;
+                    }
+                }
+            }
+        } else if q < T::zero() {
+            // Complex vector, real part in column n - 1 and imaginary part in column n
+            let mut l = n - 1;
+            if h[(n, n - 1)].abs() > h[(n - 1, n)].abs() {
+                h[(n - 1, n - 1)] = q / h[(n, n - 1)];
+                h[(n - 1, n)] = -(h[(n, n)] - p) / h[(n, n - 1)];
+            } else {
+                let (re, im) = complex_div(T::zero(), -h[(n - 1, n)], h[(n - 1, n - 1)] - p, q);
+                h[(n - 1, n - 1)] = re;
+                h[(n - 1, n)] = im;
+            }
+            h[(n, n - 1)] = T::zero();
+            h[(n, n)] = T::one();
+
+            for i in (0..n.saturating_sub(1)).rev() {
+                let mut ra = T::zero();
+                let mut sa = T::zero();
+                for j in l..=n {
+                    ra = ra + h[(i, j)] * h[(j, n - 1)];
+                    sa = sa + h[(i, j)] * h[(j, n)];
+                }
+                let w = h[(i, i)] - p;
+
+                if e[i] < T::zero() {
+                    z = w;
+                    r = ra;
+                    s = sa;
+                    continue;
+                }
+
+                l = i;
+                if e[i] == T::zero() {
+                    let (re, im) = complex_div(-ra, -sa, w, q);
+                    h[(i, n - 1)] = re;
+                    h[(i, n)] = im;
+                } else {
+                    // Solve the complex equations
+                    let x = h[(i, i + 1)];
+                    let y = h[(i + 1, i)];
+                    let mut vr = (d[i] - p) * (d[i] - p) + e[i] * e[i] - q * q;
+                    let vi = (d[i] - p) * two * q;
+                    if vr == T::zero() && vi == T::zero() {
+                        vr = eps * norm * (w.abs() + q.abs() + x.abs() + y.abs() + z.abs());
+                    }
+                    let (re, im) =
+                        complex_div(x * r - z * ra + q * sa, x * s - z * sa - q * ra, vr, vi);
+                    h[(i, n - 1)] = re;
+                    h[(i, n)] = im;
+                    if x.abs() > z.abs() + q.abs() {
+                        h[(i + 1, n - 1)] = (-ra - w * h[(i, n - 1)] + q * h[(i, n)]) / x;
+                        h[(i + 1, n)] = (-sa - w * h[(i, n)] - q * h[(i, n - 1)]) / x;
+                    } else {
+                        let (re, im) =
+                            complex_div(-r - y * h[(i, n - 1)], -s - y * h[(i, n)], z, q);
+                        h[(i + 1, n - 1)] = re;
+                        h[(i + 1, n)] = im;
+                    }
+                }
+
+                // Overflow control
+                let t = if h[(i, n - 1)].abs() > h[(i, n)].abs() {
+                    h[(i, n - 1)].abs()
+                } else {
+                    h[(i, n)].abs()
+                };
+                if (eps * t) * t > T::one() {
+                    for j in i..=n {
+                        h[(j, n - 1)] = h[(j, n - 1)] / t;
+                        h[(j, n)] = h[(j, n)] / t;
+                    }
+                }
+            }
+        }
+    }
+
+    // Back transformation to the eigenvectors of the original matrix
+    for j in (0..size).rev() {
+        for i in 0..size {
+            let mut sum = T::zero();
+            for k in 0..=j {
+                sum = sum + v[(i, k)] * h[(k, j)];
+            }
+            v[(i, j)] = sum;
+        }
+    }
+}
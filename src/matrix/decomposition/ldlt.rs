@@ -0,0 +1,327 @@
+use crate::{
+    error::Error,
+    matrix::{Layout, Matrix},
+    numlib::RealField,
+    vector::Vector,
+};
+
+/**
+ * LDL^T decomposition of a symmetric, possibly indefinite, matrix using
+ * Bunch-Kaufman pivoting, PAP^T = LDL^T. D is block diagonal with 1x1 and 2x2
+ * blocks. Only the lower triangle of A is read.
+ */
+#[derive(Debug, Clone)]
+pub struct LDLT<T> {
+    l: Matrix<T>,
+    diagonal: Vec<T>,
+    // Entry k is D[(k + 1, k)] for a 2x2 block starting at k, zero otherwise
+    off_diagonal: Vec<T>,
+    block_sizes: Vec<usize>,
+    permutation: Vec<usize>,
+    singular: bool,
+}
+
+fn swap_symmetric<T>(a: &mut Matrix<T>, i: usize, j: usize) {
+    let n = a.width;
+    for col in 0..n {
+        a.data.swap(i * n + col, j * n + col);
+    }
+    for row in 0..n {
+        a.data.swap(row * n + i, row * n + j);
+    }
+}
+
+impl<T: RealField> LDLT<T> {
+    pub fn new(matrix: &Matrix<T>) -> Result<LDLT<T>, Error> {
+        if matrix.width != matrix.height {
+            return Err(Error::NotSquare {
+                shape: matrix.shape(),
+            });
+        }
+
+        let n = matrix.height;
+        let mut a = matrix.to_layout(Layout::RowMajor);
+        for row in 0..n {
+            for col in row + 1..n {
+                a[(row, col)] = a[(col, row)];
+            }
+        }
+
+        let mut l = Matrix::identity(n);
+        let mut diagonal = vec![T::zero(); n];
+        let mut off_diagonal = vec![T::zero(); n];
+        let mut block_sizes = Vec::new();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut singular = false;
+
+        let largest = a.data.iter().fold(
+            T::zero(),
+            |acc, x| if x.abs() > acc { x.abs() } else { acc },
+        );
+        let tolerance = T::epsilon() * T::from_usize(n) * largest;
+        let alpha = (T::one() + T::from_usize(17).sqrt()) / T::from_usize(8);
+
+        let mut k = 0;
+        while k < n {
+            let diagonal_size = a[(k, k)].abs();
+            let mut r = k;
+            let mut column_max = T::zero();
+            for row in k + 1..n {
+                if a[(row, k)].abs() > column_max {
+                    r = row;
+                    column_max = a[(row, k)].abs();
+                }
+            }
+
+            if diagonal_size == T::zero() && column_max == T::zero() {
+                // Nothing to eliminate, the column is already zero
+                singular = true;
+                block_sizes.push(1);
+                k += 1;
+                continue;
+            }
+
+            let (size, pivot) = if diagonal_size >= alpha * column_max {
+                (1, k)
+            } else {
+                let mut row_max = T::zero();
+                for col in k..n {
+                    if col != r && a[(r, col)].abs() > row_max {
+                        row_max = a[(r, col)].abs();
+                    }
+                }
+
+                if diagonal_size * row_max >= alpha * column_max * column_max {
+                    (1, k)
+                } else if a[(r, r)].abs() >= alpha * row_max {
+                    (1, r)
+                } else {
+                    (2, r)
+                }
+            };
+
+            let target = k + size - 1;
+            if pivot != target {
+                swap_symmetric(&mut a, target, pivot);
+                for col in 0..k {
+                    l.data.swap(target * n + col, pivot * n + col);
+                }
+                permutation.swap(target, pivot);
+            }
+
+            if size == 1 {
+                let d = a[(k, k)];
+                diagonal[k] = d;
+                if d.abs() <= tolerance {
+                    singular = true;
+                }
+
+                for row in k + 1..n {
+                    l[(row, k)] = a[(row, k)] / d;
+                }
+                for row in k + 1..n {
+                    for col in k + 1..n {
+                        a[(row, col)] = a[(row, col)] - l[(row, k)] * a[(col, k)];
+                    }
+                }
+            } else {
+                let a11 = a[(k, k)];
+                let a21 = a[(k + 1, k)];
+                let a22 = a[(k + 1, k + 1)];
+                let det = a11 * a22 - a21 * a21;
+                diagonal[k] = a11;
+                diagonal[k + 1] = a22;
+                off_diagonal[k] = a21;
+
+                for row in k + 2..n {
+                    let w1 = a[(row, k)];
+                    let w2 = a[(row, k + 1)];
+                    l[(row, k)] = (w1 * a22 - w2 * a21) / det;
+                    l[(row, k + 1)] = (w2 * a11 - w1 * a21) / det;
+                }
+                for row in k + 2..n {
+                    for col in k + 2..n {
+                        a[(row, col)] = a[(row, col)]
+                            - l[(row, k)] * a[(col, k)]
+                            - l[(row, k + 1)] * a[(col, k + 1)];
+                    }
+                }
+            }
+
+            block_sizes.push(size);
+            k += size;
+        }
+
+        Ok(LDLT {
+            l,
+            diagonal,
+            off_diagonal,
+            block_sizes,
+            permutation,
+            singular,
+        })
+    }
+
+    pub fn l(&self) -> &Matrix<T> {
+        &self.l
+    }
+
+    /**
+     * The block diagonal factor D
+     */
+    pub fn d(&self) -> Matrix<T> {
+        let n = self.diagonal.len();
+        let mut d = Matrix::new_of_element(n, n, T::zero()).unwrap();
+        for k in 0..n {
+            d[(k, k)] = self.diagonal[k];
+            if k + 1 < n {
+                d[(k + 1, k)] = self.off_diagonal[k];
+                d[(k, k + 1)] = self.off_diagonal[k];
+            }
+        }
+
+        d
+    }
+
+    /**
+     * The permutation matrix P, so that PAP^T = LDL^T
+     */
+    pub fn p(&self) -> Matrix<T> {
+        let n = self.permutation.len();
+        let mut p = Matrix::new_of_element(n, n, T::zero()).unwrap();
+        for (row, &original) in self.permutation.iter().enumerate() {
+            p[(row, original)] = T::one();
+        }
+
+        p
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    fn block_determinants(&self) -> Vec<T> {
+        let mut k = 0;
+        let mut dets = Vec::with_capacity(self.block_sizes.len());
+        for &size in self.block_sizes.iter() {
+            if size == 1 {
+                dets.push(self.diagonal[k]);
+            } else {
+                dets.push(
+                    self.diagonal[k] * self.diagonal[k + 1]
+                        - self.off_diagonal[k] * self.off_diagonal[k],
+                );
+            }
+            k += size;
+        }
+
+        dets
+    }
+
+    pub fn determinant(&self) -> T {
+        self.block_determinants()
+            .into_iter()
+            .fold(T::one(), |acc, det| acc * det)
+    }
+
+    /**
+     * The sign and natural logarithm of the absolute value of the determinant
+     */
+    pub fn signed_log_determinant(&self) -> (T, T) {
+        let mut sign = T::one();
+        let mut log_det = T::zero();
+        for det in self.block_determinants() {
+            if det < T::zero() {
+                sign = -sign;
+            }
+            log_det = log_det + det.abs().ln();
+        }
+
+        (sign, log_det)
+    }
+
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, Error> {
+        let rhs = Matrix::new(1, b.len(), b.as_vec().to_vec())?;
+
+        Ok(Vector::new(self.solve_matrix(&rhs)?.data))
+    }
+
+    pub fn solve_matrix(&self, b: &Matrix<T>) -> Result<Matrix<T>, Error> {
+        let n = self.diagonal.len();
+        if b.height != n {
+            return Err(Error::DimensionMismatch {
+                expected: n,
+                found: b.height,
+            });
+        }
+        if self.singular {
+            return Err(Error::Singular);
+        }
+
+        let width = b.width;
+        let rows = b.as_view().row_major();
+        let mut x = Vec::with_capacity(b.size);
+        for &original in self.permutation.iter() {
+            x.extend_from_slice(&rows[original * width..(original + 1) * width]);
+        }
+
+        // Solve Ly = Pb
+        for row in 0..n {
+            for k in 0..row {
+                let factor = self.l[(row, k)];
+                for col in 0..width {
+                    x[row * width + col] = x[row * width + col] - factor * x[k * width + col];
+                }
+            }
+        }
+
+        // Solve Dz = y block by block
+        let mut k = 0;
+        for &size in self.block_sizes.iter() {
+            if size == 1 {
+                for col in 0..width {
+                    x[k * width + col] = x[k * width + col] / self.diagonal[k];
+                }
+            } else {
+                let (a11, a21, a22) =
+                    (self.diagonal[k], self.off_diagonal[k], self.diagonal[k + 1]);
+                let det = a11 * a22 - a21 * a21;
+                for col in 0..width {
+                    let y1 = x[k * width + col];
+                    let y2 = x[(k + 1) * width + col];
+                    x[k * width + col] = (a22 * y1 - a21 * y2) / det;
+                    x[(k + 1) * width + col] = (a11 * y2 - a21 * y1) / det;
+                }
+            }
+            k += size;
+        }
+
+        // Solve L^T w = z
+        for row in (0..n).rev() {
+            for k in row + 1..n {
+                let factor = self.l[(k, row)];
+                for col in 0..width {
+                    x[row * width + col] = x[row * width + col] - factor * x[k * width + col];
+                }
+            }
+        }
+
+        let mut res = vec![T::zero(); b.size];
+        for (row, &original) in self.permutation.iter().enumerate() {
+            res[original * width..(original + 1) * width]
+                .copy_from_slice(&x[row * width..(row + 1) * width]);
+        }
+
+        Matrix::new(width, n, res)
+    }
+}
+
+impl<T: RealField> Matrix<T> {
+    pub fn ldlt(&self) -> Result<LDLT<T>, Error> {
+        LDLT::new(self)
+    }
+}
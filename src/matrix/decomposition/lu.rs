@@ -0,0 +1,313 @@
+#[cfg(feature = "blas")]
+use crate::matrix::blas;
+use crate::{
+    error::Error,
+    matrix::{Layout, Matrix},
+    numlib::{ComplexField, RealField, Zero},
+    parallel,
+    vector::Vector,
+};
+
+/**
+ * LU decomposition with partial pivoting, PA = LU.
+ * L and U are stored together in a single matrix, the unit diagonal of L is implicit.
+ */
+#[derive(Debug, Clone)]
+pub struct LU<T> {
+    lu: Matrix<T>,
+    permutation: Vec<usize>,
+    swaps: usize,
+    singular: bool,
+}
+
+impl<T: ComplexField> LU<T> {
+    pub fn new(matrix: &Matrix<T>) -> Result<LU<T>, Error> {
+        if matrix.width != matrix.height {
+            return Err(Error::NotSquare {
+                shape: matrix.shape(),
+            });
+        }
+
+        let tolerance = tolerance(matrix);
+        #[cfg(feature = "blas")]
+        if let Some(lu) = LU::lapack(matrix, tolerance) {
+            return Ok(lu);
+        }
+
+        let n = matrix.height;
+        let mut lu = matrix.to_layout(Layout::RowMajor);
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut swaps = 0usize;
+        let mut singular = false;
+
+        for k in 0..n {
+            let mut pivot_row = k;
+            let mut pivot_size = lu[(k, k)].abs();
+            for row in k + 1..n {
+                if lu[(row, k)].abs() > pivot_size {
+                    pivot_row = row;
+                    pivot_size = lu[(row, k)].abs();
+                }
+            }
+
+            if pivot_row != k {
+                for col in 0..n {
+                    lu.data.swap(k * n + col, pivot_row * n + col);
+                }
+                permutation.swap(k, pivot_row);
+                swaps += 1;
+            }
+
+            if pivot_size <= tolerance {
+                singular = true;
+            }
+            if pivot_size == T::Real::zero() {
+                continue;
+            }
+
+            // The rows below the pivot are updated independently of each other
+            let (upper, lower) = lu.data.split_at_mut((k + 1) * n);
+            let pivot_row = &upper[k * n..];
+            let pivot = pivot_row[k];
+            parallel::run((n - k - 1) * (n - k), |parallel| {
+                parallel::for_each_chunk(parallel, lower, n, |_, row| {
+                    let factor = row[k] / pivot;
+                    row[k] = factor;
+                    for (entry, &value) in row[k + 1..].iter_mut().zip(&pivot_row[k + 1..]) {
+                        *entry = *entry - factor * value;
+                    }
+                })
+            });
+        }
+
+        Ok(LU {
+            lu,
+            permutation,
+            swaps,
+            singular,
+        })
+    }
+
+    /**
+     * The same factorization by getrf, which LAPACK does column by column
+     */
+    #[cfg(feature = "blas")]
+    fn lapack(matrix: &Matrix<T>, tolerance: T::Real) -> Option<LU<T>> {
+        let n = matrix.height;
+        let mut lu = matrix.to_layout(Layout::ColumnMajor);
+        let mut pivots = vec![0; n];
+        blas::getrf(n, &mut lu.data, &mut pivots)?;
+        let lu = lu.into_layout(Layout::RowMajor);
+
+        // Row k was swapped with row pivots[k], counting from one
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut swaps = 0usize;
+        for (k, &pivot) in pivots.iter().enumerate() {
+            let pivot = pivot as usize - 1;
+            if pivot != k {
+                permutation.swap(k, pivot);
+                swaps += 1;
+            }
+        }
+        let singular = (0..n).any(|k| lu[(k, k)].abs() <= tolerance);
+
+        Some(LU {
+            lu,
+            permutation,
+            swaps,
+            singular,
+        })
+    }
+
+    pub fn l(&self) -> Matrix<T> {
+        let n = self.lu.height;
+        let mut l = Matrix::new_of_element(n, n, T::zero()).unwrap();
+        for row in 0..n {
+            for col in 0..row {
+                l[(row, col)] = self.lu[(row, col)];
+            }
+            l[(row, row)] = T::one();
+        }
+
+        l
+    }
+
+    pub fn u(&self) -> Matrix<T> {
+        let n = self.lu.height;
+        let mut u = Matrix::new_of_element(n, n, T::zero()).unwrap();
+        for row in 0..n {
+            for col in row..n {
+                u[(row, col)] = self.lu[(row, col)];
+            }
+        }
+
+        u
+    }
+
+    /**
+     * The permutation matrix P, so that PA = LU
+     */
+    pub fn p(&self) -> Matrix<T> {
+        let n = self.lu.height;
+        let mut p = Matrix::new_of_element(n, n, T::zero()).unwrap();
+        for (row, &original) in self.permutation.iter().enumerate() {
+            p[(row, original)] = T::one();
+        }
+
+        p
+    }
+
+    /**
+     * Row i of PA is row permutation()[i] of A
+     */
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    pub fn determinant(&self) -> T {
+        let mut det = T::one();
+        for k in 0..self.lu.height {
+            det = det * self.lu[(k, k)];
+        }
+
+        if self.swaps % 2 == 1 {
+            -det
+        } else {
+            det
+        }
+    }
+
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, Error> {
+        let rhs = Matrix::new(1, b.len(), b.as_vec().to_vec())?;
+
+        Ok(Vector::new(self.solve_matrix(&rhs)?.data))
+    }
+
+    /**
+     * Solves AX = B for every column of B at once, reusing this factorization
+     */
+    pub fn solve_matrix(&self, b: &Matrix<T>) -> Result<Matrix<T>, Error> {
+        let n = self.lu.height;
+        if b.height != n {
+            return Err(Error::DimensionMismatch {
+                expected: n,
+                found: b.height,
+            });
+        }
+        if self.singular {
+            return Err(Error::Singular);
+        }
+
+        let width = b.width;
+        let rows = b.as_view().row_major();
+        let mut x = Vec::with_capacity(b.size);
+        for &original in self.permutation.iter() {
+            x.extend_from_slice(&rows[original * width..(original + 1) * width]);
+        }
+
+        // Forward substitution with the unit lower triangle
+        for row in 0..n {
+            for k in 0..row {
+                let factor = self.lu[(row, k)];
+                for col in 0..width {
+                    x[row * width + col] = x[row * width + col] - factor * x[k * width + col];
+                }
+            }
+        }
+
+        // Back substitution with the upper triangle
+        for row in (0..n).rev() {
+            for k in row + 1..n {
+                let factor = self.lu[(row, k)];
+                for col in 0..width {
+                    x[row * width + col] = x[row * width + col] - factor * x[k * width + col];
+                }
+            }
+            let pivot = self.lu[(row, row)];
+            for col in 0..width {
+                x[row * width + col] = x[row * width + col] / pivot;
+            }
+        }
+
+        Matrix::new(width, n, x)
+    }
+
+    pub fn inverse(&self) -> Result<Matrix<T>, Error> {
+        self.solve_matrix(&Matrix::identity(self.lu.height))
+    }
+}
+
+/**
+ * Pivots this small compared to the entries are only rounding noise
+ */
+fn tolerance<T: ComplexField>(matrix: &Matrix<T>) -> T::Real {
+    let largest = matrix.data.iter().fold(
+        T::Real::zero(),
+        |acc, x| if x.abs() > acc { x.abs() } else { acc },
+    );
+
+    T::epsilon() * T::Real::from_usize(matrix.height) * largest
+}
+
+impl<T: ComplexField> Matrix<T> {
+    pub fn lu(&self) -> Result<LU<T>, Error> {
+        LU::new(self)
+    }
+
+    pub fn determinant(&self) -> Result<T, Error> {
+        Ok(self.lu()?.determinant())
+    }
+
+    pub fn inverse(&self) -> Result<Matrix<T>, Error> {
+        self.lu()?.inverse()
+    }
+
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, Error> {
+        #[cfg(feature = "blas")]
+        if let Ok(rhs) = Matrix::new(1, b.len(), b.as_vec().to_vec()) {
+            if let Some(x) = self.lapack_solve(&rhs) {
+                return Ok(Vector::new(x?.data));
+            }
+        }
+
+        self.lu()?.solve(b)
+    }
+
+    pub fn solve_matrix(&self, b: &Matrix<T>) -> Result<Matrix<T>, Error> {
+        #[cfg(feature = "blas")]
+        if let Some(x) = self.lapack_solve(b) {
+            return x;
+        }
+
+        self.lu()?.solve_matrix(b)
+    }
+
+    /**
+     * Factors and solves in one call to gesv. Anything that is an error is left
+     * to the pure Rust path to report.
+     */
+    #[cfg(feature = "blas")]
+    fn lapack_solve(&self, b: &Matrix<T>) -> Option<Result<Matrix<T>, Error>> {
+        let n = self.height;
+        if self.width != n || b.height != n {
+            return None;
+        }
+
+        let mut lu = self.to_layout(Layout::ColumnMajor);
+        let mut x = b.to_layout(Layout::ColumnMajor);
+        let mut pivots = vec![0; n];
+        blas::gesv(n, b.width, &mut lu.data, &mut pivots, &mut x.data)?;
+
+        // Singular the same way LU decides it, not only for exactly zero pivots
+        let tolerance = tolerance(self);
+        if (0..n).any(|k| lu[(k, k)].abs() <= tolerance) {
+            return Some(Err(Error::Singular));
+        }
+
+        Some(Ok(x.into_layout(Layout::RowMajor)))
+    }
+}
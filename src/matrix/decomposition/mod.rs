@@ -0,0 +1,20 @@
+mod lu;
+pub use lu::LU;
+
+mod qr;
+pub use qr::QR;
+
+mod cholesky;
+pub use cholesky::Cholesky;
+
+mod ldlt;
+pub use ldlt::LDLT;
+
+
+mod symmetric_eigen;
+pub use symmetric_eigen::SymmetricEigen;
+
+mod eigen;
+pub use eigen::{Eigen, Hessenberg, Schur};
+mod svd;
+pub use svd::SVD;
@@ -0,0 +1,305 @@
+use crate::{
+    error::Error,
+    matrix::{Layout, Matrix},
+    numlib::{ComplexField, RealField, Zero},
+    vector::Vector,
+};
+
+/**
+ * Householder QR decomposition, AP = QR, with P the identity unless column
+ * pivoting was requested. R is stored in the upper triangle and the Householder
+ * vectors below it, their leading 1 is implicit. For complex matrices Q is
+ * unitary and the diagonal of R is real.
+ */
+#[derive(Debug, Clone)]
+pub struct QR<T> {
+    qr: Matrix<T>,
+    tau: Vec<T>,
+    permutation: Vec<usize>,
+    rank: usize,
+    // Whether the independent columns come first, always the case when pivoting
+    leading_rank: bool,
+}
+
+impl<T: ComplexField> QR<T> {
+    pub fn new(matrix: &Matrix<T>) -> QR<T> {
+        QR::factorize(matrix, false)
+    }
+
+    /**
+     * Moves the remaining column with the largest norm to the front at every step,
+     * which makes the diagonal of R non-increasing and reveals the rank
+     */
+    pub fn new_pivoted(matrix: &Matrix<T>) -> QR<T> {
+        QR::factorize(matrix, true)
+    }
+
+    fn factorize(matrix: &Matrix<T>, pivoting: bool) -> QR<T> {
+        let (m, n) = matrix.shape();
+        let steps = m.min(n);
+        let mut qr = matrix.to_layout(Layout::RowMajor);
+        let mut tau = Vec::with_capacity(steps);
+        let mut permutation: Vec<usize> = (0..n).collect();
+
+        for k in 0..steps {
+            if pivoting {
+                let mut best = k;
+                let mut best_norm = T::Real::zero();
+                for col in k..n {
+                    let mut norm = T::Real::zero();
+                    for row in k..m {
+                        norm = norm + qr[(row, col)].norm_sqr();
+                    }
+                    if norm > best_norm {
+                        best = col;
+                        best_norm = norm;
+                    }
+                }
+
+                if best != k {
+                    for row in 0..m {
+                        qr.data.swap(row * n + k, row * n + best);
+                    }
+                    permutation.swap(k, best);
+                }
+            }
+
+            let mut norm = T::Real::zero();
+            for row in k..m {
+                norm = norm + qr[(row, k)].norm_sqr();
+            }
+            norm = norm.sqrt();
+
+            if norm == T::Real::zero() {
+                tau.push(T::zero());
+                continue;
+            }
+
+            // The sign is chosen to avoid cancellation in head - beta
+            let head = qr[(k, k)];
+            let beta = T::from_real(if head.re() >= T::Real::zero() {
+                -norm
+            } else {
+                norm
+            });
+            let scale = head - beta;
+            for row in k + 1..m {
+                qr[(row, k)] = qr[(row, k)] / scale;
+            }
+            qr[(k, k)] = beta;
+            let t = (beta - head) / beta;
+            tau.push(t);
+
+            // Apply H^H = I - conj(tau) v v^H to the remaining columns
+            for col in k + 1..n {
+                let mut w = qr[(k, col)];
+                for row in k + 1..m {
+                    w = w + qr[(row, k)].conj() * qr[(row, col)];
+                }
+                w = w * t.conj();
+                qr[(k, col)] = qr[(k, col)] - w;
+                for row in k + 1..m {
+                    qr[(row, col)] = qr[(row, col)] - qr[(row, k)] * w;
+                }
+            }
+        }
+
+        let mut largest = T::Real::zero();
+        for k in 0..steps {
+            if qr[(k, k)].abs() > largest {
+                largest = qr[(k, k)].abs();
+            }
+        }
+        let tolerance = T::epsilon() * T::Real::from_usize(m.max(n)) * largest;
+        let rank = (0..steps).filter(|&k| qr[(k, k)].abs() > tolerance).count();
+        let leading_rank = (0..rank).all(|k| qr[(k, k)].abs() > tolerance);
+
+        QR {
+            qr,
+            tau,
+            permutation,
+            rank,
+            leading_rank,
+        }
+    }
+
+    /**
+     * Applies the reflectors H_first..H_last (in the given order) to the columns
+     * of x, or their adjoints when adjoint is set
+     */
+    fn apply_reflectors<I: Iterator<Item = usize>>(
+        &self,
+        x: &mut Matrix<T>,
+        order: I,
+        adjoint: bool,
+    ) {
+        let m = self.qr.height;
+        for k in order {
+            let t = if adjoint {
+                self.tau[k].conj()
+            } else {
+                self.tau[k]
+            };
+            if t == T::zero() {
+                continue;
+            }
+            for col in 0..x.width {
+                let mut w = x[(k, col)];
+                for row in k + 1..m {
+                    w = w + self.qr[(row, k)].conj() * x[(row, col)];
+                }
+                w = w * t;
+                x[(k, col)] = x[(k, col)] - w;
+                for row in k + 1..m {
+                    x[(row, col)] = x[(row, col)] - self.qr[(row, k)] * w;
+                }
+            }
+        }
+    }
+
+    fn build_q(&self, width: usize) -> Matrix<T> {
+        let m = self.qr.height;
+        let mut q = Matrix::new_of_element(width, m, T::zero()).unwrap();
+        for k in 0..width {
+            q[(k, k)] = T::one();
+        }
+        self.apply_reflectors(&mut q, (0..self.tau.len()).rev(), false);
+
+        q
+    }
+
+    /**
+     * The full m x m orthogonal factor
+     */
+    pub fn q(&self) -> Matrix<T> {
+        self.build_q(self.qr.height)
+    }
+
+    /**
+     * The first min(m, n) columns of Q
+     */
+    pub fn thin_q(&self) -> Matrix<T> {
+        self.build_q(self.tau.len())
+    }
+
+    /**
+     * The full m x n upper triangular factor
+     */
+    pub fn r(&self) -> Matrix<T> {
+        self.build_r(self.qr.height)
+    }
+
+    /**
+     * The first min(m, n) rows of R
+     */
+    pub fn thin_r(&self) -> Matrix<T> {
+        self.build_r(self.tau.len())
+    }
+
+    fn build_r(&self, height: usize) -> Matrix<T> {
+        let n = self.qr.width;
+        let mut r = Matrix::new_of_element(n, height, T::zero()).unwrap();
+        for row in 0..height.min(self.tau.len()) {
+            for col in row..n {
+                r[(row, col)] = self.qr[(row, col)];
+            }
+        }
+
+        r
+    }
+
+    /**
+     * The column permutation matrix P, so that AP = QR
+     */
+    pub fn p(&self) -> Matrix<T> {
+        let n = self.qr.width;
+        let mut p = Matrix::new_of_element(n, n, T::zero()).unwrap();
+        for (col, &original) in self.permutation.iter().enumerate() {
+            p[(original, col)] = T::one();
+        }
+
+        p
+    }
+
+    /**
+     * Column j of AP is column permutation()[j] of A
+     */
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /**
+     * The numerical rank, only reliable when the factorization was pivoted
+     */
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /**
+     * Computes Q^T b, or Q^H b for complex matrices, without forming Q
+     */
+    pub fn q_transpose_vector(&self, b: &Vector<T>) -> Result<Vector<T>, Error> {
+        if b.len() != self.qr.height {
+            return Err(Error::DimensionMismatch {
+                expected: self.qr.height,
+                found: b.len(),
+            });
+        }
+
+        let mut x = Matrix::new(1, b.len(), b.as_vec().to_vec())?;
+        self.apply_reflectors(&mut x, 0..self.tau.len(), true);
+
+        Ok(Vector::new(x.data))
+    }
+
+    /**
+     * Minimises ||Ax - b|| and returns x together with the residual norm.
+     * Columns beyond the rank are set to zero, giving a basic solution when A is
+     * rank deficient. Without pivoting that only works when the dependent columns
+     * come last, otherwise this fails with Error::Singular.
+     */
+    pub fn least_squares(&self, b: &Vector<T>) -> Result<(Vector<T>, T::Real), Error> {
+        let c = self.q_transpose_vector(b)?;
+        if !self.leading_rank {
+            return Err(Error::Singular);
+        }
+        let c = c.as_vec();
+        let n = self.qr.width;
+        let rank = self.rank;
+
+        let mut z = vec![T::zero(); n];
+        for row in (0..rank).rev() {
+            let mut value = c[row];
+            for (col, z_col) in z.iter().enumerate().take(rank).skip(row + 1) {
+                value = value - self.qr[(row, col)] * *z_col;
+            }
+            z[row] = value / self.qr[(row, row)];
+        }
+
+        let mut x = vec![T::zero(); n];
+        for (col, &original) in self.permutation.iter().enumerate() {
+            x[original] = z[col];
+        }
+
+        let mut residual = T::Real::zero();
+        for value in c.iter().skip(rank) {
+            residual = residual + value.norm_sqr();
+        }
+
+        Ok((Vector::new(x), residual.sqrt()))
+    }
+}
+
+impl<T: ComplexField> Matrix<T> {
+    pub fn qr(&self) -> QR<T> {
+        QR::new(self)
+    }
+
+    pub fn qr_pivoted(&self) -> QR<T> {
+        QR::new_pivoted(self)
+    }
+
+    pub fn least_squares(&self, b: &Vector<T>) -> Result<(Vector<T>, T::Real), Error> {
+        self.qr_pivoted().least_squares(b)
+    }
+}
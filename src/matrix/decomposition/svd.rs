@@ -0,0 +1,327 @@
+use std::cmp::Ordering;
+
+use super::QR;
+use crate::{
+    error::Error,
+    matrix::{Layout, Matrix},
+    numlib::RealField,
+    vector::Vector,
+};
+
+const MAX_SWEEPS: usize = 60;
+
+/**
+ * Singular value decomposition, A = U Σ V^T, computed with one-sided Jacobi
+ * rotations. The thin decomposition of an m x n matrix keeps k = min(m, n)
+ * columns in U and V, the full one completes both to orthogonal matrices.
+ */
+#[derive(Debug, Clone)]
+pub struct SVD<T> {
+    u: Matrix<T>,
+    singular_values: Vector<T>,
+    v: Matrix<T>,
+}
+
+impl<T: RealField> SVD<T> {
+    pub fn new(matrix: &Matrix<T>) -> Result<SVD<T>, Error> {
+        SVD::decompose(matrix, false)
+    }
+
+    /**
+     * U is m x m and V is n x n
+     */
+    pub fn new_full(matrix: &Matrix<T>) -> Result<SVD<T>, Error> {
+        SVD::decompose(matrix, true)
+    }
+
+    fn decompose(matrix: &Matrix<T>, full: bool) -> Result<SVD<T>, Error> {
+        // Work on a matrix with at least as many rows as columns, a wide matrix
+        // is decomposed through its transpose, A^T = U' Σ V'^T gives A = V' Σ U'^T
+        let tall = matrix.height >= matrix.width;
+        let (m, n) = if tall {
+            matrix.shape()
+        } else {
+            (matrix.width, matrix.height)
+        };
+
+        // The columns of A are kept as the rows of w, which rotates contiguous memory
+        let mut w = if tall {
+            matrix.transpose()
+        } else {
+            matrix.to_layout(Layout::RowMajor)
+        };
+        let mut vt = Matrix::identity(n);
+        one_sided_jacobi(&mut w, &mut vt)?;
+
+        let norms: Vec<T> = (0..n)
+            .map(|row| dot(row_of(&w, row), row_of(&w, row)).sqrt())
+            .collect();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| norms[j].partial_cmp(&norms[i]).unwrap_or(Ordering::Equal));
+
+        let left: Vec<Vec<T>> = order
+            .iter()
+            .filter(|&&j| norms[j] > T::zero())
+            .map(|&j| row_of(&w, j).iter().map(|&x| x / norms[j]).collect())
+            .collect();
+        let right: Vec<Vec<T>> = order.iter().map(|&j| row_of(&vt, j).to_vec()).collect();
+
+        let left = complete_basis(left, m, if full { m } else { n });
+        let right = complete_basis(right, n, n);
+        let singular_values = Vector::new(order.iter().map(|&j| norms[j]).collect());
+
+        let (u, v) = if tall { (left, right) } else { (right, left) };
+        Ok(SVD {
+            u,
+            singular_values,
+            v,
+        })
+    }
+
+    pub fn u(&self) -> &Matrix<T> {
+        &self.u
+    }
+
+    /**
+     * The singular values in descending order
+     */
+    pub fn singular_values(&self) -> &Vector<T> {
+        &self.singular_values
+    }
+
+    pub fn v(&self) -> &Matrix<T> {
+        &self.v
+    }
+
+    pub fn v_transpose(&self) -> Matrix<T> {
+        self.v.transpose()
+    }
+
+    /**
+     * The diagonal factor Σ, shaped to fit between U and V^T
+     */
+    pub fn sigma(&self) -> Matrix<T> {
+        let mut sigma = Matrix::new_of_element(self.v.width, self.u.width, T::zero()).unwrap();
+        for (k, &value) in self.singular_values.as_vec().iter().enumerate() {
+            sigma[(k, k)] = value;
+        }
+
+        sigma
+    }
+
+    /**
+     * The default cutoff below which singular values count as zero,
+     * ε max(m, n) σ_max
+     */
+    pub fn tolerance(&self) -> T {
+        let largest = self
+            .singular_values
+            .as_vec()
+            .first()
+            .copied()
+            .unwrap_or_else(T::zero);
+
+        T::epsilon() * T::from_usize(self.u.height.max(self.v.height)) * largest
+    }
+
+    /**
+     * The number of singular values larger than tolerance
+     */
+    pub fn rank(&self, tolerance: T) -> usize {
+        self.singular_values
+            .as_vec()
+            .iter()
+            .filter(|&&value| value > tolerance)
+            .count()
+    }
+
+    /**
+     * σ_max / σ_min in the 2-norm, infinite for a rank deficient matrix
+     */
+    pub fn condition_number(&self) -> T {
+        let values = self.singular_values.as_vec();
+        match (values.first(), values.last()) {
+            (Some(&largest), Some(&smallest)) => largest / smallest,
+            _ => T::zero(),
+        }
+    }
+
+    /**
+     * The Moore-Penrose pseudoinverse, singular values up to tolerance are
+     * treated as zero
+     */
+    pub fn pseudo_inverse(&self, tolerance: T) -> Matrix<T> {
+        let (m, n) = (self.u.height, self.v.height);
+        let mut res = Matrix::new_of_element(m, n, T::zero()).unwrap();
+        for (k, &value) in self.singular_values.as_vec().iter().enumerate() {
+            if value <= tolerance {
+                continue;
+            }
+            for row in 0..n {
+                let factor = self.v[(row, k)] / value;
+                for col in 0..m {
+                    res[(row, col)] = res[(row, col)] + factor * self.u[(col, k)];
+                }
+            }
+        }
+
+        res
+    }
+}
+
+impl<T: RealField> Matrix<T> {
+    pub fn svd(&self) -> Result<SVD<T>, Error> {
+        SVD::new(self)
+    }
+
+    pub fn svd_full(&self) -> Result<SVD<T>, Error> {
+        SVD::new_full(self)
+    }
+
+    /**
+     * Only the singular values in descending order
+     */
+    pub fn singular_values(&self) -> Result<Vector<T>, Error> {
+        Ok(self.svd()?.singular_values)
+    }
+
+    /**
+     * The pseudoinverse using the default tolerance of the decomposition
+     */
+    pub fn pseudo_inverse(&self) -> Result<Matrix<T>, Error> {
+        let svd = self.svd()?;
+
+        Ok(svd.pseudo_inverse(svd.tolerance()))
+    }
+
+    /**
+     * The number of singular values larger than tolerance
+     */
+    pub fn rank(&self, tolerance: T) -> Result<usize, Error> {
+        Ok(self.svd()?.rank(tolerance))
+    }
+
+    pub fn condition_number(&self) -> Result<T, Error> {
+        Ok(self.svd()?.condition_number())
+    }
+
+    /**
+     * An orthonormal basis of the null space as columns, the right singular
+     * vectors belonging to singular values up to tolerance
+     */
+    pub fn null_space(&self, tolerance: T) -> Result<Matrix<T>, Error> {
+        let svd = self.svd_full()?;
+        let rank = svd.rank(tolerance);
+
+        Ok(columns(&svd.v, rank, self.width))
+    }
+
+    /**
+     * An orthonormal basis of the column space as columns, the left singular
+     * vectors belonging to singular values above tolerance
+     */
+    pub fn column_space(&self, tolerance: T) -> Result<Matrix<T>, Error> {
+        let svd = self.svd()?;
+        let rank = svd.rank(tolerance);
+
+        Ok(columns(&svd.u, 0, rank))
+    }
+}
+
+fn row_of<T>(matrix: &Matrix<T>, row: usize) -> &[T] {
+    &matrix.data[row * matrix.width..(row + 1) * matrix.width]
+}
+
+fn dot<T: RealField>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b).fold(T::zero(), |acc, (&x, &y)| acc + x * y)
+}
+
+fn columns<T: Copy>(matrix: &Matrix<T>, from: usize, to: usize) -> Matrix<T> {
+    let mut data = Vec::with_capacity(matrix.height * (to - from));
+    for row in 0..matrix.height {
+        data.extend_from_slice(&matrix.data[row * matrix.width + from..row * matrix.width + to]);
+    }
+
+    Matrix::new(to - from, matrix.height, data).unwrap()
+}
+
+/**
+ * Rotates pairs of rows of w until they are all orthogonal, applying the same
+ * rotations to the rows of vt
+ */
+fn one_sided_jacobi<T: RealField>(w: &mut Matrix<T>, vt: &mut Matrix<T>) -> Result<(), Error> {
+    let n = w.height;
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for i in 0..n {
+            for j in i + 1..n {
+                let alpha = dot(row_of(w, i), row_of(w, i));
+                let beta = dot(row_of(w, j), row_of(w, j));
+                let gamma = dot(row_of(w, i), row_of(w, j));
+                if gamma == T::zero() || gamma.abs() <= T::epsilon() * alpha.sqrt() * beta.sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let mut t = T::one() / (zeta.abs() + zeta.hypot(T::one()));
+                if zeta < T::zero() {
+                    t = -t;
+                }
+                let c = T::one() / t.hypot(T::one());
+                let s = c * t;
+                rotate_rows(w, i, j, c, s);
+                rotate_rows(vt, i, j, c, s);
+            }
+        }
+
+        if !rotated {
+            return Ok(());
+        }
+    }
+
+    Err(Error::NoConvergence)
+}
+
+fn rotate_rows<T: RealField>(matrix: &mut Matrix<T>, i: usize, j: usize, c: T, s: T) {
+    let width = matrix.width;
+    let (head, tail) = matrix.data.split_at_mut(j * width);
+    let first = &mut head[i * width..(i + 1) * width];
+    let second = &mut tail[..width];
+    for (x, y) in first.iter_mut().zip(second.iter_mut()) {
+        let (a, b) = (*x, *y);
+        *x = c * a - s * b;
+        *y = s * a + c * b;
+    }
+}
+
+/**
+ * Lays out the orthonormal vectors as columns and extends them with further
+ * orthonormal columns, taken from a QR decomposition, until there are count
+ */
+fn complete_basis<T: RealField>(vectors: Vec<Vec<T>>, dimension: usize, count: usize) -> Matrix<T> {
+    let known = vectors.len();
+    let mut basis = Matrix::new_of_element(known, dimension, T::zero()).unwrap();
+    for (col, vector) in vectors.iter().enumerate() {
+        for (row, &value) in vector.iter().enumerate() {
+            basis[(row, col)] = value;
+        }
+    }
+    if known >= count {
+        return basis;
+    }
+
+    let q = QR::new(&basis).q();
+    let mut res = Matrix::new_of_element(count, dimension, T::zero()).unwrap();
+    for row in 0..dimension {
+        for col in 0..count {
+            res[(row, col)] = if col < known {
+                basis[(row, col)]
+            } else {
+                q[(row, col)]
+            };
+        }
+    }
+
+    res
+}
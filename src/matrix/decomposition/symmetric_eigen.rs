@@ -0,0 +1,351 @@
+use crate::{
+    error::Error,
+    matrix::{Layout, Matrix},
+    numlib::RealField,
+    vector::Vector,
+};
+
+const MAX_ITERATIONS_PER_EIGENVALUE: usize = 30;
+
+/**
+ * Eigendecomposition of a symmetric matrix, A = V diag(λ) V^T.
+ * Uses Householder tridiagonalization followed by the implicit QL algorithm.
+ * Only the lower triangle of A is read.
+ */
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen<T> {
+    eigenvalues: Vector<T>,
+    eigenvectors: Matrix<T>,
+}
+
+impl<T: RealField> SymmetricEigen<T> {
+    pub fn new(matrix: &Matrix<T>) -> Result<SymmetricEigen<T>, Error> {
+        let (eigenvalues, eigenvectors) = decompose(matrix, true)?;
+
+        Ok(SymmetricEigen {
+            eigenvalues: Vector::new(eigenvalues),
+            eigenvectors,
+        })
+    }
+
+    /**
+     * The eigenvalues in ascending order
+     */
+    pub fn eigenvalues(&self) -> &Vector<T> {
+        &self.eigenvalues
+    }
+
+    /**
+     * The orthonormal eigenvectors as columns, column i belongs to eigenvalue i
+     */
+    pub fn eigenvectors(&self) -> &Matrix<T> {
+        &self.eigenvectors
+    }
+
+    fn select(&self, columns: &[usize]) -> SymmetricEigen<T> {
+        let n = self.eigenvectors.height;
+        let values = self.eigenvalues.as_vec();
+        let mut vectors = Vec::with_capacity(n * columns.len());
+        for row in 0..n {
+            for &col in columns {
+                vectors.push(self.eigenvectors[(row, col)]);
+            }
+        }
+
+        SymmetricEigen {
+            eigenvalues: Vector::new(columns.iter().map(|&col| values[col]).collect()),
+            eigenvectors: Matrix::new(columns.len(), n, vectors).unwrap(),
+        }
+    }
+}
+
+impl<T: RealField> Matrix<T> {
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T>, Error> {
+        SymmetricEigen::new(self)
+    }
+
+    /**
+     * Only the eigenvalues in ascending order, which skips accumulating the
+     * eigenvectors
+     */
+    pub fn symmetric_eigenvalues(&self) -> Result<Vector<T>, Error> {
+        Ok(Vector::new(decompose(self, false)?.0))
+    }
+
+    /**
+     * The k largest eigenpairs, largest first
+     */
+    pub fn symmetric_eigen_largest(&self, k: usize) -> Result<SymmetricEigen<T>, Error> {
+        let eigen = self.symmetric_eigen()?;
+        let n = self.height;
+        if k > n {
+            return Err(Error::IndexOutOfBounds { index: k, bound: n });
+        }
+
+        Ok(eigen.select(&(n - k..n).rev().collect::<Vec<usize>>()))
+    }
+
+    /**
+     * The k smallest eigenpairs, smallest first
+     */
+    pub fn symmetric_eigen_smallest(&self, k: usize) -> Result<SymmetricEigen<T>, Error> {
+        let eigen = self.symmetric_eigen()?;
+        let n = self.height;
+        if k > n {
+            return Err(Error::IndexOutOfBounds { index: k, bound: n });
+        }
+
+        Ok(eigen.select(&(0..k).collect::<Vec<usize>>()))
+    }
+}
+
+fn decompose<T: RealField>(matrix: &Matrix<T>, vectors: bool) -> Result<(Vec<T>, Matrix<T>), Error> {
+    if matrix.width != matrix.height {
+        return Err(Error::NotSquare {
+            shape: matrix.shape(),
+        });
+    }
+
+    let n = matrix.height;
+    let mut v = matrix.to_layout(Layout::RowMajor);
+    for row in 0..n {
+        for col in row + 1..n {
+            v[(row, col)] = v[(col, row)];
+        }
+    }
+    let mut d = vec![T::zero(); n];
+    let mut e = vec![T::zero(); n];
+
+    if n > 0 {
+        tridiagonalize(&mut v, &mut d, &mut e, vectors);
+        tridiagonal_ql(&mut v, &mut d, &mut e, vectors)?;
+    }
+
+    // Selection sort so the eigenvectors can be swapped along
+    for i in 0..n {
+        let mut smallest = i;
+        for j in i + 1..n {
+            if d[j] < d[smallest] {
+                smallest = j;
+            }
+        }
+        if smallest != i {
+            d.swap(i, smallest);
+            if vectors {
+                for row in 0..n {
+                    v.data.swap(row * n + i, row * n + smallest);
+                }
+            }
+        }
+    }
+
+    Ok((d, v))
+}
+
+/**
+ * Householder reduction to tridiagonal form, leaving the diagonal in d and the
+ * subdiagonal in e[1..]. Based on tred2 from EISPACK by way of JAMA.
+ */
+fn tridiagonalize<T: RealField>(v: &mut Matrix<T>, d: &mut [T], e: &mut [T], vectors: bool) {
+    let n = d.len();
+    for j in 0..n {
+        d[j] = v[(n - 1, j)];
+    }
+
+    for i in (1..n).rev() {
+        let mut scale = T::zero();
+        let mut h = T::zero();
+        for value in d.iter().take(i) {
+            scale = scale + value.abs();
+        }
+
+        if scale == T::zero() {
+            e[i] = d[i - 1];
+            for j in 0..i {
+                d[j] = v[(i - 1, j)];
+                v[(i, j)] = T::zero();
+                v[(j, i)] = T::zero();
+            }
+        } else {
+            for value in d.iter_mut().take(i) {
+                *value = *value / scale;
+                h = h + *value * *value;
+            }
+            let f = d[i - 1];
+            let mut g = h.sqrt();
+            if f > T::zero() {
+                g = -g;
+            }
+            e[i] = scale * g;
+            h = h - f * g;
+            d[i - 1] = f - g;
+            for value in e.iter_mut().take(i) {
+                *value = T::zero();
+            }
+
+            for j in 0..i {
+                let f = d[j];
+                v[(j, i)] = f;
+                let mut g = e[j] + v[(j, j)] * f;
+                for k in j + 1..i {
+                    g = g + v[(k, j)] * d[k];
+                    e[k] = e[k] + v[(k, j)] * f;
+                }
+                e[j] = g;
+            }
+
+            let mut f = T::zero();
+            for j in 0..i {
+                e[j] = e[j] / h;
+                f = f + e[j] * d[j];
+            }
+            let hh = f / (h + h);
+            for j in 0..i {
+                e[j] = e[j] - hh * d[j];
+            }
+            for j in 0..i {
+                let f = d[j];
+                let g = e[j];
+                for k in j..i {
+                    v[(k, j)] = v[(k, j)] - (f * e[k] + g * d[k]);
+                }
+                d[j] = v[(i - 1, j)];
+                v[(i, j)] = T::zero();
+            }
+        }
+        d[i] = h;
+    }
+
+    if !vectors {
+        for (j, value) in d.iter_mut().enumerate() {
+            *value = v[(j, j)];
+        }
+        e[0] = T::zero();
+        return;
+    }
+
+    // Accumulate the transformations
+    for i in 0..n - 1 {
+        v[(n - 1, i)] = v[(i, i)];
+        v[(i, i)] = T::one();
+        let h = d[i + 1];
+        if h != T::zero() {
+            for k in 0..=i {
+                d[k] = v[(k, i + 1)] / h;
+            }
+            for j in 0..=i {
+                let mut g = T::zero();
+                for k in 0..=i {
+                    g = g + v[(k, i + 1)] * v[(k, j)];
+                }
+                for k in 0..=i {
+                    v[(k, j)] = v[(k, j)] - g * d[k];
+                }
+            }
+        }
+        for k in 0..=i {
+            v[(k, i + 1)] = T::zero();
+        }
+    }
+    for j in 0..n {
+        d[j] = v[(n - 1, j)];
+        v[(n - 1, j)] = T::zero();
+    }
+    v[(n - 1, n - 1)] = T::one();
+    e[0] = T::zero();
+}
+
+/**
+ * Implicit QL iterations on the tridiagonal matrix, based on tql2 from EISPACK
+ * by way of JAMA
+ */
+fn tridiagonal_ql<T: RealField>(
+    v: &mut Matrix<T>,
+    d: &mut [T],
+    e: &mut [T],
+    vectors: bool,
+) -> Result<(), Error> {
+    let n = d.len();
+    for i in 1..n {
+        e[i - 1] = e[i];
+    }
+    e[n - 1] = T::zero();
+
+    let mut f = T::zero();
+    let mut tst1 = T::zero();
+    let eps = T::epsilon();
+    for l in 0..n {
+        if d[l].abs() + e[l].abs() > tst1 {
+            tst1 = d[l].abs() + e[l].abs();
+        }
+        let mut m = l;
+        while m < n - 1 && e[m].abs() > eps * tst1 {
+            m += 1;
+        }
+
+        if m > l {
+            let mut iterations = 0;
+            loop {
+                iterations += 1;
+                if iterations > MAX_ITERATIONS_PER_EIGENVALUE {
+                    return Err(Error::NoConvergence);
+                }
+
+                let g = d[l];
+                let mut p = (d[l + 1] - g) / (e[l] + e[l]);
+                let mut r = p.hypot(T::one());
+                if p < T::zero() {
+                    r = -r;
+                }
+                d[l] = e[l] / (p + r);
+                d[l + 1] = e[l] * (p + r);
+                let dl1 = d[l + 1];
+                let h = g - d[l];
+                for value in d.iter_mut().skip(l + 2) {
+                    *value = *value - h;
+                }
+                f = f + h;
+
+                p = d[m];
+                let mut c = T::one();
+                let mut c2 = c;
+                let mut c3 = c;
+                let el1 = e[l + 1];
+                let mut s = T::zero();
+                let mut s2 = T::zero();
+                for i in (l..m).rev() {
+                    c3 = c2;
+                    c2 = c;
+                    s2 = s;
+                    let g = c * e[i];
+                    let h = c * p;
+                    r = p.hypot(e[i]);
+                    e[i + 1] = s * r;
+                    s = e[i] / r;
+                    c = p / r;
+                    p = c * d[i] - s * g;
+                    d[i + 1] = h + s * (c * g + s * d[i]);
+
+                    if vectors {
+                        for k in 0..n {
+                            let h = v[(k, i + 1)];
+                            v[(k, i + 1)] = s * v[(k, i)] + c * h;
+                            v[(k, i)] = c * v[(k, i)] - s * h;
+                        }
+                    }
+                }
+                p = -s * s2 * c3 * el1 * e[l] / dl1;
+                e[l] = s * p;
+                d[l] = c * p;
+
+                if e[l].abs() <= eps * tst1 {
+                    break;
+                }
+            }
+        }
+        d[l] = d[l] + f;
+        e[l] = T::zero();
+    }
+
+    Ok(())
+}
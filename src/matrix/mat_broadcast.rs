@@ -0,0 +1,158 @@
+use super::{AsMatrixView, Matrix, MatrixView, MatrixViewMut};
+use crate::{arch, error::Error, numlib::Ring, parallel, vector::Vector};
+
+/**
+ * The shape two operands stretch to, the way NumPy does it: sides that match
+ * are kept and a side of 1 takes the size of the other
+ */
+pub(crate) fn broadcast_shape(
+    lhs: (usize, usize),
+    rhs: (usize, usize),
+) -> Result<(usize, usize), Error> {
+    let side = |a: usize, b: usize| match (a, b) {
+        _ if a == b => Some(a),
+        (1, _) => Some(b),
+        (_, 1) => Some(a),
+        _ => None,
+    };
+
+    match (side(lhs.0, rhs.0), side(lhs.1, rhs.1)) {
+        (Some(height), Some(width)) => Ok((height, width)),
+        _ => Err(Error::NotBroadcastable { lhs, rhs }),
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    /**
+     * The view stretched to the given shape without copying. A single row or
+     * column repeats by getting a stride of 0.
+     */
+    pub fn broadcast_to(
+        &self,
+        (height, width): (usize, usize),
+    ) -> Result<MatrixView<'a, T>, Error> {
+        let stride = |side: usize, target: usize, stride: usize| match side {
+            _ if side == target => Some(stride),
+            1 => Some(0),
+            _ => None,
+        };
+
+        match (
+            stride(self.height, height, self.row_stride),
+            stride(self.width, width, self.col_stride),
+        ) {
+            (Some(row_stride), Some(col_stride)) => Ok(MatrixView {
+                data: self.data,
+                width,
+                height,
+                row_stride,
+                col_stride,
+            }),
+            _ => Err(Error::NotBroadcastable {
+                lhs: self.shape(),
+                rhs: (height, width),
+            }),
+        }
+    }
+}
+
+impl<T: Copy> MatrixView<'_, T> {
+    /**
+     * f of the entries at the same position once both operands are broadcast to
+     * their common shape
+     */
+    pub(crate) fn broadcast_zip<M: AsMatrixView<T> + ?Sized, F: Fn(T, T) -> T>(
+        &self,
+        other: &M,
+        f: F,
+    ) -> Result<Matrix<T>, Error> {
+        let other = other.as_view();
+        let shape = broadcast_shape(self.shape(), other.shape())?;
+        let (lhs, rhs) = (self.broadcast_to(shape)?, other.broadcast_to(shape)?);
+        let data = lhs.iter().zip(rhs.iter()).map(|(&a, &b)| f(a, b)).collect();
+
+        Ok(Matrix::new(shape.1, shape.0, data).unwrap())
+    }
+}
+
+impl<T> MatrixViewMut<'_, T> {
+    /**
+     * Like zip_apply, with other broadcast to the shape of self first
+     */
+    pub(crate) fn broadcast_apply<M: AsMatrixView<T> + ?Sized, F: Fn(&mut T, &T)>(
+        &mut self,
+        other: &M,
+        f: F,
+    ) -> Result<(), Error> {
+        let other = other.as_view();
+        let stretched = other
+            .broadcast_to(self.shape())
+            .map_err(|_| Error::NotBroadcastable {
+                lhs: self.shape(),
+                rhs: other.shape(),
+            })?;
+
+        self.zip_apply(&stretched, f)
+    }
+}
+
+impl<T: Ring> MatrixView<'_, T> {
+    /**
+     * Adds the vector to every row, without expanding it into a matrix first
+     */
+    pub fn add_row_broadcast(&self, row: &Vector<T>) -> Result<Matrix<T>, Error> {
+        if row.len() != self.width {
+            return Err(Error::NotBroadcastable {
+                lhs: self.shape(),
+                rhs: (1, row.len()),
+            });
+        }
+
+        let mut res = self.to_matrix();
+        if self.width > 0 {
+            parallel::run(res.size, |parallel| {
+                parallel::for_each_chunk(parallel, &mut res.data, self.width, |_, values| {
+                    arch::add_assign(values, row.as_vec())
+                })
+            });
+        }
+
+        Ok(res)
+    }
+
+    /**
+     * Adds the vector to every column, without expanding it into a matrix first
+     */
+    pub fn add_col_broadcast(&self, col: &Vector<T>) -> Result<Matrix<T>, Error> {
+        if col.len() != self.height {
+            return Err(Error::NotBroadcastable {
+                lhs: self.shape(),
+                rhs: (col.len(), 1),
+            });
+        }
+
+        let mut res = self.to_matrix();
+        if self.width > 0 {
+            parallel::run(res.size, |parallel| {
+                parallel::for_each_chunk(parallel, &mut res.data, self.width, |row, values| {
+                    let value = col.as_vec()[row];
+                    for entry in values {
+                        *entry = *entry + value;
+                    }
+                })
+            });
+        }
+
+        Ok(res)
+    }
+}
+
+impl<T: Ring> Matrix<T> {
+    pub fn add_row_broadcast(&self, row: &Vector<T>) -> Result<Matrix<T>, Error> {
+        self.as_view().add_row_broadcast(row)
+    }
+
+    pub fn add_col_broadcast(&self, col: &Vector<T>) -> Result<Matrix<T>, Error> {
+        self.as_view().add_col_broadcast(col)
+    }
+}
@@ -21,7 +21,7 @@ impl<T: Display> Display for Matrix<T> {
         };
 
         let to_display = self
-            .data
+            .as_view()
             .iter()
             .map(|entry| format!("{: <width$}", entry, width = max_size))
             .collect::<Vec<String>>();
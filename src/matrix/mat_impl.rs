@@ -1,14 +1,34 @@
-extern crate rayon;
+use std::borrow::Cow;
+use std::ops::{Index, IndexMut};
 
-use std::ops::{Add, Index, IndexMut, Mul};
-
-use super::Matrix;
-use crate::{numlib::Zero, vector::Vector};
+use super::{AsMatrixView, Layout, Matrix, MatrixView};
+use crate::{
+    error::Error,
+    numlib::{Field, One, Ring, Zero},
+    parallel,
+    vector::Vector,
+};
 
 impl<T> Matrix<T> {
-    pub fn new(width: usize, height: usize, data: Vec<T>) -> Result<Matrix<T>, &'static str> {
+    pub fn new(width: usize, height: usize, data: Vec<T>) -> Result<Matrix<T>, Error> {
+        Matrix::new_with_layout(width, height, data, Layout::RowMajor)
+    }
+
+    /**
+     * Takes data ordered according to layout, so column-major buffers can be used
+     * as they are
+     */
+    pub fn new_with_layout(
+        width: usize,
+        height: usize,
+        data: Vec<T>,
+        layout: Layout,
+    ) -> Result<Matrix<T>, Error> {
         if width * height != data.len() {
-            return Err("Dimensions don't match the given data");
+            return Err(Error::DimensionMismatch {
+                expected: width * height,
+                found: data.len(),
+            });
         }
 
         Ok(Matrix {
@@ -16,13 +36,28 @@ impl<T> Matrix<T> {
             height,
             size: width * height,
             data,
+            layout,
         })
     }
 
+    /**
+     * The entries in storage order, see layout
+     */
     pub fn as_vec(&self) -> &Vec<T> {
         &self.data
     }
 
+    pub const fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    const fn position(&self, row: usize, col: usize) -> usize {
+        match self.layout {
+            Layout::RowMajor => row * self.width + col,
+            Layout::ColumnMajor => col * self.height + row,
+        }
+    }
+
     pub const fn width(&self) -> usize {
         self.width
     }
@@ -41,12 +76,13 @@ impl<T: Copy> Matrix<T> {
         width: usize,
         height: usize,
         element: T,
-    ) -> Result<Matrix<T>, &'static str> {
+    ) -> Result<Matrix<T>, Error> {
         Ok(Matrix {
             width,
             height,
             size: width * height,
             data: vec![element; width * height],
+            layout: Layout::RowMajor,
         })
     }
 
@@ -60,6 +96,7 @@ impl<T: Copy> Matrix<T> {
             height,
             size: width * height,
             data: (0..width * height).map(|_| supplier()).collect(),
+            layout: Layout::RowMajor,
         }
     }
 
@@ -69,19 +106,15 @@ impl<T: Copy> Matrix<T> {
         }
     }
 
-    pub fn get_col(&self, number: usize) -> Result<Vector<T>, &'static str> {
+    pub fn get_col(&self, number: usize) -> Result<Vector<T>, Error> {
         if number >= self.width {
-            return Err("This column isn't present in this matrix");
-        }
-
-        let mut vector: Vec<T> = Vec::with_capacity(self.height);
-        let mut index = number;
-        while index < self.size {
-            vector.push(self.data[index]);
-            index += self.width; // Add width to get next value
+            return Err(Error::IndexOutOfBounds {
+                index: number,
+                bound: self.width,
+            });
         }
 
-        Ok(Vector::new(vector))
+        Ok(Vector::new(self.col_view(number)?.iter().copied().collect()))
     }
 
     pub fn get_cols(&self) -> Vec<Vector<T>> {
@@ -90,21 +123,18 @@ impl<T: Copy> Matrix<T> {
             res.push(self.get_col(w).unwrap());
         }
 
-        return res;
+        res
     }
 
-    pub fn get_row(&self, number: usize) -> Result<Vector<T>, &'static str> {
+    pub fn get_row(&self, number: usize) -> Result<Vector<T>, Error> {
         if number >= self.height {
-            return Err("This row isn't present in this matrix");
+            return Err(Error::IndexOutOfBounds {
+                index: number,
+                bound: self.height,
+            });
         }
 
-        let mut vector: Vec<T> = Vec::with_capacity(self.width);
-        let index = number * self.width;
-        for offset in 0..self.width {
-            vector.push(self.data[index + offset]);
-        }
-
-        Ok(Vector::new(vector))
+        Ok(Vector::new(self.row_view(number)?.iter().copied().collect()))
     }
 
     pub fn get_rows(&self) -> Vec<Vector<T>> {
@@ -113,79 +143,152 @@ impl<T: Copy> Matrix<T> {
             res.push(self.get_row(w).unwrap());
         }
 
-        return res;
+        res
     }
 
     pub fn transpose(&self) -> Matrix<T> {
-        let mut list = Vec::with_capacity(self.size);
-        for col in self.get_cols() {
-            list.extend(col.as_vec())
+        self.transpose_view().to_matrix()
+    }
+
+    /**
+     * A copy with the entries reordered to the given layout
+     */
+    pub fn to_layout(&self, layout: Layout) -> Matrix<T> {
+        if self.layout == layout {
+            return self.clone();
         }
 
-        let width = self.height;
-        let height = self.width;
+        let data = match layout {
+            Layout::RowMajor => self.as_view().iter().copied().collect(),
+            Layout::ColumnMajor => self.transpose_view().iter().copied().collect(),
+        };
+        Matrix::new_with_layout(self.width, self.height, data, layout).unwrap()
+    }
 
-        return Matrix::new(width, height, list).unwrap();
+    /**
+     * Reorders the entries to the given layout, which is free when it already matches
+     */
+    pub fn into_layout(self, layout: Layout) -> Matrix<T> {
+        if self.layout == layout {
+            self
+        } else {
+            self.to_layout(layout)
+        }
     }
 }
 
-impl<T: PartialOrd + Copy> Matrix<T> {
-    pub fn max(&self) -> T {
-        *self.data.iter().max_by(|a,b| a.partial_cmp(b).unwrap()).unwrap()
+impl<T: Sync> Matrix<T> {
+    /**
+     * A matrix of f of every entry, which may be of another type. The layout
+     * is kept.
+     */
+    pub fn map<U: Send, F: Fn(&T) -> U + Send + Sync>(&self, f: F) -> Matrix<U> {
+        Matrix {
+            width: self.width,
+            height: self.height,
+            size: self.size,
+            data: parallel::map_slice(&self.data, f),
+            layout: self.layout,
+        }
     }
 
-    pub fn min(&self) -> T {
-        *self.data.iter().min_by(|a,b| a.partial_cmp(b).unwrap()).unwrap()
+    /**
+     * The entries of a view of the same shape in the order of self's data
+     */
+    fn same_order<'a, U: Copy>(&self, other: &MatrixView<'a, U>) -> Result<Cow<'a, [U]>, Error> {
+        if self.shape() != other.shape() {
+            return Err(Error::ShapeMismatch {
+                expected: self.shape(),
+                found: other.shape(),
+            });
+        }
+
+        Ok(match self.layout {
+            Layout::RowMajor => other.row_major(),
+            Layout::ColumnMajor => other.transpose().row_major(),
+        })
+    }
+
+    /**
+     * A matrix of f of every entry and the entry at the same position of other,
+     * in the layout of self
+     */
+    pub fn zip_map<U, V, M, F>(&self, other: &M, f: F) -> Result<Matrix<V>, Error>
+    where
+        U: Copy + Sync,
+        V: Send,
+        M: AsMatrixView<U> + ?Sized,
+        F: Fn(&T, &U) -> V + Send + Sync,
+    {
+        let other = self.same_order(&other.as_view())?;
+
+        Ok(Matrix {
+            width: self.width,
+            height: self.height,
+            size: self.size,
+            data: parallel::zip_map(&self.data, &other, f),
+            layout: self.layout,
+        })
     }
 }
 
-impl<T: Copy + Zero + Add<T, Output = T> + Mul<T, Output = T> + std::ops::Sub<Output = T>>
-    Matrix<T>
-{
-    pub fn product_vector(&self, vector: &Vector<T>) -> Result<Vector<T>, &'static str> {
-        if self.width != vector.len() {
-            return Err("Vector and matrix have mismatched sizes");
-        }
-        let mut res = Vec::with_capacity(self.height);
-        for row in self.get_rows() {
-            res.push(row.dot(vector).unwrap());
+impl<T: Send + Sync> Matrix<T> {
+    /**
+     * Replaces every entry by f of it and the entry at the same position of other
+     */
+    pub fn zip_apply<U, M, F>(&mut self, other: &M, f: F) -> Result<(), Error>
+    where
+        U: Copy + Sync,
+        M: AsMatrixView<U> + ?Sized,
+        F: Fn(&T, &U) -> T + Send + Sync,
+    {
+        let other = self.same_order(&other.as_view())?;
+        parallel::zip_apply(&mut self.data, &other, f);
+
+        Ok(())
+    }
+}
+
+impl<T: Zero + One + Copy> Matrix<T> {
+    pub fn identity(n: usize) -> Matrix<T> {
+        let mut identity = Matrix::new_of_element(n, n, T::zero()).unwrap();
+        for k in 0..n {
+            identity[(k, k)] = T::one();
         }
 
-        Ok(Vector::new(res))
+        identity
     }
+}
 
-    pub fn add(&self, other: &Matrix<T>) -> Result<Matrix<T>, &'static str> {
-        if self.shape() != other.shape() {
-            return Err("Matrices have mismatched sizes");
-        }
+impl<T: Ring> Matrix<T> {
+    /**
+     * The conjugate transpose, the same as transpose for real matrices
+     */
+    pub fn adjoint(&self) -> Matrix<T> {
+        let mut adjoint = self.transpose();
+        adjoint.apply(|x| x.conj());
 
-        let mut res: Vec<T> = Vec::with_capacity(self.size);
-        for (i, element) in self.data.iter().enumerate() {
-            res.push(*element + *other.data.get(i).unwrap());
-        }
+        adjoint
+    }
 
-        Matrix::new(self.width, self.height, res)
+    pub fn product_vector(&self, vector: &Vector<T>) -> Result<Vector<T>, Error> {
+        self.as_view().product_vector(vector)
     }
 
-    pub fn sub(&self, other: &Matrix<T>) -> Result<Matrix<T>, &'static str> {
-        if self.shape() != other.shape() {
-            return Err("Matrices have mismatched sizes");
-        }
+    pub fn add<M: AsMatrixView<T> + ?Sized>(&self, other: &M) -> Result<Matrix<T>, Error> {
+        self.as_view().add(other)
+    }
 
-        let mut res: Vec<T> = Vec::with_capacity(self.size);
-        for (i, element) in self.data.iter().enumerate() {
-            res.push(*element - *other.data.get(i).unwrap());
-        }
+    pub fn sub<M: AsMatrixView<T> + ?Sized>(&self, other: &M) -> Result<Matrix<T>, Error> {
+        self.as_view().sub(other)
+    }
 
-        Matrix::new(self.width, self.height, res)
+    pub fn hadamard<M: AsMatrixView<T> + ?Sized>(&self, other: &M) -> Result<Matrix<T>, Error> {
+        self.as_view().hadamard(other)
     }
 
     pub fn sum(&self) -> T {
-        let mut accumulator: T = T::zero();
-        for el in self.data.iter() {
-            accumulator = accumulator + *el;
-        }
-        accumulator
+        parallel::sum(&self.data)
     }
 
     pub fn sum_columns(&self) -> Matrix<T> {
@@ -193,12 +296,12 @@ impl<T: Copy + Zero + Add<T, Output = T> + Mul<T, Output = T> + std::ops::Sub<Ou
         for column in 0..self.width {
             let mut accumulator: T = T::zero();
             for k in 0..self.height {
-                accumulator = accumulator + self.data[k * self.width + column];
+                accumulator = accumulator + self[(k, column)];
             }
             sum.push(accumulator);
         }
 
-        return Matrix::new(self.width, 1, sum).unwrap();
+        Matrix::new(self.width, 1, sum).unwrap()
     }
 
     pub fn sum_rows(&self) -> Vector<T> {
@@ -206,31 +309,41 @@ impl<T: Copy + Zero + Add<T, Output = T> + Mul<T, Output = T> + std::ops::Sub<Ou
         for row in 0..self.height {
             let mut accumulator: T = T::zero();
             for k in 0..self.width {
-                accumulator = accumulator + self.data[row * self.width + k];
+                accumulator = accumulator + self[(row, k)];
             }
             sum.push(accumulator);
         }
 
-        return Vector::new(sum);
+        Vector::new(sum)
     }
 
     pub fn scale(&self, scalar: T) -> Matrix<T> {
         let mut scaled = self.clone();
-        scaled.apply(|&x| x*scalar);
+        parallel::scale(&mut scaled.data, scalar);
 
         scaled
     }
 }
 
+impl<T: Field> Matrix<T> {
+    pub fn elementwise_div<M: AsMatrixView<T> + ?Sized>(
+        &self,
+        other: &M,
+    ) -> Result<Matrix<T>, Error> {
+        self.as_view().elementwise_div(other)
+    }
+}
+
 impl<T> Index<(usize, usize)> for Matrix<T> {
     type Output = T;
     fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
-        &self.data[row * self.width + col]
+        &self.data[self.position(row, col)]
     }
 }
 
 impl<T> IndexMut<(usize, usize)> for Matrix<T> {
     fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
-        &mut self.data[row * self.width + col]
+        let position = self.position(row, col);
+        &mut self.data[position]
     }
 }
@@ -0,0 +1,226 @@
+use crate::{
+    arch::{self, MR, NR},
+    matrix::MatrixView,
+    numlib::Ring,
+    parallel,
+};
+
+/**
+ * Blocking parameters of the GotoBLAS scheme. A packed MC x KC block of A is
+ * meant to stay in L2 and a packed KC x NR sliver of B in L1, while the
+ * micro-kernel accumulates an MR x NR tile of C in registers.
+ */
+const MC: usize = 64;
+const KC: usize = 256;
+const NC: usize = 2048;
+
+/**
+ * Below this many multiply-adds packing costs more than it saves
+ */
+const SMALL: usize = 32 * 32 * 32;
+
+/**
+ * One factor of the product, conjugated entry by entry as it is packed when
+ * conjugate is set
+ */
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Operand<'a, T> {
+    pub(crate) view: MatrixView<'a, T>,
+    pub(crate) conjugate: bool,
+}
+
+impl<'a, T: Ring> Operand<'a, T> {
+    pub(crate) fn new(view: MatrixView<'a, T>) -> Operand<'a, T> {
+        Operand {
+            view,
+            conjugate: false,
+        }
+    }
+
+    pub(crate) fn transpose(self) -> Operand<'a, T> {
+        Operand {
+            view: self.view.transpose(),
+            conjugate: self.conjugate,
+        }
+    }
+
+    pub(crate) fn get(&self, row: usize, col: usize) -> T {
+        let entry = self.view.data[row * self.view.row_stride + col * self.view.col_stride];
+        if self.conjugate {
+            entry.conj()
+        } else {
+            entry
+        }
+    }
+}
+
+/**
+ * C += alpha AB where C is m x n, stored row by row with ldc entries between the
+ * starts of consecutive rows. A and B may have any strides, they are packed into
+ * contiguous panels before the micro-kernel touches them.
+ */
+pub(crate) fn gemm<T: Ring>(
+    alpha: T,
+    a: Operand<'_, T>,
+    b: Operand<'_, T>,
+    c: &mut [T],
+    ldc: usize,
+) {
+    let work = a.view.height * a.view.width * b.view.width;
+    parallel::run(work, |parallel| gemm_with(parallel, alpha, a, b, c, ldc))
+}
+
+/**
+ * gemm for callers that already know whether to run in parallel
+ */
+pub(crate) fn gemm_with<T: Ring>(
+    parallel: bool,
+    alpha: T,
+    a: Operand<'_, T>,
+    b: Operand<'_, T>,
+    c: &mut [T],
+    ldc: usize,
+) {
+    let (m, k, n) = (a.view.height, a.view.width, b.view.width);
+    if m == 0 || n == 0 || k == 0 {
+        return;
+    }
+
+    // Conjugating does nothing to the real types BLAS takes
+    #[cfg(feature = "blas")]
+    if crate::matrix::blas::gemm(alpha, a.view, b.view, c, ldc).is_some() {
+        return;
+    }
+
+    if m * n * k <= SMALL {
+        for row in 0..m {
+            let out = &mut c[row * ldc..row * ldc + n];
+            for index in 0..k {
+                let factor = alpha * a.get(row, index);
+                for (col, entry) in out.iter_mut().enumerate() {
+                    *entry = *entry + factor * b.get(index, col);
+                }
+            }
+        }
+        return;
+    }
+
+    // The buffers live for this call only, so a large product doesn't leave
+    // megabytes of packed panels behind on every thread that ran it
+    let mut packed_b = Vec::new();
+    for jc in (0..n).step_by(NC) {
+        let nc = NC.min(n - jc);
+        for pc in (0..k).step_by(KC) {
+            let kc = KC.min(k - pc);
+            pack_b(&mut packed_b, b, pc, kc, jc, nc);
+
+            // Every macro-tile of rows of C is independent, so they are shared
+            // out over the thread pool, each packing its own block of A
+            let packed_b = &packed_b;
+            parallel::for_each_chunk_init(parallel, c, MC * ldc, Vec::new, |packed_a, block, c| {
+                let ic = block * MC;
+                let mc = MC.min(m - ic);
+                pack_a(packed_a, alpha, a, ic, mc, pc, kc);
+                macro_kernel(packed_a, packed_b, mc, nc, kc, &mut c[jc..], ldc);
+            });
+        }
+    }
+}
+
+/**
+ * Packs A[ic..ic + mc, pc..pc + kc], scaled by alpha, into slivers of MR rows
+ * stored column by column and padded with zeros
+ */
+fn pack_a<T: Ring>(
+    packed: &mut Vec<T>,
+    alpha: T,
+    a: Operand<'_, T>,
+    ic: usize,
+    mc: usize,
+    pc: usize,
+    kc: usize,
+) {
+    packed.clear();
+    for ir in (0..mc).step_by(MR) {
+        let mr = MR.min(mc - ir);
+        for p in 0..kc {
+            for i in 0..MR {
+                packed.push(if i < mr {
+                    alpha * a.get(ic + ir + i, pc + p)
+                } else {
+                    T::zero()
+                });
+            }
+        }
+    }
+}
+
+/**
+ * Packs B[pc..pc + kc, jc..jc + nc] into slivers of NR columns stored row by
+ * row and padded with zeros
+ */
+fn pack_b<T: Ring>(
+    packed: &mut Vec<T>,
+    b: Operand<'_, T>,
+    pc: usize,
+    kc: usize,
+    jc: usize,
+    nc: usize,
+) {
+    packed.clear();
+    for jr in (0..nc).step_by(NR) {
+        let nr = NR.min(nc - jr);
+        for p in 0..kc {
+            for j in 0..NR {
+                packed.push(if j < nr {
+                    b.get(pc + p, jc + jr + j)
+                } else {
+                    T::zero()
+                });
+            }
+        }
+    }
+}
+
+fn macro_kernel<T: Ring>(
+    packed_a: &[T],
+    packed_b: &[T],
+    mc: usize,
+    nc: usize,
+    kc: usize,
+    c: &mut [T],
+    ldc: usize,
+) {
+    for jr in (0..nc).step_by(NR) {
+        let nr = NR.min(nc - jr);
+        let b = &packed_b[jr * kc..(jr + NR) * kc];
+        for ir in (0..mc).step_by(MR) {
+            let mr = MR.min(mc - ir);
+            let a = &packed_a[ir * kc..(ir + MR) * kc];
+            micro_kernel(a, b, kc, &mut c[ir * ldc + jr..], ldc, mr, nr);
+        }
+    }
+}
+
+/**
+ * Computes an MR x NR tile with the SIMD kernels when there are any for T, then
+ * adds the part that lies inside C
+ */
+fn micro_kernel<T: Ring>(
+    a: &[T],
+    b: &[T],
+    kc: usize,
+    c: &mut [T],
+    ldc: usize,
+    mr: usize,
+    nr: usize,
+) {
+    let mut tile = [T::zero(); MR * NR];
+    arch::micro_kernel(a, b, kc, &mut tile);
+
+    for (i, row) in tile.chunks_exact(NR).enumerate().take(mr) {
+        for (entry, &value) in c[i * ldc..i * ldc + nr].iter_mut().zip(row) {
+            *entry = *entry + value;
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use super::blocked::{self, Operand};
+use crate::{
+    arch,
+    error::Error,
+    matrix::{AsMatrixView, Layout, Matrix, MatrixView, Op},
+    numlib::Ring,
+    parallel,
+    vector::Vector,
+};
+
+/**
+ * Rows of y per block when gemv runs in parallel
+ */
+const ROWS: usize = 64;
+
+fn operand<T: Ring>(view: MatrixView<'_, T>, op: Op) -> Operand<'_, T> {
+    match op {
+        Op::NoTranspose => Operand::new(view),
+        Op::Transpose => Operand::new(view.transpose()),
+        Op::ConjugateTranspose => Operand {
+            view: view.transpose(),
+            conjugate: true,
+        },
+    }
+}
+
+/**
+ * Multiplies the output by beta before anything is added to it. Like in BLAS a
+ * zero beta overwrites the output, so whatever it held, NaN included, is ignored.
+ */
+fn scale_output<T: Ring>(data: &mut [T], beta: T) {
+    if beta == T::zero() {
+        data.fill(T::zero());
+    } else if beta != T::one() {
+        parallel::scale(data, beta);
+    }
+}
+
+/**
+ * In place products in the style of BLAS, which write into storage the caller
+ * owns and so let hot loops run without allocating
+ */
+impl<T: Ring> Matrix<T> {
+    /**
+     * C = alpha op(A) op(B) + beta C, where op(A) is m x k, op(B) is k x n and
+     * C is m x n
+     */
+    pub fn gemm<A: AsMatrixView<T> + ?Sized, B: AsMatrixView<T> + ?Sized>(
+        alpha: T,
+        a: &A,
+        op_a: Op,
+        b: &B,
+        op_b: Op,
+        beta: T,
+        c: &mut Matrix<T>,
+    ) -> Result<(), Error> {
+        let a = operand(a.as_view(), op_a);
+        let b = operand(b.as_view(), op_b);
+        if a.view.width != b.view.height {
+            return Err(Error::DimensionMismatch {
+                expected: a.view.width,
+                found: b.view.height,
+            });
+        }
+        let shape = (a.view.height, b.view.width);
+        if c.shape() != shape {
+            return Err(Error::ShapeMismatch {
+                expected: shape,
+                found: c.shape(),
+            });
+        }
+
+        scale_output(&mut c.data, beta);
+        if alpha == T::zero() {
+            return Ok(());
+        }
+
+        match c.layout {
+            Layout::RowMajor => blocked::gemm(alpha, a, b, &mut c.data, c.width),
+            // The transpose of a column-major C is row-major, so compute
+            // C^T += alpha op(B)^T op(A)^T instead
+            Layout::ColumnMajor => {
+                blocked::gemm(alpha, b.transpose(), a.transpose(), &mut c.data, c.height)
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+     * y = alpha op(A) x + beta y, where op(A) is m x n, x has length n and y
+     * length m
+     */
+    pub fn gemv<A: AsMatrixView<T> + ?Sized>(
+        alpha: T,
+        a: &A,
+        op_a: Op,
+        x: &Vector<T>,
+        beta: T,
+        y: &mut Vector<T>,
+    ) -> Result<(), Error> {
+        let a = operand(a.as_view(), op_a);
+        let (m, n) = a.view.shape();
+        if x.len() != n {
+            return Err(Error::DimensionMismatch {
+                expected: n,
+                found: x.len(),
+            });
+        }
+        if y.len() != m {
+            return Err(Error::DimensionMismatch {
+                expected: m,
+                found: y.len(),
+            });
+        }
+
+        let x = x.as_vec();
+        let y = y.as_mut_vec();
+        scale_output(y, beta);
+        if alpha == T::zero() || n == 0 {
+            return Ok(());
+        }
+
+        #[cfg(feature = "blas")]
+        if crate::matrix::blas::gemv(alpha, a.view, x, y).is_some() {
+            return Ok(());
+        }
+
+        // Rows of y are independent, blocks of them go to different threads
+        let view = a.view;
+        parallel::run(m * n, |parallel| {
+            parallel::for_each_chunk(parallel, y, ROWS, |block, y| {
+                let first = block * ROWS;
+                if view.col_stride == 1 {
+                    // Contiguous rows, every entry of y is a dot product
+                    for (row, entry) in (first..).zip(y.iter_mut()) {
+                        let start = row * view.row_stride;
+                        let row = &view.data[start..start + n];
+                        let dot = if a.conjugate {
+                            arch::dotc(row, x)
+                        } else {
+                            arch::dot(row, x)
+                        };
+                        *entry = *entry + alpha * dot;
+                    }
+                } else {
+                    // Otherwise y gathers the columns of op(A) scaled by the entries of x
+                    for (col, &x) in x.iter().enumerate() {
+                        let factor = alpha * x;
+                        for (row, entry) in (first..).zip(y.iter_mut()) {
+                            *entry = *entry + a.get(row, col) * factor;
+                        }
+                    }
+                }
+            })
+        });
+
+        Ok(())
+    }
+}
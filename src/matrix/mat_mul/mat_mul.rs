@@ -1,47 +1,30 @@
-use crate::matrix::Matrix;
+use super::blocked::{self, Operand};
+use super::strassen::{self, DEFAULT_CUTOFF};
+use crate::matrix::{AsMatrixView, Matrix, MatrixView, MulAlgorithm};
 
-use rayon::prelude::*;
-
-use std::ops::{Add, Mul};
-use crate::numlib::Zero;
+use crate::{error::Error, numlib::Ring};
 
 /**
  * Logic for generic matrix multiplication.
- * Naive and pretty slow, but it works.
+ * product_matrix uses a cache blocked kernel, see blocked.rs
  */
-impl<T: Send + Sync + Copy + Zero + Add<T, Output = T> + Mul<T, Output = T> + std::ops::Sub<Output = T>>
-    Matrix<T>
-{
-    pub fn product_matrix(&self, other: &Matrix<T>) -> Result<Matrix<T>, &'static str> {
-        if self.width != other.height {
-            return Err("Matrices have mismatched sizes");
-        }
-
-        // Transpose for better alignment in memory
-        let mut other_t = Vec::with_capacity(other.size);
-        for row in 0..other.width {
-            for col in 0..other.height {
-                other_t.push(other[(col, row)]);
-            }
-        }
-
-        // Rayon parallel iterator for faster computation
-        let res: Vec<T> = (0..self.height() * other.width())
-            .into_par_iter()
-            .map(|index| {
-                let row = index / other.width();
-                let col = index % other.width();
-
-                let mut entry = T::zero();
-                for index in 0..self.width {
-                    entry = entry
-                        + self.data[row * self.width + index]
-                            * other_t[col * other.width + index];
-                }
-                entry
-        }).collect();
+impl<T: Ring> Matrix<T> {
+    pub fn product_matrix<M: AsMatrixView<T> + ?Sized>(
+        &self,
+        other: &M,
+    ) -> Result<Matrix<T>, Error> {
+        self.as_view().product_matrix(other)
+    }
 
-        Matrix::new(other.width, self.height, res)
+    /**
+     * The product by a chosen algorithm, to compare them against each other
+     */
+    pub fn product_matrix_with<M: AsMatrixView<T> + ?Sized>(
+        &self,
+        other: &M,
+        algorithm: MulAlgorithm,
+    ) -> Result<Matrix<T>, Error> {
+        self.as_view().product_matrix_with(other, algorithm)
     }
 
     /**
@@ -52,9 +35,12 @@ impl<T: Send + Sync + Copy + Zero + Add<T, Output = T> + Mul<T, Output = T> + st
         since = "0.1.7",
         note = "simple_product_matrix is a slower method use product_matrix to use the fastest algorithm"
     )]
-    pub fn simple_product_matrix(&self, other: &Matrix<T>) -> Result<Matrix<T>, &'static str> {
+    pub fn simple_product_matrix(&self, other: &Matrix<T>) -> Result<Matrix<T>, Error> {
         if self.width != other.height {
-            return Err("Matrices have mismatched sizes");
+            return Err(Error::DimensionMismatch {
+                expected: self.width,
+                found: other.height,
+            });
         }
         let mut res = Vec::with_capacity(self.height * other.width);
         for col in other.get_cols() {
@@ -75,9 +61,12 @@ impl<T: Send + Sync + Copy + Zero + Add<T, Output = T> + Mul<T, Output = T> + st
         since = "0.2.0",
         note = "trivial_product_matrix is a slower method use product_matrix to use the fastest algorithm"
     )]
-    pub fn trivial_product_matrix(&self, other: &Matrix<T>) -> Result<Matrix<T>, &'static str> {
+    pub fn trivial_product_matrix(&self, other: &Matrix<T>) -> Result<Matrix<T>, Error> {
         if self.width != other.height {
-            return Err("Matrices have mismatched sizes");
+            return Err(Error::DimensionMismatch {
+                expected: self.width,
+                found: other.height,
+            });
         }
 
         // Transpose for better alignment in memory
@@ -88,14 +77,15 @@ impl<T: Send + Sync + Copy + Zero + Add<T, Output = T> + Mul<T, Output = T> + st
             }
         }
         
+        let lhs = self.as_view().row_major();
         let mut res = vec![T::zero(); self.height * other.width];
         for row in 0..self.height {
             for col in 0..other.width {
                 let mut entry = res[row * other.width + col];
                 for index in 0..self.width {
                     entry = entry
-                        + self.data[row * self.width + index]
-                            * other_t[col * other.width + index];
+                        + lhs[row * self.width + index]
+                            * other_t[col * other.height + index];
                 }
                 res[row * other.width + col] = entry;
             }
@@ -104,3 +94,53 @@ impl<T: Send + Sync + Copy + Zero + Add<T, Output = T> + Mul<T, Output = T> + st
         Matrix::new(other.width, self.height, res)
     }
 }
+
+impl<T: Ring> MatrixView<'_, T> {
+    pub fn product_matrix<M: AsMatrixView<T> + ?Sized>(
+        &self,
+        other: &M,
+    ) -> Result<Matrix<T>, Error> {
+        let other = other.as_view();
+        if self.width != other.height {
+            return Err(Error::DimensionMismatch {
+                expected: self.width,
+                found: other.height,
+            });
+        }
+
+        let mut res = vec![T::zero(); self.height * other.width];
+        blocked::gemm(
+            T::one(),
+            Operand::new(*self),
+            Operand::new(other),
+            &mut res,
+            other.width,
+        );
+
+        Matrix::new(other.width, self.height, res)
+    }
+
+    pub fn product_matrix_with<M: AsMatrixView<T> + ?Sized>(
+        &self,
+        other: &M,
+        algorithm: MulAlgorithm,
+    ) -> Result<Matrix<T>, Error> {
+        let other = other.as_view();
+        let (cutoff, winograd) = match algorithm {
+            MulAlgorithm::Blocked => return self.product_matrix(&other),
+            MulAlgorithm::Strassen => (DEFAULT_CUTOFF, false),
+            MulAlgorithm::Winograd => (DEFAULT_CUTOFF, true),
+            MulAlgorithm::StrassenWithCutoff(cutoff) => (cutoff, false),
+            MulAlgorithm::WinogradWithCutoff(cutoff) => (cutoff, true),
+        };
+        if self.width != other.height {
+            return Err(Error::DimensionMismatch {
+                expected: self.width,
+                found: other.height,
+            });
+        }
+
+        let res = strassen::product(*self, other, cutoff, winograd);
+        Matrix::new(other.width, self.height, res)
+    }
+}
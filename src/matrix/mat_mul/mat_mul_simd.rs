@@ -1,10 +1,10 @@
+use crate::error::Error;
 use crate::matrix::Matrix;
 use crate::numlib::Zero;
 use std::simd::SimdElement;
 use std::ops::AddAssign;
 use std::simd::Simd;
 use std::ops::Mul;
-use rayon::prelude::*;
 
 const CHUNK_SIZE: usize = 8usize;
 
@@ -13,12 +13,15 @@ impl<T: Send + Sync + Zero + SimdElement + AddAssign> Matrix<T> where Simd<T, 8>
         pub fn simd_product_matrix(
             &self,
             other: &Matrix<T>,
-        ) -> Result<Matrix<T>, &'static str> {
+        ) -> Result<Matrix<T>, Error> {
             if self.width != other.height {
-                return Err("Matrices have mismatched sizes");
+                return Err(Error::DimensionMismatch {
+                    expected: self.width,
+                    found: other.height,
+                });
             }
 
-            let data = &self.data;
+            let data = self.as_view().row_major();
 
             let mut transposed_b = Vec::with_capacity(other.size);
             for row in 0..other.width {
@@ -1,4 +1,8 @@
+#[allow(clippy::module_inception)]
 pub mod mat_mul;
+mod blocked;
+mod gemm;
+mod strassen;
 
 #[cfg(feature = "simd")]
 pub mod mat_mul_simd;
\ No newline at end of file
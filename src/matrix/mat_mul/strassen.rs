@@ -0,0 +1,258 @@
+use std::borrow::Cow;
+
+use super::blocked::{self, Operand};
+use crate::{arch, matrix::MatrixView, numlib::Ring, parallel};
+
+/**
+ * Below about this size the blocked kernel beats another level of recursion, the
+ * additions and temporaries of a level cost more than the product they save
+ */
+pub(crate) const DEFAULT_CUTOFF: usize = 1024;
+
+/**
+ * The m x n product AB row by row. Every side is halved until the smallest is at
+ * most cutoff, so the operands are padded with zeros to a multiple of the number
+ * of halvings first. That adds fewer than 2^levels rows and columns, small next to
+ * a side that is still over cutoff after levels - 1 halvings.
+ */
+pub(crate) fn product<T: Ring>(
+    a: MatrixView<'_, T>,
+    b: MatrixView<'_, T>,
+    cutoff: usize,
+    winograd: bool,
+) -> Vec<T> {
+    let (m, k, n) = (a.height, a.width, b.width);
+    let smallest = m.min(k).min(n);
+    let mut levels = 0;
+    while smallest >> levels > cutoff.max(1) {
+        levels += 1;
+    }
+
+    let block = 1 << levels;
+    let (pm, pk, pn) = (round_up(m, block), round_up(k, block), round_up(n, block));
+    let padded_a = pad(a, pm, pk);
+    let padded_b = pad(b, pk, pn);
+    let c = parallel::run(m * k * n, |parallel| {
+        multiply(
+            dense(&padded_a, pm, pk),
+            dense(&padded_b, pk, pn),
+            levels,
+            winograd,
+            parallel,
+        )
+    });
+
+    if pm == m && pn == n {
+        return c;
+    }
+    c.chunks_exact(pn)
+        .take(m)
+        .flat_map(|row| &row[..n])
+        .copied()
+        .collect()
+}
+
+fn round_up(size: usize, block: usize) -> usize {
+    size.div_ceil(block) * block
+}
+
+/**
+ * The view row by row, with zero rows and columns added up to rows x cols
+ */
+fn pad<'a, T: Ring>(view: MatrixView<'a, T>, rows: usize, cols: usize) -> Cow<'a, [T]> {
+    let data = view.row_major();
+    if rows == view.height && cols == view.width {
+        return data;
+    }
+
+    let mut padded = vec![T::zero(); rows * cols];
+    if view.width > 0 {
+        for (row, values) in padded
+            .chunks_exact_mut(cols)
+            .zip(data.chunks_exact(view.width))
+        {
+            row[..view.width].copy_from_slice(values);
+        }
+    }
+
+    Cow::Owned(padded)
+}
+
+fn dense<T>(data: &[T], rows: usize, cols: usize) -> MatrixView<'_, T> {
+    MatrixView {
+        data,
+        width: cols,
+        height: rows,
+        row_stride: cols,
+        col_stride: 1,
+    }
+}
+
+/**
+ * The four quadrants 11, 12, 21 and 22 of a view with contiguous rows and even
+ * sides
+ */
+fn quadrants<T>(view: MatrixView<'_, T>) -> [MatrixView<'_, T>; 4] {
+    let (rows, cols) = (view.height / 2, view.width / 2);
+    let quadrant = |row: usize, col: usize| MatrixView {
+        data: &view.data[row * rows * view.row_stride + col * cols..],
+        width: cols,
+        height: rows,
+        row_stride: view.row_stride,
+        col_stride: 1,
+    };
+
+    [
+        quadrant(0, 0),
+        quadrant(0, 1),
+        quadrant(1, 0),
+        quadrant(1, 1),
+    ]
+}
+
+fn rows<'a, T>(view: MatrixView<'a, T>) -> impl Iterator<Item = &'a [T]> {
+    (0..view.height).map(move |row| {
+        let start = row * view.row_stride;
+        &view.data[start..start + view.width]
+    })
+}
+
+fn to_vec<T: Ring>(view: MatrixView<'_, T>) -> Vec<T> {
+    rows(view).flatten().copied().collect()
+}
+
+/**
+ * a + b or a - b entry by entry, row by row
+ */
+fn add<T: Ring>(a: MatrixView<'_, T>, b: MatrixView<'_, T>) -> Vec<T> {
+    let mut res = to_vec(a);
+    for (out, row) in res.chunks_exact_mut(a.width).zip(rows(b)) {
+        arch::add_assign(out, row);
+    }
+    res
+}
+
+fn sub<T: Ring>(a: MatrixView<'_, T>, b: MatrixView<'_, T>) -> Vec<T> {
+    let mut res = to_vec(a);
+    for (out, row) in res.chunks_exact_mut(a.width).zip(rows(b)) {
+        arch::sub_assign(out, row);
+    }
+    res
+}
+
+fn multiply<T: Ring>(
+    a: MatrixView<'_, T>,
+    b: MatrixView<'_, T>,
+    levels: usize,
+    winograd: bool,
+    parallel: bool,
+) -> Vec<T> {
+    let (m, n) = (a.height, b.width);
+    let mut c = vec![T::zero(); m * n];
+    if levels == 0 {
+        blocked::gemm_with(
+            parallel,
+            T::one(),
+            Operand::new(a),
+            Operand::new(b),
+            &mut c,
+            n,
+        );
+        return c;
+    }
+
+    let (rows, cols, inner) = (m / 2, n / 2, a.width / 2);
+    let [a11, a12, a21, a22] = quadrants(a);
+    let [b11, b12, b21, b22] = quadrants(b);
+    let lhs = |data| dense(data, rows, inner);
+    let rhs = |data| dense(data, inner, cols);
+    // The seven products are independent, so they share out over the thread pool
+    let products = |pairs: [(MatrixView<'_, T>, MatrixView<'_, T>); 7]| -> Vec<Vec<T>> {
+        parallel::map(parallel, pairs.to_vec(), |(a, b)| {
+            multiply(a, b, levels - 1, winograd, parallel)
+        })
+    };
+
+    let [c11, c12, c21, c22] = if winograd {
+        let s1 = add(a21, a22);
+        let s2 = sub(lhs(&s1), a11);
+        let s3 = sub(a11, a21);
+        let s4 = sub(a12, lhs(&s2));
+        let t1 = sub(b12, b11);
+        let t2 = sub(b22, rhs(&t1));
+        let t3 = sub(b22, b12);
+        let t4 = sub(rhs(&t2), b21);
+
+        let [p1, p2, p3, p4, p5, p6, p7]: [Vec<T>; 7] = products([
+            (a11, b11),
+            (a12, b21),
+            (lhs(&s4), b22),
+            (a22, rhs(&t4)),
+            (lhs(&s1), rhs(&t1)),
+            (lhs(&s2), rhs(&t2)),
+            (lhs(&s3), rhs(&t3)),
+        ])
+        .try_into()
+        .unwrap();
+
+        // C11 = P1 + P2, C12 = U2 + P5 + P3, C21 = U3 - P4 and C22 = U3 + P5,
+        // where U2 = P1 + P6 and U3 = U2 + P7
+        let mut c11 = p1.clone();
+        arch::add_assign(&mut c11, &p2);
+        let mut u2 = p1;
+        arch::add_assign(&mut u2, &p6);
+        let mut u3 = u2.clone();
+        arch::add_assign(&mut u3, &p7);
+        let mut c12 = u2;
+        arch::add_assign(&mut c12, &p5);
+        arch::add_assign(&mut c12, &p3);
+        let mut c21 = u3.clone();
+        arch::sub_assign(&mut c21, &p4);
+        let mut c22 = u3;
+        arch::add_assign(&mut c22, &p5);
+        [c11, c12, c21, c22]
+    } else {
+        let (a11_a22, a21_a22, a11_a12) = (add(a11, a22), add(a21, a22), add(a11, a12));
+        let (a21_a11, a12_a22) = (sub(a21, a11), sub(a12, a22));
+        let (b11_b22, b11_b12, b21_b22) = (add(b11, b22), add(b11, b12), add(b21, b22));
+        let (b12_b22, b21_b11) = (sub(b12, b22), sub(b21, b11));
+
+        let [m1, m2, m3, m4, m5, m6, m7]: [Vec<T>; 7] = products([
+            (lhs(&a11_a22), rhs(&b11_b22)),
+            (lhs(&a21_a22), b11),
+            (a11, rhs(&b12_b22)),
+            (a22, rhs(&b21_b11)),
+            (lhs(&a11_a12), b22),
+            (lhs(&a21_a11), rhs(&b11_b12)),
+            (lhs(&a12_a22), rhs(&b21_b22)),
+        ])
+        .try_into()
+        .unwrap();
+
+        // C11 = M1 + M4 - M5 + M7, C12 = M3 + M5, C21 = M2 + M4 and
+        // C22 = M1 - M2 + M3 + M6
+        let mut c11 = m1.clone();
+        arch::add_assign(&mut c11, &m4);
+        arch::sub_assign(&mut c11, &m5);
+        arch::add_assign(&mut c11, &m7);
+        let mut c12 = m3.clone();
+        arch::add_assign(&mut c12, &m5);
+        let mut c21 = m2.clone();
+        arch::add_assign(&mut c21, &m4);
+        let mut c22 = m1;
+        arch::sub_assign(&mut c22, &m2);
+        arch::add_assign(&mut c22, &m3);
+        arch::add_assign(&mut c22, &m6);
+        [c11, c12, c21, c22]
+    };
+
+    for (index, quadrant) in [c11, c12, c21, c22].iter().enumerate() {
+        let (row, col) = (index / 2 * rows, index % 2 * cols);
+        for (offset, values) in quadrant.chunks_exact(cols).enumerate() {
+            let start = (row + offset) * n + col;
+            c[start..start + cols].copy_from_slice(values);
+        }
+    }
+
+    c
+}
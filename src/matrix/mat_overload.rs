@@ -0,0 +1,244 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::{mat_broadcast::broadcast_shape, Matrix};
+use crate::{numlib::Ring, vector::Vector};
+
+/**
+ * Operator overloads for matrices. These are the unchecked counterparts of
+ * the Result returning methods and panic when the shapes don't line up.
+ * Addition and subtraction broadcast like NumPy does, so a 1 x n or m x 1
+ * matrix combines with an m x n one without being expanded, and a scalar
+ * combines with every entry.
+ */
+fn zip_assign<T: Copy, F: Fn(T, T) -> T>(lhs: &mut Matrix<T>, rhs: &Matrix<T>, f: F) {
+    if lhs.shape() == rhs.shape() && lhs.layout == rhs.layout {
+        for (a, b) in lhs.data.iter_mut().zip(rhs.data.iter()) {
+            *a = f(*a, *b);
+        }
+    } else {
+        lhs.as_view_mut()
+            .broadcast_apply(rhs, |a, b| *a = f(*a, *b))
+            .unwrap_or_else(|err| panic!("{}", err));
+    }
+}
+
+/**
+ * lhs op rhs, written into a copy of lhs unless broadcasting makes the result
+ * bigger than it
+ */
+fn zip<T: Copy, F: Fn(T, T) -> T>(lhs: &Matrix<T>, rhs: &Matrix<T>, f: F) -> Matrix<T> {
+    if broadcast_shape(lhs.shape(), rhs.shape()) == Ok(lhs.shape()) {
+        let mut res = lhs.clone();
+        zip_assign(&mut res, rhs, f);
+        res
+    } else {
+        lhs.as_view()
+            .broadcast_zip(rhs, f)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/**
+ * Like zip, reusing the buffer of an owned lhs when the result fits in it
+ */
+fn zip_owned<T: Copy, F: Fn(T, T) -> T>(mut lhs: Matrix<T>, rhs: &Matrix<T>, f: F) -> Matrix<T> {
+    if broadcast_shape(lhs.shape(), rhs.shape()) == Ok(lhs.shape()) {
+        zip_assign(&mut lhs, rhs, f);
+        lhs
+    } else {
+        zip(&lhs, rhs, f)
+    }
+}
+
+macro_rules! impl_elementwise_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl<T: Copy + $trait<T, Output = T>> $assign_trait<&Matrix<T>> for Matrix<T> {
+            fn $assign_method(&mut self, rhs: &Matrix<T>) {
+                zip_assign(self, rhs, |a, b| a $op b);
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $assign_trait<Matrix<T>> for Matrix<T> {
+            fn $assign_method(&mut self, rhs: Matrix<T>) {
+                zip_assign(self, &rhs, |a, b| a $op b);
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $trait<&Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: &Matrix<T>) -> Matrix<T> {
+                zip_owned(self, rhs, |a, b| a $op b)
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $trait<Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: Matrix<T>) -> Matrix<T> {
+                zip_owned(self, &rhs, |a, b| a $op b)
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $trait<&Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: &Matrix<T>) -> Matrix<T> {
+                zip(self, rhs, |a, b| a $op b)
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $trait<Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: Matrix<T>) -> Matrix<T> {
+                // Reuse the buffer of the owned right hand side
+                zip_owned(rhs, self, |b, a| a $op b)
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $assign_trait<T> for Matrix<T> {
+            fn $assign_method(&mut self, scalar: T) {
+                self.apply(|&x| x $op scalar);
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $trait<T> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(mut self, scalar: T) -> Matrix<T> {
+                self.apply(|&x| x $op scalar);
+                self
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $trait<T> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, scalar: T) -> Matrix<T> {
+                self.clone() $op scalar
+            }
+        }
+    };
+}
+
+impl_elementwise_op!(Add, add, AddAssign, add_assign, +);
+impl_elementwise_op!(Sub, sub, SubAssign, sub_assign, -);
+
+macro_rules! impl_product_op {
+    ($lhs:ty, $rhs:ty) => {
+        impl<T: Ring> Mul<$rhs> for $lhs {
+            type Output = Matrix<T>;
+
+            fn mul(self, rhs: $rhs) -> Matrix<T> {
+                self.product_matrix(&rhs)
+                    .unwrap_or_else(|err| panic!("{}", err))
+            }
+        }
+    };
+}
+
+impl_product_op!(Matrix<T>, Matrix<T>);
+impl_product_op!(Matrix<T>, &Matrix<T>);
+impl_product_op!(&Matrix<T>, Matrix<T>);
+impl_product_op!(&Matrix<T>, &Matrix<T>);
+
+impl<T: Ring> MulAssign<&Matrix<T>> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: &Matrix<T>) {
+        *self = &*self * rhs;
+    }
+}
+
+impl<T: Ring> MulAssign<Matrix<T>> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: Matrix<T>) {
+        *self = &*self * &rhs;
+    }
+}
+
+macro_rules! impl_vector_product_op {
+    ($lhs:ty, $rhs:ty) => {
+        impl<T: Ring> Mul<$rhs> for $lhs {
+            type Output = Vector<T>;
+
+            fn mul(self, rhs: $rhs) -> Vector<T> {
+                self.product_vector(&rhs)
+                    .unwrap_or_else(|err| panic!("{}", err))
+            }
+        }
+    };
+}
+
+impl_vector_product_op!(Matrix<T>, Vector<T>);
+impl_vector_product_op!(Matrix<T>, &Vector<T>);
+impl_vector_product_op!(&Matrix<T>, Vector<T>);
+impl_vector_product_op!(&Matrix<T>, &Vector<T>);
+
+impl<T: Copy + Mul<T, Output = T>> MulAssign<T> for Matrix<T> {
+    fn mul_assign(&mut self, scalar: T) {
+        self.apply(|&x| x * scalar);
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T>> Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(mut self, scalar: T) -> Matrix<T> {
+        self *= scalar;
+        self
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T>> Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, scalar: T) -> Matrix<T> {
+        self.clone() * scalar
+    }
+}
+
+macro_rules! impl_scalar_lhs_op {
+    (for $($t:ty),+) => {
+        $(impl_scalar_lhs_op!(@op $t, Mul, mul, *);
+        impl_scalar_lhs_op!(@op $t, Add, add, +);
+        impl_scalar_lhs_op!(@op $t, Sub, sub, -);)*
+    };
+    (@op $t:ty, $trait:ident, $method:ident, $op:tt) => {
+        impl $trait<Matrix<$t>> for $t {
+            type Output = Matrix<$t>;
+
+            fn $method(self, mut matrix: Matrix<$t>) -> Matrix<$t> {
+                matrix.apply(|&x| self $op x);
+                matrix
+            }
+        }
+
+        impl $trait<&Matrix<$t>> for $t {
+            type Output = Matrix<$t>;
+
+            fn $method(self, matrix: &Matrix<$t>) -> Matrix<$t> {
+                self $op matrix.clone()
+            }
+        }
+    };
+}
+
+impl_scalar_lhs_op!(for u8, u16, u32, u64, u128, usize);
+impl_scalar_lhs_op!(for i8, i16, i32, i64, i128, isize);
+impl_scalar_lhs_op!(for f32, f64);
+
+impl<T: Copy + Neg<Output = T>> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(mut self) -> Matrix<T> {
+        self.apply(|&x| -x);
+        self
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Matrix<T> {
+        -self.clone()
+    }
+}
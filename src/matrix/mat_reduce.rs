@@ -0,0 +1,287 @@
+use std::cmp::Ordering;
+
+use super::{Matrix, MatrixView};
+use crate::{
+    numlib::{is_nan, ComplexField, RealField, Ring, Zero},
+    vector::{Axis, Vector},
+};
+
+// Reductions over the whole matrix, or along an axis with one result per
+// column (Axis::Column) or per row (Axis::Row). The nan_ variants skip entries
+// that aren't equal to themselves, which only NaN does. Reductions that have no
+// value for no entries return None when there is nothing to reduce, along an
+// axis as soon as one column or row comes up empty.
+
+/**
+ * The entries row by row, without the NaNs when skipping them
+ */
+fn entries<T: PartialEq>(view: MatrixView<'_, T>, skip_nan: bool) -> impl Iterator<Item = &T> {
+    view.iter().filter(move |x| !skip_nan || !is_nan(*x))
+}
+
+/**
+ * The position and value of the first entry that compares as wanted against all
+ * others. Without skipping, the first NaN wins so it isn't silently dropped.
+ */
+fn extreme<T: PartialOrd>(
+    view: MatrixView<'_, T>,
+    skip_nan: bool,
+    wanted: Ordering,
+) -> Option<(usize, &T)> {
+    let mut best: Option<(usize, &T)> = None;
+    for (index, x) in view.iter().enumerate() {
+        if is_nan(x) {
+            if skip_nan {
+                continue;
+            }
+            return Some((index, x));
+        }
+        match best {
+            Some((_, value)) if x.partial_cmp(value) != Some(wanted) => {}
+            _ => best = Some((index, x)),
+        }
+    }
+
+    best
+}
+
+fn sum<T: Ring>(view: MatrixView<'_, T>, skip_nan: bool) -> T {
+    entries(view, skip_nan).fold(T::zero(), |acc, &x| acc + x)
+}
+
+fn mean<T: ComplexField>(view: MatrixView<'_, T>, skip_nan: bool) -> Option<T> {
+    let (count, sum) =
+        entries(view, skip_nan).fold((0, T::zero()), |(count, sum), &x| (count + 1, sum + x));
+
+    (count > 0).then(|| sum / T::from_real(T::Real::from_usize(count)))
+}
+
+/**
+ * The mean of |x - mean|^2, dividing by the number of entries like NumPy does
+ */
+fn var<T: ComplexField>(view: MatrixView<'_, T>, skip_nan: bool) -> Option<T::Real> {
+    let mean = mean(view, skip_nan)?;
+    let (count, sum) = entries(view, skip_nan).fold((0, T::Real::zero()), |(count, sum), &x| {
+        (count + 1, sum + (x - mean).norm_sqr())
+    });
+
+    Some(sum / T::Real::from_usize(count))
+}
+
+impl<T> Matrix<T> {
+    fn lanes(&self, axis: Axis) -> Vec<MatrixView<'_, T>> {
+        let view = self.as_view();
+        match axis {
+            Axis::Column => (0..self.width).map(|col| view.col(col).unwrap()).collect(),
+            Axis::Row => (0..self.height).map(|row| view.row(row).unwrap()).collect(),
+        }
+    }
+
+    fn reduce_axis<U>(&self, axis: Axis, f: impl Fn(MatrixView<'_, T>) -> U) -> Vector<U> {
+        Vector::new(self.lanes(axis).into_iter().map(f).collect())
+    }
+
+    fn try_reduce_axis<U>(
+        &self,
+        axis: Axis,
+        f: impl Fn(MatrixView<'_, T>) -> Option<U>,
+    ) -> Option<Vector<U>> {
+        Some(Vector::new(
+            self.lanes(axis).into_iter().map(f).collect::<Option<_>>()?,
+        ))
+    }
+
+    /**
+     * The (row, col) of the entry at index of the row by row order of iter
+     */
+    const fn row_col(&self, index: usize) -> (usize, usize) {
+        (index / self.width, index % self.width)
+    }
+
+    /**
+     * Folds the entries row by row into init
+     */
+    pub fn fold<U, F: Fn(U, &T) -> U>(&self, init: U, f: F) -> U {
+        self.as_view().iter().fold(init, f)
+    }
+
+    /**
+     * Folds every column or row into its own copy of init
+     */
+    pub fn fold_axis<U: Clone, F: Fn(U, &T) -> U>(&self, axis: Axis, init: U, f: F) -> Vector<U> {
+        self.reduce_axis(axis, |lane| lane.iter().fold(init.clone(), &f))
+    }
+}
+
+impl<T: Ring> Matrix<T> {
+    pub fn sum_axis(&self, axis: Axis) -> Vector<T> {
+        self.reduce_axis(axis, |lane| sum(lane, false))
+    }
+
+    pub fn nan_sum(&self) -> T {
+        sum(self.as_view(), true)
+    }
+
+    pub fn nan_sum_axis(&self, axis: Axis) -> Vector<T> {
+        self.reduce_axis(axis, |lane| sum(lane, true))
+    }
+
+    pub fn prod(&self) -> T {
+        self.data.iter().fold(T::one(), |acc, &x| acc * x)
+    }
+
+    pub fn prod_axis(&self, axis: Axis) -> Vector<T> {
+        self.reduce_axis(axis, |lane| lane.iter().fold(T::one(), |acc, &x| acc * x))
+    }
+}
+
+impl<T: ComplexField> Matrix<T> {
+    pub fn mean(&self) -> Option<T> {
+        mean(self.as_view(), false)
+    }
+
+    pub fn mean_axis(&self, axis: Axis) -> Option<Vector<T>> {
+        self.try_reduce_axis(axis, |lane| mean(lane, false))
+    }
+
+    pub fn nan_mean(&self) -> Option<T> {
+        mean(self.as_view(), true)
+    }
+
+    pub fn nan_mean_axis(&self, axis: Axis) -> Option<Vector<T>> {
+        self.try_reduce_axis(axis, |lane| mean(lane, true))
+    }
+
+    /**
+     * The population variance, real even for complex entries
+     */
+    pub fn var(&self) -> Option<T::Real> {
+        var(self.as_view(), false)
+    }
+
+    pub fn var_axis(&self, axis: Axis) -> Option<Vector<T::Real>> {
+        self.try_reduce_axis(axis, |lane| var(lane, false))
+    }
+
+    pub fn nan_var(&self) -> Option<T::Real> {
+        var(self.as_view(), true)
+    }
+
+    pub fn nan_var_axis(&self, axis: Axis) -> Option<Vector<T::Real>> {
+        self.try_reduce_axis(axis, |lane| var(lane, true))
+    }
+
+    /**
+     * The square root of var
+     */
+    pub fn std(&self) -> Option<T::Real> {
+        self.var().map(|var| var.sqrt())
+    }
+
+    pub fn std_axis(&self, axis: Axis) -> Option<Vector<T::Real>> {
+        self.try_reduce_axis(axis, |lane| var(lane, false).map(|var| var.sqrt()))
+    }
+
+    pub fn nan_std(&self) -> Option<T::Real> {
+        self.nan_var().map(|var| var.sqrt())
+    }
+
+    pub fn nan_std_axis(&self, axis: Axis) -> Option<Vector<T::Real>> {
+        self.try_reduce_axis(axis, |lane| var(lane, true).map(|var| var.sqrt()))
+    }
+}
+
+impl<T: PartialOrd + Copy> Matrix<T> {
+    /**
+     * The largest entry, or the first NaN if there is one
+     */
+    pub fn max(&self) -> Option<T> {
+        extreme(self.as_view(), false, Ordering::Greater).map(|(_, &x)| x)
+    }
+
+    /**
+     * The smallest entry, or the first NaN if there is one
+     */
+    pub fn min(&self) -> Option<T> {
+        extreme(self.as_view(), false, Ordering::Less).map(|(_, &x)| x)
+    }
+
+    pub fn max_axis(&self, axis: Axis) -> Option<Vector<T>> {
+        self.try_reduce_axis(axis, |lane| {
+            extreme(lane, false, Ordering::Greater).map(|(_, &x)| x)
+        })
+    }
+
+    pub fn min_axis(&self, axis: Axis) -> Option<Vector<T>> {
+        self.try_reduce_axis(axis, |lane| {
+            extreme(lane, false, Ordering::Less).map(|(_, &x)| x)
+        })
+    }
+
+    pub fn nan_max(&self) -> Option<T> {
+        extreme(self.as_view(), true, Ordering::Greater).map(|(_, &x)| x)
+    }
+
+    pub fn nan_min(&self) -> Option<T> {
+        extreme(self.as_view(), true, Ordering::Less).map(|(_, &x)| x)
+    }
+
+    pub fn nan_max_axis(&self, axis: Axis) -> Option<Vector<T>> {
+        self.try_reduce_axis(axis, |lane| {
+            extreme(lane, true, Ordering::Greater).map(|(_, &x)| x)
+        })
+    }
+
+    pub fn nan_min_axis(&self, axis: Axis) -> Option<Vector<T>> {
+        self.try_reduce_axis(axis, |lane| {
+            extreme(lane, true, Ordering::Less).map(|(_, &x)| x)
+        })
+    }
+
+    /**
+     * The (row, col) of the first largest entry, or of the first NaN
+     */
+    pub fn argmax(&self) -> Option<(usize, usize)> {
+        extreme(self.as_view(), false, Ordering::Greater).map(|(index, _)| self.row_col(index))
+    }
+
+    pub fn argmin(&self) -> Option<(usize, usize)> {
+        extreme(self.as_view(), false, Ordering::Less).map(|(index, _)| self.row_col(index))
+    }
+
+    pub fn nan_argmax(&self) -> Option<(usize, usize)> {
+        extreme(self.as_view(), true, Ordering::Greater).map(|(index, _)| self.row_col(index))
+    }
+
+    pub fn nan_argmin(&self) -> Option<(usize, usize)> {
+        extreme(self.as_view(), true, Ordering::Less).map(|(index, _)| self.row_col(index))
+    }
+
+    /**
+     * The row of the largest entry of every column, or the column of the
+     * largest entry of every row
+     */
+    pub fn argmax_axis(&self, axis: Axis) -> Option<Vector<usize>> {
+        self.try_reduce_axis(axis, |lane| {
+            extreme(lane, false, Ordering::Greater).map(|(index, _)| index)
+        })
+    }
+
+    pub fn argmin_axis(&self, axis: Axis) -> Option<Vector<usize>> {
+        self.try_reduce_axis(axis, |lane| {
+            extreme(lane, false, Ordering::Less).map(|(index, _)| index)
+        })
+    }
+
+    pub fn nan_argmax_axis(&self, axis: Axis) -> Option<Vector<usize>> {
+        self.try_reduce_axis(axis, |lane| {
+            extreme(lane, true, Ordering::Greater).map(|(index, _)| index)
+        })
+    }
+
+    pub fn nan_argmin_axis(&self, axis: Axis) -> Option<Vector<usize>> {
+        self.try_reduce_axis(axis, |lane| {
+            extreme(lane, true, Ordering::Less).map(|(index, _)| index)
+        })
+    }
+}
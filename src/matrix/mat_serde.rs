@@ -0,0 +1,64 @@
+use serde::{
+    de::Error as _, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::{Matrix, MatrixView};
+
+// A matrix is written as its height, width and the entries row by row, so the
+// format doesn't depend on the layout. Reading one back gives a row-major
+// matrix, after checking that there are exactly height * width entries.
+
+/**
+ * The entries of a view row by row
+ */
+struct Rows<'a, T>(MatrixView<'a, T>);
+
+impl<T: Serialize> Serialize for Rows<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter())
+    }
+}
+
+impl<T: Serialize> Serialize for Matrix<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Matrix", 3)?;
+        state.serialize_field("height", &self.height)?;
+        state.serialize_field("width", &self.width)?;
+        state.serialize_field("data", &Rows(self.as_view()))?;
+        state.end()
+    }
+}
+
+/**
+ * A matrix as it was read, before the shape is checked against the data
+ */
+#[derive(Deserialize)]
+#[serde(rename = "Matrix")]
+struct Unchecked<T> {
+    height: usize,
+    width: usize,
+    data: Vec<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Matrix<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Unchecked {
+            height,
+            width,
+            data,
+        } = Unchecked::deserialize(deserializer)?;
+
+        let size = height.checked_mul(width).ok_or_else(|| {
+            D::Error::custom(format_args!(
+                "a {} x {} matrix has too many entries to address",
+                height, width
+            ))
+        })?;
+        if data.len() != size {
+            let expected = format!("{} entries for a {} x {} matrix", size, height, width);
+            return Err(D::Error::invalid_length(data.len(), &expected.as_str()));
+        }
+
+        Ok(Matrix::new(width, height, data).unwrap())
+    }
+}
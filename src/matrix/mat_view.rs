@@ -0,0 +1,586 @@
+use std::borrow::Cow;
+use std::ops::{Bound, Index, IndexMut, RangeBounds};
+
+use super::{Layout, Matrix, MatrixView, MatrixViewMut, Op};
+use crate::{
+    error::Error,
+    numlib::{Field, Ring},
+    parallel,
+    vector::Vector,
+};
+
+/**
+ * Anything that can be looked at through a MatrixView, so arithmetic and
+ * products take owned matrices and views alike
+ */
+pub trait AsMatrixView<T> {
+    fn as_view(&self) -> MatrixView<'_, T>;
+}
+
+impl<T> AsMatrixView<T> for Matrix<T> {
+    fn as_view(&self) -> MatrixView<'_, T> {
+        Matrix::as_view(self)
+    }
+}
+
+impl<T> AsMatrixView<T> for MatrixView<'_, T> {
+    fn as_view(&self) -> MatrixView<'_, T> {
+        *self
+    }
+}
+
+impl<T> AsMatrixView<T> for MatrixViewMut<'_, T> {
+    fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixViewMut::as_view(self)
+    }
+}
+
+impl<T, M: AsMatrixView<T> + ?Sized> AsMatrixView<T> for &M {
+    fn as_view(&self) -> MatrixView<'_, T> {
+        (**self).as_view()
+    }
+}
+
+/**
+ * Turns a range into start..end, checking it against the length
+ */
+fn resolve<R: RangeBounds<usize>>(range: R, bound: usize) -> Result<(usize, usize), Error> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => bound,
+    };
+
+    if end > bound {
+        return Err(Error::IndexOutOfBounds {
+            index: end - 1,
+            bound,
+        });
+    }
+    if start > end {
+        return Err(Error::IndexOutOfBounds {
+            index: start,
+            bound: end,
+        });
+    }
+
+    Ok((start, end))
+}
+
+/**
+ * Where a window of the given shape starts in the data of its parent, empty
+ * windows start nowhere
+ */
+fn window_offset(
+    (rows, cols): ((usize, usize), (usize, usize)),
+    row_stride: usize,
+    col_stride: usize,
+) -> Option<usize> {
+    if rows.0 == rows.1 || cols.0 == cols.1 {
+        None
+    } else {
+        Some(rows.0 * row_stride + cols.0 * col_stride)
+    }
+}
+
+fn check_position(row: usize, col: usize, height: usize, width: usize) {
+    if row >= height {
+        panic!(
+            "{}",
+            Error::IndexOutOfBounds {
+                index: row,
+                bound: height,
+            }
+        );
+    }
+    if col >= width {
+        panic!(
+            "{}",
+            Error::IndexOutOfBounds {
+                index: col,
+                bound: width,
+            }
+        );
+    }
+}
+
+impl<T> Matrix<T> {
+    const fn strides(&self) -> (usize, usize) {
+        match self.layout {
+            Layout::RowMajor => (self.width, 1),
+            Layout::ColumnMajor => (1, self.height),
+        }
+    }
+
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        let (row_stride, col_stride) = self.strides();
+        MatrixView {
+            data: &self.data,
+            width: self.width,
+            height: self.height,
+            row_stride,
+            col_stride,
+        }
+    }
+
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let (row_stride, col_stride) = self.strides();
+        MatrixViewMut {
+            data: &mut self.data,
+            width: self.width,
+            height: self.height,
+            row_stride,
+            col_stride,
+        }
+    }
+
+    /**
+     * The submatrix of the given rows and columns, m.view(1..3, ..) for example
+     */
+    pub fn view<R: RangeBounds<usize>, C: RangeBounds<usize>>(
+        &self,
+        rows: R,
+        cols: C,
+    ) -> Result<MatrixView<'_, T>, Error> {
+        self.as_view().view(rows, cols)
+    }
+
+    pub fn view_mut<R: RangeBounds<usize>, C: RangeBounds<usize>>(
+        &mut self,
+        rows: R,
+        cols: C,
+    ) -> Result<MatrixViewMut<'_, T>, Error> {
+        self.as_view_mut().into_view_mut(rows, cols)
+    }
+
+    pub fn row_view(&self, number: usize) -> Result<MatrixView<'_, T>, Error> {
+        self.as_view().row(number)
+    }
+
+    pub fn col_view(&self, number: usize) -> Result<MatrixView<'_, T>, Error> {
+        self.as_view().col(number)
+    }
+
+    /**
+     * The transpose without copying, see transpose for an owned one
+     */
+    pub fn transpose_view(&self) -> MatrixView<'_, T> {
+        self.as_view().transpose()
+    }
+}
+
+// Derived Clone and Copy would needlessly require T: Copy
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+impl<'a, T> From<&'a Matrix<T>> for MatrixView<'a, T> {
+    fn from(matrix: &'a Matrix<T>) -> MatrixView<'a, T> {
+        matrix.as_view()
+    }
+}
+
+impl<'a, T> From<&'a mut Matrix<T>> for MatrixViewMut<'a, T> {
+    fn from(matrix: &'a mut Matrix<T>) -> MatrixViewMut<'a, T> {
+        matrix.as_view_mut()
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    pub const fn shape(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    /**
+     * How far apart consecutive rows and consecutive columns are in memory
+     */
+    pub const fn strides(&self) -> (usize, usize) {
+        (self.row_stride, self.col_stride)
+    }
+
+    /**
+     * Whether the entries are laid out row by row without gaps
+     */
+    pub const fn is_contiguous(&self) -> bool {
+        (self.col_stride == 1 || self.width <= 1)
+            && (self.row_stride == self.width || self.height <= 1)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row < self.height && col < self.width {
+            self.data.get(row * self.row_stride + col * self.col_stride)
+        } else {
+            None
+        }
+    }
+
+    pub fn view<R: RangeBounds<usize>, C: RangeBounds<usize>>(
+        &self,
+        rows: R,
+        cols: C,
+    ) -> Result<MatrixView<'a, T>, Error> {
+        let rows = resolve(rows, self.height)?;
+        let cols = resolve(cols, self.width)?;
+        let data = match window_offset((rows, cols), self.row_stride, self.col_stride) {
+            Some(offset) => &self.data[offset..],
+            None => &self.data[..0],
+        };
+
+        Ok(MatrixView {
+            data,
+            width: cols.1 - cols.0,
+            height: rows.1 - rows.0,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        })
+    }
+
+    pub fn row(&self, number: usize) -> Result<MatrixView<'a, T>, Error> {
+        if number >= self.height {
+            return Err(Error::IndexOutOfBounds {
+                index: number,
+                bound: self.height,
+            });
+        }
+
+        self.view(number..=number, ..)
+    }
+
+    pub fn col(&self, number: usize) -> Result<MatrixView<'a, T>, Error> {
+        if number >= self.width {
+            return Err(Error::IndexOutOfBounds {
+                index: number,
+                bound: self.width,
+            });
+        }
+
+        self.view(.., number..=number)
+    }
+
+    /**
+     * Swaps the strides, which costs nothing
+     */
+    pub fn transpose(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            data: self.data,
+            width: self.height,
+            height: self.width,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+        }
+    }
+
+    /**
+     * The entries row by row
+     */
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let view = *self;
+        (0..view.height).flat_map(move |row| {
+            (0..view.width)
+                .map(move |col| &view.data[row * view.row_stride + col * view.col_stride])
+        })
+    }
+}
+
+impl<'a, T: Copy> MatrixView<'a, T> {
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::new(self.width, self.height, self.iter().copied().collect()).unwrap()
+    }
+
+    /**
+     * The entries row by row, only copying when the view has gaps or is transposed
+     */
+    pub(crate) fn row_major(&self) -> Cow<'a, [T]> {
+        if self.is_contiguous() {
+            Cow::Borrowed(&self.data[..self.width * self.height])
+        } else {
+            Cow::Owned(self.iter().copied().collect())
+        }
+    }
+}
+
+impl<T: Copy + Sync> MatrixView<'_, T> {
+    /**
+     * A matrix of f of every entry, which may be of another type
+     */
+    pub fn map<U: Send, F: Fn(&T) -> U + Send + Sync>(&self, f: F) -> Matrix<U> {
+        Matrix::new(self.width, self.height, parallel::map_slice(&self.row_major(), f)).unwrap()
+    }
+
+    /**
+     * A matrix of f of every entry and the entry at the same position of other
+     */
+    pub fn zip_map<U, V, M, F>(&self, other: &M, f: F) -> Result<Matrix<V>, Error>
+    where
+        U: Copy + Sync,
+        V: Send,
+        M: AsMatrixView<U> + ?Sized,
+        F: Fn(&T, &U) -> V + Send + Sync,
+    {
+        let other = other.as_view();
+        if self.shape() != other.shape() {
+            return Err(Error::ShapeMismatch {
+                expected: self.shape(),
+                found: other.shape(),
+            });
+        }
+
+        let data = parallel::zip_map(&self.row_major(), &other.row_major(), f);
+        Ok(Matrix::new(self.width, self.height, data).unwrap())
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        check_position(row, col, self.height, self.width);
+        &self.data[row * self.row_stride + col * self.col_stride]
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    pub const fn shape(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            data: self.data,
+            width: self.width,
+            height: self.height,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        }
+    }
+
+    /**
+     * A mutable submatrix of this view, borrowing it for as long as it lives
+     */
+    pub fn view_mut<R: RangeBounds<usize>, C: RangeBounds<usize>>(
+        &mut self,
+        rows: R,
+        cols: C,
+    ) -> Result<MatrixViewMut<'_, T>, Error> {
+        MatrixViewMut {
+            data: &mut *self.data,
+            width: self.width,
+            height: self.height,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        }
+        .into_view_mut(rows, cols)
+    }
+
+    fn into_view_mut<R: RangeBounds<usize>, C: RangeBounds<usize>>(
+        self,
+        rows: R,
+        cols: C,
+    ) -> Result<MatrixViewMut<'a, T>, Error> {
+        let rows = resolve(rows, self.height)?;
+        let cols = resolve(cols, self.width)?;
+        let data = match window_offset((rows, cols), self.row_stride, self.col_stride) {
+            Some(offset) => &mut self.data[offset..],
+            None => &mut self.data[..0],
+        };
+
+        Ok(MatrixViewMut {
+            data,
+            width: cols.1 - cols.0,
+            height: rows.1 - rows.0,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        })
+    }
+
+    pub fn transpose(self) -> MatrixViewMut<'a, T> {
+        MatrixViewMut {
+            data: self.data,
+            width: self.height,
+            height: self.width,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+        }
+    }
+
+    pub fn apply<F: Fn(&T) -> T>(&mut self, f: F) {
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let entry = &mut self.data[row * self.row_stride + col * self.col_stride];
+                *entry = f(entry);
+            }
+        }
+    }
+
+    /**
+     * Calls f with every entry of self and the entry at the same position of other
+     */
+    pub(crate) fn zip_apply<M: AsMatrixView<T> + ?Sized, F: Fn(&mut T, &T)>(
+        &mut self,
+        other: &M,
+        f: F,
+    ) -> Result<(), Error> {
+        let other = other.as_view();
+        if self.shape() != other.shape() {
+            return Err(Error::ShapeMismatch {
+                expected: self.shape(),
+                found: other.shape(),
+            });
+        }
+
+        for row in 0..self.height {
+            for col in 0..self.width {
+                f(
+                    &mut self.data[row * self.row_stride + col * self.col_stride],
+                    &other[(row, col)],
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Copy> MatrixViewMut<'_, T> {
+    pub fn fill(&mut self, value: T) {
+        self.apply(|_| value);
+    }
+
+    /**
+     * Overwrites the entries with those of a matrix or view of the same shape
+     */
+    pub fn copy_from<M: AsMatrixView<T> + ?Sized>(&mut self, other: &M) -> Result<(), Error> {
+        self.zip_apply(other, |a, b| *a = *b)
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        self.as_view().to_matrix()
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        check_position(row, col, self.height, self.width);
+        &self.data[row * self.row_stride + col * self.col_stride]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        check_position(row, col, self.height, self.width);
+        &mut self.data[row * self.row_stride + col * self.col_stride]
+    }
+}
+
+impl<T: Ring> MatrixView<'_, T> {
+    pub fn product_vector(&self, vector: &Vector<T>) -> Result<Vector<T>, Error> {
+        if self.width != vector.len() {
+            return Err(Error::DimensionMismatch {
+                expected: self.width,
+                found: vector.len(),
+            });
+        }
+
+        // gemv, which unlike Vector::dot doesn't conjugate the rows of complex
+        // matrices
+        let mut res = Vector::new(vec![T::zero(); self.height]);
+        Matrix::gemv(T::one(), self, Op::NoTranspose, vector, T::zero(), &mut res)?;
+
+        Ok(res)
+    }
+
+    pub fn add<M: AsMatrixView<T> + ?Sized>(&self, other: &M) -> Result<Matrix<T>, Error> {
+        let other = other.as_view();
+        if self.shape() != other.shape() {
+            return Err(Error::ShapeMismatch {
+                expected: self.shape(),
+                found: other.shape(),
+            });
+        }
+
+        let mut res = self.to_matrix();
+        parallel::add_assign(&mut res.data, &other.row_major());
+
+        Ok(res)
+    }
+
+    pub fn sub<M: AsMatrixView<T> + ?Sized>(&self, other: &M) -> Result<Matrix<T>, Error> {
+        let other = other.as_view();
+        if self.shape() != other.shape() {
+            return Err(Error::ShapeMismatch {
+                expected: self.shape(),
+                found: other.shape(),
+            });
+        }
+
+        let mut res = self.to_matrix();
+        parallel::sub_assign(&mut res.data, &other.row_major());
+
+        Ok(res)
+    }
+
+    /**
+     * The entrywise product
+     */
+    pub fn hadamard<M: AsMatrixView<T> + ?Sized>(&self, other: &M) -> Result<Matrix<T>, Error> {
+        let other = other.as_view();
+        if self.shape() != other.shape() {
+            return Err(Error::ShapeMismatch {
+                expected: self.shape(),
+                found: other.shape(),
+            });
+        }
+
+        let mut res = self.to_matrix();
+        parallel::mul_assign(&mut res.data, &other.row_major());
+
+        Ok(res)
+    }
+}
+
+impl<T: Field> MatrixView<'_, T> {
+    pub fn elementwise_div<M: AsMatrixView<T> + ?Sized>(
+        &self,
+        other: &M,
+    ) -> Result<Matrix<T>, Error> {
+        let other = other.as_view();
+        if self.shape() != other.shape() {
+            return Err(Error::ShapeMismatch {
+                expected: self.shape(),
+                found: other.shape(),
+            });
+        }
+
+        let mut res = self.to_matrix();
+        parallel::div_assign(&mut res.data, &other.row_major());
+
+        Ok(res)
+    }
+}
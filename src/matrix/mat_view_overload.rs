@@ -0,0 +1,144 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::{mat_broadcast::broadcast_shape, AsMatrixView, Matrix, MatrixView, MatrixViewMut};
+use crate::{error::Error, numlib::Ring, vector::Vector};
+
+/**
+ * Operator overloads mixing views with matrices. Like those on Matrix they
+ * broadcast, panic when the shapes don't line up, and always produce an owned
+ * Matrix.
+ */
+fn zip_assign<T: Copy, M: AsMatrixView<T> + ?Sized, F: Fn(T, T) -> T>(
+    lhs: &mut MatrixViewMut<'_, T>,
+    rhs: &M,
+    f: F,
+) {
+    lhs.broadcast_apply(rhs, |a, b| *a = f(*a, *b))
+        .unwrap_or_else(|err: Error| panic!("{}", err));
+}
+
+fn zip<T: Copy, L: AsMatrixView<T> + ?Sized, R: AsMatrixView<T> + ?Sized, F: Fn(T, T) -> T>(
+    lhs: &L,
+    rhs: &R,
+    f: F,
+) -> Matrix<T> {
+    lhs.as_view()
+        .broadcast_zip(rhs, f)
+        .unwrap_or_else(|err: Error| panic!("{}", err))
+}
+
+macro_rules! impl_view_elementwise_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl_view_elementwise_op!(@new $trait, $method, $op, MatrixView<'_, T>, MatrixView<'_, T>);
+        impl_view_elementwise_op!(@new $trait, $method, $op, MatrixView<'_, T>, &Matrix<T>);
+        impl_view_elementwise_op!(@new $trait, $method, $op, MatrixView<'_, T>, Matrix<T>);
+        impl_view_elementwise_op!(@new $trait, $method, $op, &Matrix<T>, MatrixView<'_, T>);
+
+        impl<T: Copy + $trait<T, Output = T>> $trait<MatrixView<'_, T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(mut self, rhs: MatrixView<'_, T>) -> Matrix<T> {
+                if broadcast_shape(self.shape(), rhs.shape()) != Ok(self.shape()) {
+                    return zip(&self, &rhs, |a, b| a $op b);
+                }
+                zip_assign(&mut self.as_view_mut(), &rhs, |a, b| a $op b);
+                self
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $assign_trait<MatrixView<'_, T>> for Matrix<T> {
+            fn $assign_method(&mut self, rhs: MatrixView<'_, T>) {
+                zip_assign(&mut self.as_view_mut(), &rhs, |a, b| a $op b);
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $assign_trait<MatrixView<'_, T>>
+            for MatrixViewMut<'_, T>
+        {
+            fn $assign_method(&mut self, rhs: MatrixView<'_, T>) {
+                zip_assign(self, &rhs, |a, b| a $op b);
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $assign_trait<&Matrix<T>> for MatrixViewMut<'_, T> {
+            fn $assign_method(&mut self, rhs: &Matrix<T>) {
+                zip_assign(self, rhs, |a, b| a $op b);
+            }
+        }
+    };
+    (@new $trait:ident, $method:ident, $op:tt, $lhs:ty, $rhs:ty) => {
+        impl<T: Copy + $trait<T, Output = T>> $trait<$rhs> for $lhs {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: $rhs) -> Matrix<T> {
+                zip(&self, &rhs, |a, b| a $op b)
+            }
+        }
+    };
+}
+
+impl_view_elementwise_op!(Add, add, AddAssign, add_assign, +);
+impl_view_elementwise_op!(Sub, sub, SubAssign, sub_assign, -);
+
+macro_rules! impl_view_product_op {
+    ($lhs:ty, $rhs:ty) => {
+        impl<T: Ring> Mul<$rhs> for $lhs {
+            type Output = Matrix<T>;
+
+            fn mul(self, rhs: $rhs) -> Matrix<T> {
+                self.as_view()
+                    .product_matrix(&rhs)
+                    .unwrap_or_else(|err| panic!("{}", err))
+            }
+        }
+    };
+}
+
+impl_view_product_op!(MatrixView<'_, T>, MatrixView<'_, T>);
+impl_view_product_op!(MatrixView<'_, T>, Matrix<T>);
+impl_view_product_op!(MatrixView<'_, T>, &Matrix<T>);
+impl_view_product_op!(Matrix<T>, MatrixView<'_, T>);
+impl_view_product_op!(&Matrix<T>, MatrixView<'_, T>);
+
+impl<T: Ring> Mul<&Vector<T>> for MatrixView<'_, T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Vector<T> {
+        self.product_vector(rhs)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+impl<T: Ring> Mul<Vector<T>> for MatrixView<'_, T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Vector<T>) -> Vector<T> {
+        self * &rhs
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T>> Mul<T> for MatrixView<'_, T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, scalar: T) -> Matrix<T> {
+        let mut res = self.to_matrix();
+        res.apply(|&x| x * scalar);
+        res
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T>> MulAssign<T> for MatrixViewMut<'_, T> {
+    fn mul_assign(&mut self, scalar: T) {
+        self.apply(|&x| x * scalar);
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Neg for MatrixView<'_, T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Matrix<T> {
+        let mut res = self.to_matrix();
+        res.apply(|&x| -x);
+        res
+    }
+}
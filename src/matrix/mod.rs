@@ -1,11 +1,113 @@
 mod mat_impl;
 mod mat_mul;
 mod mat_display;
+mod mat_overload;
+mod mat_view;
+mod mat_view_overload;
+mod mat_broadcast;
+mod mat_reduce;
+mod smatrix;
+mod decomposition;
+#[cfg(feature = "blas")]
+mod blas;
+#[cfg(feature = "serde")]
+mod mat_serde;
+
+pub use decomposition::*;
+pub use mat_view::AsMatrixView;
+pub use smatrix::SMatrix;
+
+/**
+ * How the entries of a matrix are ordered in memory
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Layout {
+    /**
+     * Row after row, entry (row, col) at row * width + col
+     */
+    #[default]
+    RowMajor,
+    /**
+     * Column after column like Fortran and LAPACK, entry (row, col) at
+     * col * height + row
+     */
+    ColumnMajor,
+}
+
+/**
+ * What gemm and gemv do to an operand before multiplying, the N, T and C
+ * flags of BLAS
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Op {
+    #[default]
+    NoTranspose,
+    Transpose,
+    /**
+     * The conjugate transpose, which is just the transpose for real matrices
+     */
+    ConjugateTranspose,
+}
+
+/**
+ * Which algorithm product_matrix_with multiplies by
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MulAlgorithm {
+    /**
+     * The cache blocked kernel product_matrix uses
+     */
+    #[default]
+    Blocked,
+    /**
+     * Strassen's seven half size products, with a cutoff of 1024
+     */
+    Strassen,
+    /**
+     * Winograd's variant of Strassen, which needs 15 instead of 18 additions
+     * per level, with a cutoff of 1024
+     */
+    Winograd,
+    /**
+     * Strassen recursing until the smallest side of a product is at most cutoff,
+     * below which the blocked kernel takes over
+     */
+    StrassenWithCutoff(usize),
+    WinogradWithCutoff(usize),
+}
 
 #[derive(Debug, Clone)]
 pub struct Matrix<T> {
     width: usize,
     height: usize,
     size: usize,
-    data: Vec<T>
-}
\ No newline at end of file
+    data: Vec<T>,
+    layout: Layout
+}
+
+/**
+ * A borrowed, possibly strided, window into a matrix. Entry (row, col) lives at
+ * data[row * row_stride + col * col_stride], so submatrices and transposes are
+ * views too and never copy.
+ */
+#[derive(Debug)]
+pub struct MatrixView<'a, T> {
+    data: &'a [T],
+    width: usize,
+    height: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+/**
+ * The mutable counterpart of MatrixView, writes go straight to the matrix it
+ * was taken from
+ */
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    data: &'a mut [T],
+    width: usize,
+    height: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
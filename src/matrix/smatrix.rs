@@ -0,0 +1,297 @@
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::{AsMatrixView, Matrix, MatrixView};
+use crate::{
+    error::Error,
+    numlib::{One, Ring, Zero},
+    vector::SVector,
+};
+
+/**
+ * An R x C matrix on the stack, stored row by row. The shape is part of the
+ * type, so adding different shapes or multiplying R x K by anything but K x C
+ * doesn't compile. The kernels loop a known number of times, which the
+ * compiler unrolls for the 2 x 2 to 6 x 6 sizes these are meant for.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SMatrix<T, const R: usize, const C: usize> {
+    data: [[T; C]; R],
+}
+
+impl<T, const R: usize, const C: usize> SMatrix<T, R, C> {
+    /**
+     * The matrix with the given rows
+     */
+    pub const fn new(data: [[T; C]; R]) -> Self {
+        SMatrix { data }
+    }
+
+    /**
+     * The matrix with f(row, col) at (row, col)
+     */
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(mut f: F) -> Self {
+        SMatrix {
+            data: std::array::from_fn(|row| std::array::from_fn(|col| f(row, col))),
+        }
+    }
+
+    pub const fn width(&self) -> usize {
+        C
+    }
+
+    pub const fn height(&self) -> usize {
+        R
+    }
+
+    /**
+     * (height, width) like Matrix::shape
+     */
+    pub const fn shape(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    pub const fn as_rows(&self) -> &[[T; C]; R] {
+        &self.data
+    }
+
+    pub fn into_rows(self) -> [[T; C]; R] {
+        self.data
+    }
+
+    /**
+     * The entries row by row
+     */
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_flattened()
+    }
+
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            data: self.data.as_flattened(),
+            width: C,
+            height: R,
+            row_stride: C,
+            col_stride: 1,
+        }
+    }
+}
+
+impl<T: Copy, const R: usize, const C: usize> SMatrix<T, R, C> {
+    /**
+     * Copies the entries of a matrix or view of the same shape, in any layout
+     */
+    pub fn from_view<M: AsMatrixView<T> + ?Sized>(matrix: &M) -> Result<Self, Error> {
+        let view = matrix.as_view();
+        if view.shape() != (R, C) {
+            return Err(Error::ShapeMismatch {
+                expected: (R, C),
+                found: view.shape(),
+            });
+        }
+
+        Ok(SMatrix::from_fn(|row, col| view[(row, col)]))
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::new(C, R, self.as_slice().to_vec()).unwrap()
+    }
+
+    pub fn row(&self, row: usize) -> SVector<T, C> {
+        SVector::new(self.data[row])
+    }
+
+    pub fn col(&self, col: usize) -> SVector<T, R> {
+        SVector::from_fn(|row| self.data[row][col])
+    }
+
+    pub fn transpose(&self) -> SMatrix<T, C, R> {
+        SMatrix::from_fn(|row, col| self.data[col][row])
+    }
+
+    pub fn map<U, F: Fn(T) -> U>(&self, f: F) -> SMatrix<U, R, C> {
+        SMatrix::from_fn(|row, col| f(self.data[row][col]))
+    }
+
+    pub fn zip_map<U, F: Fn(T, T) -> U>(&self, other: &SMatrix<T, R, C>, f: F) -> SMatrix<U, R, C> {
+        SMatrix::from_fn(|row, col| f(self.data[row][col], other.data[row][col]))
+    }
+}
+
+impl<T: Zero + Copy, const R: usize, const C: usize> SMatrix<T, R, C> {
+    pub fn zeroes() -> Self {
+        SMatrix::new([[T::zero(); C]; R])
+    }
+}
+
+impl<T: Zero + One + Copy, const N: usize> SMatrix<T, N, N> {
+    pub fn identity() -> Self {
+        SMatrix::from_fn(|row, col| if row == col { T::one() } else { T::zero() })
+    }
+}
+
+impl<T: Ring, const R: usize, const C: usize> SMatrix<T, R, C> {
+    /**
+     * The conjugate transpose
+     */
+    pub fn adjoint(&self) -> SMatrix<T, C, R> {
+        SMatrix::from_fn(|row, col| self.data[col][row].conj())
+    }
+
+    pub fn hadamard(&self, other: &SMatrix<T, R, C>) -> SMatrix<T, R, C> {
+        self.zip_map(other, |a, b| a * b)
+    }
+}
+
+impl<T: Ring, const N: usize> SMatrix<T, N, N> {
+    pub fn trace(&self) -> T {
+        (0..N).fold(T::zero(), |acc, i| acc + self.data[i][i])
+    }
+}
+
+impl<T, const R: usize, const C: usize> AsMatrixView<T> for SMatrix<T, R, C> {
+    fn as_view(&self) -> MatrixView<'_, T> {
+        SMatrix::as_view(self)
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for SMatrix<T, R, C> {
+    fn from(data: [[T; C]; R]) -> Self {
+        SMatrix::new(data)
+    }
+}
+
+impl<T: Copy, const R: usize, const C: usize> From<SMatrix<T, R, C>> for Matrix<T> {
+    fn from(matrix: SMatrix<T, R, C>) -> Self {
+        matrix.to_matrix()
+    }
+}
+
+impl<T: Copy, const R: usize, const C: usize> TryFrom<&Matrix<T>> for SMatrix<T, R, C> {
+    type Error = Error;
+
+    fn try_from(matrix: &Matrix<T>) -> Result<Self, Error> {
+        SMatrix::from_view(matrix)
+    }
+}
+
+impl<T: Copy, const R: usize, const C: usize> TryFrom<MatrixView<'_, T>> for SMatrix<T, R, C> {
+    type Error = Error;
+
+    fn try_from(view: MatrixView<'_, T>) -> Result<Self, Error> {
+        SMatrix::from_view(&view)
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for SMatrix<T, R, C> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.data[row][col]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for SMatrix<T, R, C> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.data[row][col]
+    }
+}
+
+macro_rules! impl_elementwise_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl<T: Copy + $trait<T, Output = T>, const R: usize, const C: usize> $trait
+            for SMatrix<T, R, C>
+        {
+            type Output = SMatrix<T, R, C>;
+
+            fn $method(self, rhs: SMatrix<T, R, C>) -> SMatrix<T, R, C> {
+                self.zip_map(&rhs, |a, b| a $op b)
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>, const R: usize, const C: usize> $assign_trait
+            for SMatrix<T, R, C>
+        {
+            fn $assign_method(&mut self, rhs: SMatrix<T, R, C>) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+impl_elementwise_op!(Add, add, AddAssign, add_assign, +);
+impl_elementwise_op!(Sub, sub, SubAssign, sub_assign, -);
+
+/**
+ * The R x K by K x C product, each entry a dot product of K terms
+ */
+impl<T: Ring, const R: usize, const K: usize, const C: usize> Mul<SMatrix<T, K, C>>
+    for SMatrix<T, R, K>
+{
+    type Output = SMatrix<T, R, C>;
+
+    fn mul(self, rhs: SMatrix<T, K, C>) -> SMatrix<T, R, C> {
+        SMatrix::from_fn(|row, col| {
+            (0..K).fold(T::zero(), |acc, k| {
+                acc + self.data[row][k] * rhs.data[k][col]
+            })
+        })
+    }
+}
+
+impl<T: Ring, const R: usize, const C: usize> Mul<SVector<T, C>> for SMatrix<T, R, C> {
+    type Output = SVector<T, R>;
+
+    fn mul(self, vector: SVector<T, C>) -> SVector<T, R> {
+        SVector::from_fn(|row| {
+            (0..C).fold(T::zero(), |acc, col| {
+                acc + self.data[row][col] * vector[col]
+            })
+        })
+    }
+}
+
+impl<T: Ring, const N: usize> MulAssign<SMatrix<T, N, N>> for SMatrix<T, N, N> {
+    fn mul_assign(&mut self, rhs: SMatrix<T, N, N>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T>, const R: usize, const C: usize> Mul<T> for SMatrix<T, R, C> {
+    type Output = SMatrix<T, R, C>;
+
+    fn mul(self, scalar: T) -> SMatrix<T, R, C> {
+        self.map(|x| x * scalar)
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T>, const R: usize, const C: usize> MulAssign<T>
+    for SMatrix<T, R, C>
+{
+    fn mul_assign(&mut self, scalar: T) {
+        *self = *self * scalar;
+    }
+}
+
+macro_rules! impl_scalar_lhs_mul {
+    (for $($t:ty),+) => {
+        $(impl<const R: usize, const C: usize> Mul<SMatrix<$t, R, C>> for $t {
+            type Output = SMatrix<$t, R, C>;
+
+            fn mul(self, matrix: SMatrix<$t, R, C>) -> SMatrix<$t, R, C> {
+                matrix * self
+            }
+        })*
+    }
+}
+
+impl_scalar_lhs_mul!(for u8, u16, u32, u64, u128, usize);
+impl_scalar_lhs_mul!(for i8, i16, i32, i64, i128, isize);
+impl_scalar_lhs_mul!(for f32, f64);
+
+impl<T: Copy + Neg<Output = T>, const R: usize, const C: usize> Neg for SMatrix<T, R, C> {
+    type Output = SMatrix<T, R, C>;
+
+    fn neg(self) -> SMatrix<T, R, C> {
+        self.map(|x| -x)
+    }
+}
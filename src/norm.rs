@@ -0,0 +1,221 @@
+use crate::{
+    error::Error,
+    matrix::Matrix,
+    numlib::{is_nan, ComplexField, One, RealField, Zero},
+    vector::{Axis, SVector, Vector},
+};
+
+/**
+ * The vector norms, P is the p-norm for any p of at least 1
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VectorNorm<R> {
+    L1,
+    L2,
+    Inf,
+    P(R),
+}
+
+/**
+ * The matrix norms. One and Inf are the largest absolute column and row sums,
+ * Spectral the largest singular value and Nuclear the sum of all of them.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixNorm {
+    Frobenius,
+    One,
+    Inf,
+    Spectral,
+    Nuclear,
+}
+
+/**
+ * The largest of the values, NaN if one of them is and zero if there are none
+ */
+fn max<R: RealField>(values: impl Iterator<Item = R>) -> R {
+    values.fold(
+        R::zero(),
+        |max, x| if x > max || is_nan(&x) { x } else { max },
+    )
+}
+
+/**
+ * sqrt(Σ|x|^2) the way LAPACK's nrm2 does it, keeping the sum of squares scaled
+ * by the largest magnitude so far so it can't overflow or underflow when the
+ * norm itself is representable. Any NaN makes the norm NaN, and otherwise any
+ * infinity makes it infinite, without ever dividing infinity by itself.
+ */
+fn l2<T: ComplexField>(entries: impl Iterator<Item = T>) -> T::Real {
+    let (mut scale, mut ssq) = (T::Real::zero(), T::Real::one());
+    let mut infinity = None;
+    for x in entries {
+        let x = x.abs();
+        if is_nan(&x) {
+            return x;
+        }
+        if x.is_infinite() {
+            infinity = Some(x);
+            continue;
+        }
+        if x == T::Real::zero() {
+            continue;
+        }
+        if scale < x {
+            let ratio = scale / x;
+            ssq = T::Real::one() + ssq * ratio * ratio;
+            scale = x;
+        } else {
+            let ratio = x / scale;
+            ssq = ssq + ratio * ratio;
+        }
+    }
+
+    infinity.unwrap_or_else(|| scale * ssq.sqrt())
+}
+
+impl<T: ComplexField> Vector<T> {
+    pub fn norm(&self, norm: VectorNorm<T::Real>) -> T::Real {
+        match norm {
+            VectorNorm::L1 => self.norm_l1(),
+            VectorNorm::L2 => self.norm_l2(),
+            VectorNorm::Inf => self.norm_inf(),
+            VectorNorm::P(p) => self.norm_p(p),
+        }
+    }
+
+    pub fn norm_l1(&self) -> T::Real {
+        self.as_vec()
+            .iter()
+            .fold(T::Real::zero(), |acc, x| acc + x.abs())
+    }
+
+    pub fn norm_l2(&self) -> T::Real {
+        l2(self.as_vec().iter().copied())
+    }
+
+    pub fn norm_inf(&self) -> T::Real {
+        max(self.as_vec().iter().map(|x| x.abs()))
+    }
+
+    /**
+     * (Σ|x|^p)^(1/p), with every |x| divided by the largest first so the powers
+     * stay in range. An infinite p gives the largest magnitude.
+     */
+    pub fn norm_p(&self, p: T::Real) -> T::Real {
+        let scale = self.norm_inf();
+        if scale == T::Real::zero() || is_nan(&scale) || scale.is_infinite() {
+            return scale;
+        }
+
+        let sum = self
+            .as_vec()
+            .iter()
+            .fold(T::Real::zero(), |acc, x| acc + (x.abs() / scale).powf(p));
+        scale * sum.powf(T::Real::one() / p)
+    }
+
+    /**
+     * The Euclidean length, the same as norm_l2
+     */
+    pub fn abs(&self) -> T::Real {
+        self.norm_l2()
+    }
+
+    /**
+     * Scales the vector to length 1. A zero vector has no direction and comes
+     * out as NaN.
+     */
+    pub fn normalize(&mut self) {
+        let length = T::from_real(self.norm_l2());
+        self.apply(|&x| x / length);
+    }
+
+    pub fn normalized(&self) -> Vector<T> {
+        let mut res = self.clone();
+        res.normalize();
+        res
+    }
+}
+
+impl<T: ComplexField, const N: usize> SVector<T, N> {
+    pub fn norm_l2(&self) -> T::Real {
+        l2(self.as_array().iter().copied())
+    }
+
+    /**
+     * Scales the vector to length 1, NaN for a zero vector like Vector::normalize
+     */
+    pub fn normalize(&mut self) {
+        let length = T::from_real(self.norm_l2());
+        *self = self.map(|x| x / length);
+    }
+
+    pub fn normalized(&self) -> SVector<T, N> {
+        let mut res = *self;
+        res.normalize();
+        res
+    }
+}
+
+impl<T: ComplexField> Matrix<T> {
+    /**
+     * sqrt(Σ|a|^2) over every entry, computed without overflow like norm_l2
+     */
+    pub fn norm_frobenius(&self) -> T::Real {
+        l2(self.as_vec().iter().copied())
+    }
+
+    /**
+     * The largest absolute column sum
+     */
+    pub fn norm_one(&self) -> T::Real {
+        let sums = self.fold_axis(Axis::Column, T::Real::zero(), |acc, x| acc + x.abs());
+        max(sums.as_vec().iter().copied())
+    }
+
+    /**
+     * The largest absolute row sum
+     */
+    pub fn norm_inf(&self) -> T::Real {
+        let sums = self.fold_axis(Axis::Row, T::Real::zero(), |acc, x| acc + x.abs());
+        max(sums.as_vec().iter().copied())
+    }
+}
+
+impl<T: RealField> Matrix<T> {
+    /**
+     * The spectral and nuclear norms need the singular values, so only they
+     * can fail
+     */
+    pub fn norm(&self, norm: MatrixNorm) -> Result<T, Error> {
+        match norm {
+            MatrixNorm::Frobenius => Ok(self.norm_frobenius()),
+            MatrixNorm::One => Ok(self.norm_one()),
+            MatrixNorm::Inf => Ok(self.norm_inf()),
+            MatrixNorm::Spectral => self.norm_spectral(),
+            MatrixNorm::Nuclear => self.norm_nuclear(),
+        }
+    }
+
+    /**
+     * The largest singular value, the most the matrix stretches any vector
+     */
+    pub fn norm_spectral(&self) -> Result<T, Error> {
+        if self.width() == 0 || self.height() == 0 {
+            return Ok(T::zero());
+        }
+
+        Ok(self.singular_values()?.as_vec()[0])
+    }
+
+    /**
+     * The sum of the singular values
+     */
+    pub fn norm_nuclear(&self) -> Result<T, Error> {
+        if self.width() == 0 || self.height() == 0 {
+            return Ok(T::zero());
+        }
+
+        Ok(self.singular_values()?.norm_l1())
+    }
+}
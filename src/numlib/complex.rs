@@ -0,0 +1,186 @@
+use std::fmt::Display;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::{ComplexField, Field, One, RealField, Ring, Scalar, Zero};
+
+/**
+ * A complex number re + im * i
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    pub const fn new(re: T, im: T) -> Complex<T> {
+        Complex { re, im }
+    }
+}
+
+impl<T: Zero> Complex<T> {
+    pub fn from_real(re: T) -> Complex<T> {
+        Complex { re, im: T::zero() }
+    }
+}
+
+impl<T: RealField> Complex<T> {
+    pub fn conj(self) -> Complex<T> {
+        Complex::new(self.re, -self.im)
+    }
+
+    pub fn norm_sqr(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    /**
+     * The absolute value |z|, computed without intermediate overflow
+     */
+    pub fn norm(self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    pub fn is_real(self) -> bool {
+        self.im == T::zero()
+    }
+}
+
+impl<T: Zero> Zero for Complex<T> {
+    fn zero() -> Complex<T> {
+        Complex::new(T::zero(), T::zero())
+    }
+}
+
+impl<T: Zero + One> One for Complex<T> {
+    fn one() -> Complex<T> {
+        Complex::new(T::one(), T::zero())
+    }
+}
+
+impl<T: Add<T, Output = T>> Add for Complex<T> {
+    type Output = Complex<T>;
+
+    fn add(self, other: Complex<T>) -> Complex<T> {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<T: Sub<T, Output = T>> Sub for Complex<T> {
+    type Output = Complex<T>;
+
+    fn sub(self, other: Complex<T>) -> Complex<T> {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T>> Mul for Complex<T> {
+    type Output = Complex<T>;
+
+    fn mul(self, other: Complex<T>) -> Complex<T> {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl<T: RealField> Div for Complex<T> {
+    type Output = Complex<T>;
+
+    /**
+     * Smith's algorithm, which scales by the larger part of the divisor to avoid
+     * overflow
+     */
+    fn div(self, other: Complex<T>) -> Complex<T> {
+        if other.re.abs() > other.im.abs() {
+            let r = other.im / other.re;
+            let d = other.re + r * other.im;
+            Complex::new((self.re + r * self.im) / d, (self.im - r * self.re) / d)
+        } else {
+            let r = other.re / other.im;
+            let d = other.im + r * other.re;
+            Complex::new((r * self.re + self.im) / d, (r * self.im - self.re) / d)
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Complex<T> {
+    type Output = Complex<T>;
+
+    fn neg(self) -> Complex<T> {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl<T: Display + PartialOrd + Zero + Copy + Neg<Output = T>> Display for Complex<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.im < T::zero() {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+impl<T: RealField> Scalar for Complex<T> {}
+
+impl<T: RealField> Ring for Complex<T> {
+    fn conj(self) -> Complex<T> {
+        Complex::conj(self)
+    }
+}
+
+impl<T: RealField> Field for Complex<T> {}
+
+impl<T: RealField> ComplexField for Complex<T> {
+    type Real = T;
+
+    fn from_real(re: T) -> Complex<T> {
+        Complex::new(re, T::zero())
+    }
+
+    fn re(self) -> T {
+        self.re
+    }
+
+    fn im(self) -> T {
+        self.im
+    }
+
+    fn abs(self) -> T {
+        self.norm()
+    }
+
+    fn norm_sqr(self) -> T {
+        Complex::norm_sqr(self)
+    }
+
+    /**
+     * The root with non-negative real part, computed from the modulus to avoid
+     * cancellation
+     */
+    fn sqrt(self) -> Complex<T> {
+        if self.re == T::zero() && self.im == T::zero() {
+            return self;
+        }
+
+        let two = T::one() + T::one();
+        let t = ((self.norm() + self.re.abs()) / two).sqrt();
+        if self.re >= T::zero() {
+            Complex::new(t, self.im / (t + t))
+        } else if self.im < T::zero() {
+            Complex::new(self.im.abs() / (t + t), -t)
+        } else {
+            Complex::new(self.im / (t + t), t)
+        }
+    }
+
+    fn epsilon() -> T {
+        T::epsilon()
+    }
+
+    fn from_f64(x: f64) -> Complex<T> {
+        Complex::new(T::from_f64(x), T::zero())
+    }
+}
@@ -0,0 +1,75 @@
+use std::ops::Neg;
+
+use super::{Field, RealField};
+
+/**
+ * Real or complex floating point numbers, needed by the algorithms that have
+ * to take square roots and decide when a value is small enough to be treated
+ * as zero. Real is the type of the real and imaginary parts.
+ */
+pub trait ComplexField: Field + Neg<Output = Self> {
+    type Real: RealField;
+
+    fn from_real(re: Self::Real) -> Self;
+    fn re(self) -> Self::Real;
+    fn im(self) -> Self::Real;
+    /**
+     * The modulus |z|
+     */
+    fn abs(self) -> Self::Real;
+    /**
+     * |z|^2, which avoids the square root of abs
+     */
+    fn norm_sqr(self) -> Self::Real;
+    /**
+     * The principal square root
+     */
+    fn sqrt(self) -> Self;
+    /**
+     * The difference between 1 and the next representable real number
+     */
+    fn epsilon() -> Self::Real;
+    fn from_f64(x: f64) -> Self;
+}
+
+macro_rules! impl_ComplexField {
+    (for $($t:ident),+) => {
+        $(impl ComplexField for $t {
+            type Real = $t;
+
+            fn from_real(re: $t) -> $t {
+                re
+            }
+
+            fn re(self) -> $t {
+                self
+            }
+
+            fn im(self) -> $t {
+                0.0
+            }
+
+            fn abs(self) -> $t {
+                $t::abs(self)
+            }
+
+            fn norm_sqr(self) -> $t {
+                self * self
+            }
+
+            fn sqrt(self) -> $t {
+                $t::sqrt(self)
+            }
+
+            fn epsilon() -> $t {
+                $t::EPSILON
+            }
+
+            fn from_f64(x: f64) -> $t {
+                x as $t
+            }
+        })*
+    }
+}
+
+impl_ComplexField!(for f32, f64);
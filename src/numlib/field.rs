@@ -0,0 +1,17 @@
+use std::ops::Div;
+
+use super::Ring;
+
+/**
+ * Rings where every non-zero element has an inverse. Integer division
+ * truncates, so only the floating point primitives are fields.
+ */
+pub trait Field: Ring + Div<Self, Output = Self> {}
+
+macro_rules! impl_Field {
+    (for $($t:ty),+) => {
+        $(impl Field for $t {})*
+    }
+}
+
+impl_Field!(for f32, f64);
@@ -3,3 +3,21 @@ pub use zero::*;
 
 mod one;
 pub use one::*;
+
+mod scalar;
+pub use scalar::*;
+
+mod ring;
+pub use ring::*;
+
+mod field;
+pub use field::*;
+
+mod complex_field;
+pub use complex_field::*;
+
+mod real_field;
+pub use real_field::*;
+
+mod complex;
+pub use complex::*;
@@ -14,4 +14,4 @@ macro_rules! impl_One {
 
 impl_One!(for u8, u16, u32, u64, u128, usize);
 impl_One!(for i8, i16, i32, i64, i128, isize);
-impl_One!(for f32, f64);
\ No newline at end of file
+impl_One!(for f32, f64);
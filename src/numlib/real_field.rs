@@ -0,0 +1,59 @@
+use super::ComplexField;
+
+/**
+ * Ordered real floating point numbers, needed by the algorithms that pivot on
+ * or compare magnitudes.
+ */
+pub trait RealField: ComplexField<Real = Self> + PartialOrd {
+    fn ln(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    /**
+     * The angle of the point (other, self) in (-pi, pi]
+     */
+    fn atan2(self, other: Self) -> Self;
+    fn is_infinite(self) -> bool;
+    fn from_usize(n: usize) -> Self;
+}
+
+macro_rules! impl_RealField {
+    (for $($t:ident),+) => {
+        $(impl RealField for $t {
+            fn ln(self) -> $t {
+                $t::ln(self)
+            }
+
+            fn hypot(self, other: $t) -> $t {
+                $t::hypot(self, other)
+            }
+
+            fn powf(self, n: $t) -> $t {
+                $t::powf(self, n)
+            }
+
+            fn sin(self) -> $t {
+                $t::sin(self)
+            }
+
+            fn cos(self) -> $t {
+                $t::cos(self)
+            }
+
+            fn atan2(self, other: $t) -> $t {
+                $t::atan2(self, other)
+            }
+
+            fn is_infinite(self) -> bool {
+                $t::is_infinite(self)
+            }
+
+            fn from_usize(n: usize) -> $t {
+                n as $t
+            }
+        })*
+    }
+}
+
+impl_RealField!(for f32, f64);
@@ -0,0 +1,32 @@
+use std::ops::{Add, Mul, Sub};
+
+use super::{One, Scalar, Zero};
+
+/**
+ * Scalars with addition, subtraction and multiplication, which is all that
+ * sums and matrix products need
+ */
+pub trait Ring:
+    Scalar + Zero + One + Add<Self, Output = Self> + Sub<Self, Output = Self> + Mul<Self, Output = Self>
+{
+    /**
+     * The complex conjugate, which leaves real scalars unchanged. Inner products
+     * and adjoints conjugate through this so they work the same for every ring.
+     * There is no default, so a complex-like ring can't silently skip it.
+     */
+    fn conj(self) -> Self;
+}
+
+macro_rules! impl_Ring {
+    (for $($t:ty),+) => {
+        $(impl Ring for $t {
+            fn conj(self) -> $t {
+                self
+            }
+        })*
+    }
+}
+
+impl_Ring!(for u8, u16, u32, u64, u128, usize);
+impl_Ring!(for i8, i16, i32, i64, i128, isize);
+impl_Ring!(for f32, f64);
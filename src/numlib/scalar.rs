@@ -0,0 +1,26 @@
+use std::fmt::Debug;
+
+/**
+ * Anything that can be stored in a matrix or vector and moved between threads
+ * by the parallel kernels. Being 'static lets the kernels recognise f32 and f64
+ * and hand them to the SIMD code.
+ */
+pub trait Scalar: Copy + PartialEq + Debug + Send + Sync + 'static {}
+
+macro_rules! impl_Scalar {
+    (for $($t:ty),+) => {
+        $(impl Scalar for $t {})*
+    }
+}
+
+impl_Scalar!(for u8, u16, u32, u64, u128, usize);
+impl_Scalar!(for i8, i16, i32, i64, i128, isize);
+impl_Scalar!(for f32, f64);
+
+/**
+ * NaN for floats and complex numbers with a NaN part, never for integers
+ */
+#[allow(clippy::eq_op)]
+pub(crate) fn is_nan<T: PartialEq>(x: &T) -> bool {
+    x != x
+}
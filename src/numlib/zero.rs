@@ -14,4 +14,4 @@ macro_rules! impl_Zero {
 
 impl_Zero!(for u8, u16, u32, u64, u128, usize);
 impl_Zero!(for i8, i16, i32, i64, i128, isize);
-impl_Zero!(for f32, f64);
\ No newline at end of file
+impl_Zero!(for f32, f64);
@@ -0,0 +1,373 @@
+use std::cell::RefCell;
+use std::sync::RwLock;
+
+#[cfg(feature = "parallel")]
+use std::sync::{Arc, OnceLock};
+
+#[cfg(feature = "parallel")]
+use rayon::{prelude::*, ThreadPool, ThreadPoolBuilder};
+
+use crate::{
+    arch,
+    numlib::{Field, Ring},
+};
+
+/**
+ * Work below this many multiply-adds, or entries for elementwise operations,
+ * stays on the calling thread unless a threshold is set
+ */
+pub const DEFAULT_THRESHOLD: usize = 1 << 16;
+
+/**
+ * Elementwise operations and reductions work on chunks of this many entries.
+ * Sums are added up chunk by chunk in order however many threads run, so their
+ * rounding doesn't depend on the parallelism.
+ */
+const CHUNK: usize = 1 << 14;
+
+/**
+ * Where the work of products, elementwise operations, reductions and
+ * factorizations runs. Without the parallel feature everything is sequential.
+ */
+#[derive(Debug, Clone)]
+pub enum Parallelism {
+    /**
+     * Everything on the calling thread
+     */
+    Sequential,
+    /**
+     * Rayon's global pool, or the pool the caller is already running in
+     */
+    Global,
+    /**
+     * A pool of this many threads, built the first time the context runs work.
+     * The context and its clones share it and it goes away with the last of
+     * them, so keep the context around rather than making one per call. Zero
+     * lets rayon pick, like the global pool does.
+     */
+    Threads(usize),
+    /**
+     * A pool the caller built, for programs that already manage their own
+     */
+    #[cfg(feature = "parallel")]
+    Pool(Arc<ThreadPool>),
+}
+
+impl Default for Parallelism {
+    fn default() -> Parallelism {
+        if cfg!(feature = "parallel") {
+            Parallelism::Global
+        } else {
+            Parallelism::Sequential
+        }
+    }
+}
+
+/**
+ * A parallelism together with the size below which work stays single-threaded.
+ * One is set for the whole program with set_global, or for a stretch of code on
+ * the current thread with install.
+ */
+#[derive(Debug, Clone)]
+pub struct ExecutionContext {
+    parallelism: Parallelism,
+    threshold: usize,
+    #[cfg(feature = "parallel")]
+    pool: Arc<OnceLock<Option<ThreadPool>>>,
+}
+
+static GLOBAL: RwLock<Option<ExecutionContext>> = RwLock::new(None);
+
+thread_local! {
+    static INSTALLED: RefCell<Option<ExecutionContext>> = const { RefCell::new(None) };
+}
+
+impl Default for ExecutionContext {
+    fn default() -> ExecutionContext {
+        ExecutionContext::new(Parallelism::default())
+    }
+}
+
+impl ExecutionContext {
+    pub fn new(parallelism: Parallelism) -> ExecutionContext {
+        ExecutionContext {
+            parallelism,
+            threshold: DEFAULT_THRESHOLD,
+            #[cfg(feature = "parallel")]
+            pool: Default::default(),
+        }
+    }
+
+    pub fn sequential() -> ExecutionContext {
+        ExecutionContext::new(Parallelism::Sequential)
+    }
+
+    pub fn with_threshold(mut self, threshold: usize) -> ExecutionContext {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn parallelism(&self) -> &Parallelism {
+        &self.parallelism
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /**
+     * The context operations on this thread run with, the installed one if
+     * there is one and the global one otherwise
+     */
+    pub fn current() -> ExecutionContext {
+        INSTALLED
+            .with(|installed| installed.borrow().clone())
+            .or_else(|| GLOBAL.read().unwrap().clone())
+            .unwrap_or_default()
+    }
+
+    /**
+     * Makes this the context of every thread that hasn't installed its own
+     */
+    pub fn set_global(self) {
+        *GLOBAL.write().unwrap() = Some(self);
+    }
+
+    /**
+     * Runs f with this context on the current thread, restoring the previous one
+     * afterwards, even when f panics
+     */
+    pub fn install<R>(&self, f: impl FnOnce() -> R) -> R {
+        struct Restore(Option<ExecutionContext>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                INSTALLED.with(|installed| *installed.borrow_mut() = self.0.take());
+            }
+        }
+
+        let previous = INSTALLED.with(|installed| installed.replace(Some(self.clone())));
+        let _restore = Restore(previous);
+        f()
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl ExecutionContext {
+    /**
+     * The pool of Parallelism::Threads, None if rayon couldn't build it
+     */
+    fn threads_pool(&self, threads: usize) -> Option<&ThreadPool> {
+        self.pool
+            .get_or_init(|| ThreadPoolBuilder::new().num_threads(threads).build().ok())
+            .as_ref()
+    }
+}
+
+/**
+ * Runs f in the current context, telling it whether to split its work over
+ * threads. Only the outermost call of an operation asks, the kernels below it
+ * pass the answer on, so nested work stays in the pool that was picked.
+ */
+pub(crate) fn run<R: Send>(work: usize, f: impl FnOnce(bool) -> R + Send) -> R {
+    let context = ExecutionContext::current();
+    if work < context.threshold {
+        return f(false);
+    }
+
+    match context.parallelism {
+        Parallelism::Sequential => f(false),
+        #[cfg(not(feature = "parallel"))]
+        Parallelism::Global | Parallelism::Threads(_) => f(false),
+        #[cfg(feature = "parallel")]
+        Parallelism::Global => f(true),
+        #[cfg(feature = "parallel")]
+        Parallelism::Threads(threads) => match context.threads_pool(threads) {
+            Some(pool) => pool.install(|| f(true)),
+            None => f(false),
+        },
+        #[cfg(feature = "parallel")]
+        Parallelism::Pool(pool) => pool.install(|| f(true)),
+    }
+}
+
+/**
+ * Calls f with the index and contents of every chunk of data
+ */
+pub(crate) fn for_each_chunk<T: Send>(
+    parallel: bool,
+    data: &mut [T],
+    size: usize,
+    f: impl Fn(usize, &mut [T]) + Send + Sync,
+) {
+    #[cfg(feature = "parallel")]
+    if parallel {
+        data.par_chunks_mut(size)
+            .enumerate()
+            .for_each(|(index, chunk)| f(index, chunk));
+        return;
+    }
+
+    let _ = parallel;
+    for (index, chunk) in data.chunks_mut(size).enumerate() {
+        f(index, chunk);
+    }
+}
+
+/**
+ * for_each_chunk with a scratch value from init for f to reuse. Each thread
+ * makes its own and drops it when the call returns.
+ */
+pub(crate) fn for_each_chunk_init<T: Send, S>(
+    parallel: bool,
+    data: &mut [T],
+    size: usize,
+    init: impl Fn() -> S + Send + Sync,
+    f: impl Fn(&mut S, usize, &mut [T]) + Send + Sync,
+) {
+    #[cfg(feature = "parallel")]
+    if parallel {
+        data.par_chunks_mut(size)
+            .enumerate()
+            .for_each_init(&init, |scratch, (index, chunk)| f(scratch, index, chunk));
+        return;
+    }
+
+    let _ = parallel;
+    let mut scratch = init();
+    for (index, chunk) in data.chunks_mut(size).enumerate() {
+        f(&mut scratch, index, chunk);
+    }
+}
+
+/**
+ * f of every item, in order
+ */
+pub(crate) fn map<I: Send, R: Send>(
+    parallel: bool,
+    items: Vec<I>,
+    f: impl Fn(I) -> R + Send + Sync,
+) -> Vec<R> {
+    #[cfg(feature = "parallel")]
+    if parallel {
+        return items.into_par_iter().map(f).collect();
+    }
+
+    let _ = parallel;
+    items.into_iter().map(f).collect()
+}
+
+/**
+ * Adds up f of every chunk of data in order
+ */
+fn reduce<T: Ring>(data: &[T], f: impl Fn(usize, &[T]) -> T + Send + Sync) -> T {
+    run(data.len(), |parallel| {
+        let chunks: Vec<(usize, &[T])> = data.chunks(CHUNK).enumerate().collect();
+        map(parallel, chunks, |(index, chunk)| f(index, chunk))
+            .into_iter()
+            .fold(T::zero(), |acc, x| acc + x)
+    })
+}
+
+/**
+ * The arch kernels over the current context
+ */
+pub(crate) fn add_assign<T: Ring>(a: &mut [T], b: &[T]) {
+    run(a.len(), |parallel| {
+        for_each_chunk(parallel, a, CHUNK, |index, chunk| {
+            arch::add_assign(chunk, b.get(index * CHUNK..).unwrap_or(&[]))
+        })
+    })
+}
+
+pub(crate) fn sub_assign<T: Ring>(a: &mut [T], b: &[T]) {
+    run(a.len(), |parallel| {
+        for_each_chunk(parallel, a, CHUNK, |index, chunk| {
+            arch::sub_assign(chunk, b.get(index * CHUNK..).unwrap_or(&[]))
+        })
+    })
+}
+
+pub(crate) fn mul_assign<T: Ring>(a: &mut [T], b: &[T]) {
+    run(a.len(), |parallel| {
+        for_each_chunk(parallel, a, CHUNK, |index, chunk| {
+            arch::mul_assign(chunk, b.get(index * CHUNK..).unwrap_or(&[]))
+        })
+    })
+}
+
+pub(crate) fn div_assign<T: Field>(a: &mut [T], b: &[T]) {
+    run(a.len(), |parallel| {
+        for_each_chunk(parallel, a, CHUNK, |index, chunk| {
+            arch::div_assign(chunk, b.get(index * CHUNK..).unwrap_or(&[]))
+        })
+    })
+}
+
+pub(crate) fn scale<T: Ring>(a: &mut [T], scalar: T) {
+    run(a.len(), |parallel| {
+        for_each_chunk(parallel, a, CHUNK, |_, chunk| arch::scale(chunk, scalar))
+    })
+}
+
+pub(crate) fn sum<T: Ring>(a: &[T]) -> T {
+    reduce(a, |_, chunk| arch::sum(chunk))
+}
+
+pub(crate) fn dotc<T: Ring>(a: &[T], b: &[T]) -> T {
+    let b = &b[..a.len().min(b.len())];
+    reduce(&a[..b.len()], |index, chunk| {
+        arch::dotc(chunk, b.get(index * CHUNK..).unwrap_or(&[]))
+    })
+}
+
+/**
+ * f of every entry, in order
+ */
+pub(crate) fn map_slice<T: Sync, U: Send>(a: &[T], f: impl Fn(&T) -> U + Send + Sync) -> Vec<U> {
+    run(a.len(), |parallel| {
+        #[cfg(feature = "parallel")]
+        if parallel {
+            return a.par_iter().map(f).collect();
+        }
+
+        let _ = parallel;
+        a.iter().map(f).collect()
+    })
+}
+
+/**
+ * f of every pair of entries, over the shorter of the two
+ */
+pub(crate) fn zip_map<T: Sync, U: Sync, V: Send>(
+    a: &[T],
+    b: &[U],
+    f: impl Fn(&T, &U) -> V + Send + Sync,
+) -> Vec<V> {
+    run(a.len().min(b.len()), |parallel| {
+        #[cfg(feature = "parallel")]
+        if parallel {
+            return a.par_iter().zip(b).map(|(x, y)| f(x, y)).collect();
+        }
+
+        let _ = parallel;
+        a.iter().zip(b).map(|(x, y)| f(x, y)).collect()
+    })
+}
+
+/**
+ * Replaces every entry of a by f of it and the entry of b at the same place
+ */
+pub(crate) fn zip_apply<T: Send + Sync, U: Sync>(
+    a: &mut [T],
+    b: &[U],
+    f: impl Fn(&T, &U) -> T + Send + Sync,
+) {
+    run(a.len(), |parallel| {
+        for_each_chunk(parallel, a, CHUNK, |index, chunk| {
+            let b = b.get(index * CHUNK..).unwrap_or(&[]);
+            for (x, y) in chunk.iter_mut().zip(b) {
+                *x = f(x, y);
+            }
+        })
+    })
+}
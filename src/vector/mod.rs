@@ -1,6 +1,13 @@
 mod vec_impl;
 mod vec_ops;
+mod vec_overload;
+mod svector;
+#[cfg(feature = "serde")]
+mod vec_serde;
 
+pub use svector::SVector;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Axis {
     Column,
     Row,
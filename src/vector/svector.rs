@@ -0,0 +1,210 @@
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::Vector;
+use crate::{
+    error::Error,
+    numlib::{One, Ring, Zero},
+};
+
+/**
+ * A vector of N entries on the stack. The length is part of the type, so
+ * operands of different lengths don't compile instead of panicking, and every
+ * loop runs a known number of times that the compiler unrolls for the small
+ * sizes these are meant for.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SVector<T, const N: usize> {
+    data: [T; N],
+}
+
+impl<T, const N: usize> SVector<T, N> {
+    pub const fn new(data: [T; N]) -> Self {
+        SVector { data }
+    }
+
+    /**
+     * The vector with f(i) at index i
+     */
+    pub fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self {
+        SVector {
+            data: std::array::from_fn(f),
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    pub const fn as_array(&self) -> &[T; N] {
+        &self.data
+    }
+
+    pub fn as_mut_array(&mut self) -> &mut [T; N] {
+        &mut self.data
+    }
+
+    pub fn into_array(self) -> [T; N] {
+        self.data
+    }
+}
+
+impl<T: Copy, const N: usize> SVector<T, N> {
+    pub fn map<U, F: Fn(T) -> U>(&self, f: F) -> SVector<U, N> {
+        SVector::from_fn(|i| f(self.data[i]))
+    }
+
+    pub fn zip_map<U, F: Fn(T, T) -> U>(&self, other: &SVector<T, N>, f: F) -> SVector<U, N> {
+        SVector::from_fn(|i| f(self.data[i], other.data[i]))
+    }
+}
+
+impl<T: Zero + Copy, const N: usize> SVector<T, N> {
+    pub fn zeroes() -> Self {
+        SVector::new([T::zero(); N])
+    }
+}
+
+impl<T: One + Copy, const N: usize> SVector<T, N> {
+    pub fn ones() -> Self {
+        SVector::new([T::one(); N])
+    }
+}
+
+impl<T: Ring, const N: usize> SVector<T, N> {
+    /**
+     * Σ conj(a) * b, conjugating self like Vector::dot does
+     */
+    pub fn dot(&self, other: &SVector<T, N>) -> T {
+        (0..N).fold(T::zero(), |acc, i| {
+            acc + self.data[i].conj() * other.data[i]
+        })
+    }
+
+    pub fn sum(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, &x| acc + x)
+    }
+
+    pub fn hadamard(&self, other: &SVector<T, N>) -> SVector<T, N> {
+        self.zip_map(other, |a, b| a * b)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for SVector<T, N> {
+    fn from(data: [T; N]) -> Self {
+        SVector::new(data)
+    }
+}
+
+impl<T, const N: usize> From<SVector<T, N>> for Vector<T> {
+    fn from(vector: SVector<T, N>) -> Self {
+        Vector::new(Vec::from(vector.data))
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vector<T>> for SVector<T, N> {
+    type Error = Error;
+
+    fn try_from(vector: Vector<T>) -> Result<Self, Error> {
+        let found = vector.size;
+        let data = vector
+            .data
+            .try_into()
+            .map_err(|_| Error::DimensionMismatch { expected: N, found })?;
+
+        Ok(SVector { data })
+    }
+}
+
+impl<T: Copy, const N: usize> TryFrom<&Vector<T>> for SVector<T, N> {
+    type Error = Error;
+
+    fn try_from(vector: &Vector<T>) -> Result<Self, Error> {
+        let data = vector
+            .data
+            .as_slice()
+            .try_into()
+            .map_err(|_| Error::DimensionMismatch {
+                expected: N,
+                found: vector.size,
+            })?;
+
+        Ok(SVector { data })
+    }
+}
+
+impl<T, const N: usize> Index<usize> for SVector<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.data[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for SVector<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.data[index]
+    }
+}
+
+macro_rules! impl_elementwise_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl<T: Copy + $trait<T, Output = T>, const N: usize> $trait for SVector<T, N> {
+            type Output = SVector<T, N>;
+
+            fn $method(self, rhs: SVector<T, N>) -> SVector<T, N> {
+                self.zip_map(&rhs, |a, b| a $op b)
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>, const N: usize> $assign_trait for SVector<T, N> {
+            fn $assign_method(&mut self, rhs: SVector<T, N>) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+impl_elementwise_op!(Add, add, AddAssign, add_assign, +);
+impl_elementwise_op!(Sub, sub, SubAssign, sub_assign, -);
+
+impl<T: Copy + Mul<T, Output = T>, const N: usize> Mul<T> for SVector<T, N> {
+    type Output = SVector<T, N>;
+
+    fn mul(self, scalar: T) -> SVector<T, N> {
+        self.map(|x| x * scalar)
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T>, const N: usize> MulAssign<T> for SVector<T, N> {
+    fn mul_assign(&mut self, scalar: T) {
+        *self = *self * scalar;
+    }
+}
+
+macro_rules! impl_scalar_lhs_mul {
+    (for $($t:ty),+) => {
+        $(impl<const N: usize> Mul<SVector<$t, N>> for $t {
+            type Output = SVector<$t, N>;
+
+            fn mul(self, vector: SVector<$t, N>) -> SVector<$t, N> {
+                vector * self
+            }
+        })*
+    }
+}
+
+impl_scalar_lhs_mul!(for u8, u16, u32, u64, u128, usize);
+impl_scalar_lhs_mul!(for i8, i16, i32, i64, i128, isize);
+impl_scalar_lhs_mul!(for f32, f64);
+
+impl<T: Copy + Neg<Output = T>, const N: usize> Neg for SVector<T, N> {
+    type Output = SVector<T, N>;
+
+    fn neg(self) -> SVector<T, N> {
+        self.map(|x| -x)
+    }
+}
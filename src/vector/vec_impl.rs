@@ -1,4 +1,5 @@
 use crate::{
+    error::Error,
     matrix::Matrix,
     numlib::{One, Zero},
 };
@@ -24,6 +25,10 @@ impl<T> Vector<T> {
     pub const fn len(&self) -> usize {
         self.size
     }
+
+    pub const fn is_empty(&self) -> bool {
+        self.size == 0
+    }
 }
 
 impl<T: Copy> Vector<T> {
@@ -69,9 +74,12 @@ impl<T: Zero + One + Clone> Vector<T> {
     pub fn cartesian_unit_vector(
         number: usize,
         dimensions: usize,
-    ) -> Result<Vector<T>, &'static str> {
-        if number > dimensions {
-            return Err("The number cannot be larger than the dimensions");
+    ) -> Result<Vector<T>, Error> {
+        if number == 0 || number > dimensions {
+            return Err(Error::IndexOutOfBounds {
+                index: number,
+                bound: dimensions,
+            });
         }
         let mut data = vec![T::zero(); dimensions];
         data[number - 1] = T::one();
@@ -1,6 +1,8 @@
-use std::ops::{Mul, Add, Sub};
-
-use crate::numlib::Zero;
+use crate::{
+    error::Error,
+    numlib::{Field, Ring},
+    parallel,
+};
 
 use super::Vector;
 
@@ -12,95 +14,135 @@ impl<T: Copy> Vector<T> {
     }
 }
 
-impl<T: Copy + Mul<T, Output = T>> Vector<T> {
-    pub fn scale(&self, scalar: T) -> Vector<T> {
-        let mut res = self.data.to_vec();
+impl<T: Sync> Vector<T> {
+    /**
+     * A vector of f of every entry, which may be of another type
+     */
+    pub fn map<U: Send, F: Fn(&T) -> U + Send + Sync>(&self, f: F) -> Vector<U> {
+        Vector::new(parallel::map_slice(&self.data, f))
+    }
 
-        for val in &mut res {
-            *val = (*val) * scalar;
+    /**
+     * A vector of f of every entry and the entry at the same position of other
+     */
+    pub fn zip_map<U: Sync, V: Send, F: Fn(&T, &U) -> V + Send + Sync>(
+        &self,
+        other: &Vector<U>,
+        f: F,
+    ) -> Result<Vector<V>, Error> {
+        if other.size != self.size {
+            return Err(Error::DimensionMismatch {
+                expected: self.size,
+                found: other.size,
+            });
         }
 
-        Vector::new(res)
+        Ok(Vector::new(parallel::zip_map(&self.data, &other.data, f)))
     }
 }
 
-impl<T: Copy + Add<T, Output = T>> Vector<T> {
-    pub fn add(&self, other: &Vector<T>) -> Result<Vector<T>, &'static str> {
-        let to_add = other.as_vec();
+impl<T: Send + Sync> Vector<T> {
+    /**
+     * Replaces every entry by f of it and the entry at the same position of other
+     */
+    pub fn zip_apply<U: Sync, F: Fn(&T, &U) -> T + Send + Sync>(
+        &mut self,
+        other: &Vector<U>,
+        f: F,
+    ) -> Result<(), Error> {
         if other.size != self.size {
-            return Err("Vectors don't match in size");
+            return Err(Error::DimensionMismatch {
+                expected: self.size,
+                found: other.size,
+            });
         }
+        parallel::zip_apply(&mut self.data, &other.data, f);
+
+        Ok(())
+    }
+}
+
+impl<T: Ring> Vector<T> {
+    pub fn scale(&self, scalar: T) -> Vector<T> {
         let mut res = self.data.to_vec();
-        for index in 0..self.size {
-            if let Some(elem) = res.get_mut(index) {
-                // Unreachable since they have the same size and thus all indexes we loop through are
-                // Also present in to_add
-                *elem = *elem
-                    + match to_add.get(index) {
-                        Some(value) => *value,
-                        None => unreachable!(),
-                    };
-            }
+        parallel::scale(&mut res, scalar);
+
+        Vector::new(res)
+    }
+
+    pub fn add(&self, other: &Vector<T>) -> Result<Vector<T>, Error> {
+        if other.size != self.size {
+            return Err(Error::DimensionMismatch {
+                expected: self.size,
+                found: other.size,
+            });
         }
+        let mut res = self.data.to_vec();
+        parallel::add_assign(&mut res, &other.data);
 
         Ok(Vector::new(res))
     }
-}
 
-impl<T: Copy + Sub<T, Output = T>> Vector<T> {
-    pub fn sub(&self, other: &Vector<T>) -> Result<Vector<T>, &'static str> {
-        let to_add = other.as_vec();
+    pub fn sub(&self, other: &Vector<T>) -> Result<Vector<T>, Error> {
         if other.size != self.size {
-            return Err("Vectors don't match in size");
+            return Err(Error::DimensionMismatch {
+                expected: self.size,
+                found: other.size,
+            });
         }
         let mut res = self.data.to_vec();
-        for index in 0..self.size {
-            if let Some(elem) = res.get_mut(index) {
-                // None is unreachable since they have the same size and thus all indexes we loop through are
-                // Also present in to_add
-                *elem = *elem
-                    - match to_add.get(index) {
-                        Some(value) => *value,
-                        None => unreachable!(),
-                    };
-            }
-        }
+        parallel::sub_assign(&mut res, &other.data);
 
         Ok(Vector::new(res))
     }
-}
 
-impl<T: Copy + Zero + Add<T, Output = T> + Mul<T, Output = T>> Vector<T> {
-    pub fn dot(&self, other: &Vector<T>) -> Result<T, &'static str> {
+    /**
+     * The inner product, conjugating self so that complex vectors get the
+     * Hermitian inner product and v.dot(v) is real
+     */
+    pub fn dot(&self, other: &Vector<T>) -> Result<T, Error> {
         if self.size != other.size {
-            return Err("Vectors don't match in size");
+            return Err(Error::DimensionMismatch {
+                expected: self.size,
+                found: other.size,
+            });
         }
 
-        let mut res = T::zero();
+        Ok(parallel::dotc(&self.data, &other.data))
+    }
 
-        if self.size == 0 {
-            return Ok(res);
-        }
+    pub fn sum(&self) -> T {
+        parallel::sum(&self.data)
+    }
 
-        for index in 0..self.size {
-            if let Some(elem) = self.data.get(index) {
-                // None is unreachable since they have the same size and thus all indexes we loop through are
-                // Also present in to_add
-                res = res
-                    + (*elem
-                        * match other.data.get(index) {
-                            Some(value) => *value,
-                            None => unreachable!(),
-                        });
-            }
+    /**
+     * The entrywise product
+     */
+    pub fn hadamard(&self, other: &Vector<T>) -> Result<Vector<T>, Error> {
+        if other.size != self.size {
+            return Err(Error::DimensionMismatch {
+                expected: self.size,
+                found: other.size,
+            });
         }
+        let mut res = self.data.to_vec();
+        parallel::mul_assign(&mut res, &other.data);
 
-        Ok(res)
+        Ok(Vector::new(res))
     }
 }
 
-impl Vector<f64> {
-    pub fn abs(&self) -> f64 {
-        self.dot(&self).unwrap().sqrt()
+impl<T: Field> Vector<T> {
+    pub fn elementwise_div(&self, other: &Vector<T>) -> Result<Vector<T>, Error> {
+        if other.size != self.size {
+            return Err(Error::DimensionMismatch {
+                expected: self.size,
+                found: other.size,
+            });
+        }
+        let mut res = self.data.to_vec();
+        parallel::div_assign(&mut res, &other.data);
+
+        Ok(Vector::new(res))
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,145 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::Vector;
+use crate::error::Error;
+
+/**
+ * Operator overloads for vectors. These are the unchecked counterparts of
+ * the Result returning methods and panic when the lengths don't line up.
+ */
+fn zip_assign<T: Copy, F: Fn(T, T) -> T>(lhs: &mut Vector<T>, rhs: &Vector<T>, f: F) {
+    if lhs.size != rhs.size {
+        panic!(
+            "{}",
+            Error::DimensionMismatch {
+                expected: lhs.size,
+                found: rhs.size,
+            }
+        );
+    }
+
+    for (a, b) in lhs.data.iter_mut().zip(rhs.data.iter()) {
+        *a = f(*a, *b);
+    }
+}
+
+macro_rules! impl_elementwise_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl<T: Copy + $trait<T, Output = T>> $assign_trait<&Vector<T>> for Vector<T> {
+            fn $assign_method(&mut self, rhs: &Vector<T>) {
+                zip_assign(self, rhs, |a, b| a $op b);
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $assign_trait<Vector<T>> for Vector<T> {
+            fn $assign_method(&mut self, rhs: Vector<T>) {
+                zip_assign(self, &rhs, |a, b| a $op b);
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $trait<&Vector<T>> for Vector<T> {
+            type Output = Vector<T>;
+
+            fn $method(mut self, rhs: &Vector<T>) -> Vector<T> {
+                zip_assign(&mut self, rhs, |a, b| a $op b);
+                self
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $trait<Vector<T>> for Vector<T> {
+            type Output = Vector<T>;
+
+            fn $method(mut self, rhs: Vector<T>) -> Vector<T> {
+                zip_assign(&mut self, &rhs, |a, b| a $op b);
+                self
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $trait<&Vector<T>> for &Vector<T> {
+            type Output = Vector<T>;
+
+            fn $method(self, rhs: &Vector<T>) -> Vector<T> {
+                let mut res = self.clone();
+                zip_assign(&mut res, rhs, |a, b| a $op b);
+                res
+            }
+        }
+
+        impl<T: Copy + $trait<T, Output = T>> $trait<Vector<T>> for &Vector<T> {
+            type Output = Vector<T>;
+
+            fn $method(self, mut rhs: Vector<T>) -> Vector<T> {
+                // Reuse the buffer of the owned right hand side
+                zip_assign(&mut rhs, self, |b, a| a $op b);
+                rhs
+            }
+        }
+    };
+}
+
+impl_elementwise_op!(Add, add, AddAssign, add_assign, +);
+impl_elementwise_op!(Sub, sub, SubAssign, sub_assign, -);
+
+impl<T: Copy + Mul<T, Output = T>> MulAssign<T> for Vector<T> {
+    fn mul_assign(&mut self, scalar: T) {
+        self.apply(|&x| x * scalar);
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T>> Mul<T> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(mut self, scalar: T) -> Vector<T> {
+        self *= scalar;
+        self
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T>> Mul<T> for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, scalar: T) -> Vector<T> {
+        self.clone() * scalar
+    }
+}
+
+macro_rules! impl_scalar_lhs_mul {
+    (for $($t:ty),+) => {
+        $(impl Mul<Vector<$t>> for $t {
+            type Output = Vector<$t>;
+
+            fn mul(self, vector: Vector<$t>) -> Vector<$t> {
+                vector * self
+            }
+        }
+
+        impl Mul<&Vector<$t>> for $t {
+            type Output = Vector<$t>;
+
+            fn mul(self, vector: &Vector<$t>) -> Vector<$t> {
+                vector * self
+            }
+        })*
+    }
+}
+
+impl_scalar_lhs_mul!(for u8, u16, u32, u64, u128, usize);
+impl_scalar_lhs_mul!(for i8, i16, i32, i64, i128, isize);
+impl_scalar_lhs_mul!(for f32, f64);
+
+impl<T: Copy + Neg<Output = T>> Neg for Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(mut self) -> Vector<T> {
+        self.apply(|&x| -x);
+        self
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Neg for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Vector<T> {
+        -self.clone()
+    }
+}
@@ -0,0 +1,39 @@
+use serde::{
+    de::Error as _, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::Vector;
+
+// A vector is written as its size and its entries, and reading one back checks
+// that the two agree.
+
+impl<T: Serialize> Serialize for Vector<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Vector", 2)?;
+        state.serialize_field("size", &self.size)?;
+        state.serialize_field("data", &self.data)?;
+        state.end()
+    }
+}
+
+/**
+ * A vector as it was read, before the size is checked against the data
+ */
+#[derive(Deserialize)]
+#[serde(rename = "Vector")]
+struct Unchecked<T> {
+    size: usize,
+    data: Vec<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vector<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Unchecked { size, data } = Unchecked::deserialize(deserializer)?;
+        if data.len() != size {
+            let expected = format!("{} entries, the size of the vector", size);
+            return Err(D::Error::invalid_length(data.len(), &expected.as_str()));
+        }
+
+        Ok(Vector::new(data))
+    }
+}
@@ -0,0 +1,87 @@
+mod common;
+
+#[cfg(test)]
+mod arch_tests {
+    extern crate linearalgebra;
+
+    use super::common::integers;
+    use linearalgebra::matrix::*;
+    use linearalgebra::vector::*;
+
+    // Lengths around every vector width, so the SIMD loops and their scalar
+    // tails both run. Small integers keep the float results exact.
+    const LENGTHS: [usize; 8] = [0, 1, 3, 7, 8, 17, 33, 100];
+
+    macro_rules! elementwise_test {
+        ($name:ident, $t:ty) => {
+            #[test]
+            fn $name() {
+                for len in LENGTHS {
+                    let (a, b) = (integers(len, 1), integers(len, 4));
+                    let x = Vector::new(a.iter().map(|&v| v as $t).collect());
+                    let y = Vector::new(b.iter().map(|&v| v as $t).collect());
+                    let expect = |f: &dyn Fn(i64, i64) -> i64| -> Vec<$t> {
+                        a.iter().zip(&b).map(|(&p, &q)| f(p, q) as $t).collect()
+                    };
+
+                    assert_eq!(&expect(&|p, q| p + q), x.add(&y).unwrap().as_vec());
+                    assert_eq!(&expect(&|p, q| p - q), x.sub(&y).unwrap().as_vec());
+                    assert_eq!(&expect(&|p, _| 3 * p), x.scale(3.0).as_vec());
+                    assert_eq!(
+                        a.iter().zip(&b).map(|(p, q)| p * q).sum::<i64>() as $t,
+                        x.dot(&y).unwrap()
+                    );
+                    assert_eq!(a.iter().sum::<i64>() as $t, x.sum());
+
+                    let matrix = Matrix::new(len, 1, x.as_vec().clone()).unwrap();
+                    let other = Matrix::new(len, 1, y.as_vec().clone()).unwrap();
+                    assert_eq!(&expect(&|p, q| p + q), matrix.add(&other).unwrap().as_vec());
+                    assert_eq!(&expect(&|p, q| p - q), matrix.sub(&other).unwrap().as_vec());
+                    assert_eq!(&expect(&|p, _| -2 * p), matrix.scale(-2.0).as_vec());
+                    assert_eq!(a.iter().sum::<i64>() as $t, matrix.sum());
+                }
+            }
+        };
+    }
+
+    elementwise_test!(f64_elementwise_test, f64);
+    elementwise_test!(f32_elementwise_test, f32);
+
+    macro_rules! product_test {
+        ($name:ident, $t:ty) => {
+            #[test]
+            fn $name() {
+                for (m, k, n) in [(1, 1, 1), (5, 3, 9), (37, 70, 41), (66, 300, 17)] {
+                    let (a, b) = (integers(m * k, 2), integers(k * n, 5));
+                    let mut expected = vec![0; m * n];
+                    for row in 0..m {
+                        for col in 0..n {
+                            for index in 0..k {
+                                expected[row * n + col] += a[row * k + index] * b[index * n + col];
+                            }
+                        }
+                    }
+
+                    let lhs = Matrix::new(k, m, a.iter().map(|&v| v as $t).collect()).unwrap();
+                    let rhs = Matrix::new(n, k, b.iter().map(|&v| v as $t).collect()).unwrap();
+                    let expected: Vec<$t> = expected.iter().map(|&v| v as $t).collect();
+                    assert_eq!(&expected, lhs.product_matrix(&rhs).unwrap().as_vec());
+                }
+            }
+        };
+    }
+
+    product_test!(f64_product_test, f64);
+    product_test!(f32_product_test, f32);
+
+    #[test]
+    fn other_types_test() {
+        // Everything but f32 and f64 takes the scalar path
+        let x = Vector::new(vec![1u8, 2, 3]);
+        let y = Vector::new(vec![4u8, 5, 6]);
+
+        assert_eq!(&vec![5, 7, 9], x.add(&y).unwrap().as_vec());
+        assert_eq!(32, x.dot(&y).unwrap());
+        assert_eq!(6, x.sum());
+    }
+}
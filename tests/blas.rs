@@ -0,0 +1,228 @@
+mod common;
+
+#[cfg(all(test, feature = "blas"))]
+mod blas_tests {
+    extern crate linearalgebra;
+
+    use super::common::values;
+    use linearalgebra::matrix::*;
+    use linearalgebra::vector::*;
+    use linearalgebra::{Complex, Error};
+
+    // Only f32 and f64 go to BLAS, so the same numbers as complex numbers with
+    // no imaginary part give what the pure Rust path computes
+
+    // Diagonally dominant, so well away from singular
+    fn invertible(n: usize, seed: usize) -> Vec<f64> {
+        let mut values = values(n * n, seed);
+        for k in 0..n {
+            values[k * n + k] += n as f64;
+        }
+        values
+    }
+
+    fn real(values: &[f64]) -> Vec<Complex<f64>> {
+        values.iter().map(|&v| Complex::from_real(v)).collect()
+    }
+
+    fn assert_close(expected: &[Complex<f64>], found: &[f64]) {
+        assert_eq!(expected.len(), found.len());
+        for (expected, &found) in expected.iter().zip(found) {
+            assert!(expected.im.abs() < 1e-9);
+            assert!(
+                (expected.re - found).abs() < 1e-9 * (1.0 + found.abs()),
+                "{} != {}",
+                expected.re,
+                found
+            );
+        }
+    }
+
+    #[test]
+    fn product_matrix_test() {
+        for (m, k, n) in [(1, 1, 1), (5, 3, 9), (70, 90, 40)] {
+            let (a, b) = (values(m * k, 1), values(k * n, 4));
+            let lhs = Matrix::new(k, m, a.clone()).unwrap();
+            let rhs = Matrix::new(n, k, b.clone()).unwrap();
+            let expected = Matrix::new(k, m, real(&a))
+                .unwrap()
+                .product_matrix(&Matrix::new(n, k, real(&b)).unwrap())
+                .unwrap();
+
+            assert_close(
+                expected.as_vec(),
+                lhs.product_matrix(&rhs).unwrap().as_vec(),
+            );
+
+            // Transposed and column-major operands reach BLAS with the transpose flag
+            let lhs_t = lhs.transpose();
+            let product = lhs_t.transpose_view().product_matrix(&rhs).unwrap();
+            assert_close(expected.as_vec(), product.as_vec());
+            let product = lhs
+                .to_layout(Layout::ColumnMajor)
+                .product_matrix(&rhs.to_layout(Layout::ColumnMajor))
+                .unwrap();
+            assert_close(expected.as_vec(), product.as_vec());
+        }
+    }
+
+    #[test]
+    fn gemm_test() {
+        let (a, b, c) = (values(12, 2), values(20, 3), values(15, 5));
+        let mut found = Matrix::new(5, 3, c.clone()).unwrap();
+        let mut expected = Matrix::new(5, 3, real(&c)).unwrap();
+
+        Matrix::gemm(
+            2.0,
+            &Matrix::new(3, 4, a.clone()).unwrap(),
+            Op::Transpose,
+            &Matrix::new(4, 5, b.clone()).unwrap(),
+            Op::Transpose,
+            -0.5,
+            &mut found,
+        )
+        .unwrap();
+        Matrix::gemm(
+            Complex::from_real(2.0),
+            &Matrix::new(3, 4, real(&a)).unwrap(),
+            Op::Transpose,
+            &Matrix::new(4, 5, real(&b)).unwrap(),
+            Op::Transpose,
+            Complex::from_real(-0.5),
+            &mut expected,
+        )
+        .unwrap();
+
+        assert_close(expected.as_vec(), found.as_vec());
+    }
+
+    #[test]
+    fn product_vector_test() {
+        let (a, x) = (values(6 * 4, 1), values(4, 2));
+        let matrix = Matrix::new(4, 6, a.clone()).unwrap();
+        let expected = Matrix::new(4, 6, real(&a))
+            .unwrap()
+            .product_vector(&Vector::new(real(&x)))
+            .unwrap();
+
+        let vector = Vector::new(x.clone());
+        assert_close(
+            expected.as_vec(),
+            matrix.product_vector(&vector).unwrap().as_vec(),
+        );
+        let column_major = matrix.to_layout(Layout::ColumnMajor);
+        assert_close(
+            expected.as_vec(),
+            column_major.product_vector(&vector).unwrap().as_vec(),
+        );
+
+        let mut y = Vector::new(vec![1.0; 4]);
+        let mut expected = Vector::new(real(&[1.0; 4]));
+        Matrix::gemv(
+            3.0,
+            &matrix,
+            Op::Transpose,
+            &Vector::new(values(6, 3)),
+            2.0,
+            &mut y,
+        )
+        .unwrap();
+        Matrix::gemv(
+            Complex::from_real(3.0),
+            &Matrix::new(4, 6, real(&a)).unwrap(),
+            Op::Transpose,
+            &Vector::new(real(&values(6, 3))),
+            Complex::from_real(2.0),
+            &mut expected,
+        )
+        .unwrap();
+        assert_close(expected.as_vec(), y.as_vec());
+    }
+
+    #[test]
+    fn lu_test() {
+        let a = invertible(50, 6);
+        let matrix = Matrix::new(50, 50, a.clone()).unwrap();
+        let complex = Matrix::new(50, 50, real(&a)).unwrap();
+
+        let lu = matrix.lu().unwrap();
+        let expected = complex.lu().unwrap();
+        assert_eq!(expected.permutation(), lu.permutation());
+        assert_close(expected.l().as_vec(), lu.l().as_vec());
+        assert_close(expected.u().as_vec(), lu.u().as_vec());
+        assert_close(&[expected.determinant()], &[lu.determinant()]);
+        assert_close(
+            expected.inverse().unwrap().as_vec(),
+            lu.inverse().unwrap().as_vec(),
+        );
+    }
+
+    #[test]
+    fn solve_test() {
+        let (a, b) = (invertible(30, 2), values(30 * 3, 9));
+        let matrix = Matrix::new(30, 30, a.clone()).unwrap();
+        let complex = Matrix::new(30, 30, real(&a)).unwrap();
+
+        let expected = complex
+            .solve_matrix(&Matrix::new(3, 30, real(&b)).unwrap())
+            .unwrap();
+        let x = matrix
+            .solve_matrix(&Matrix::new(3, 30, b.clone()).unwrap())
+            .unwrap();
+        assert_close(expected.as_vec(), x.as_vec());
+
+        let expected = complex.solve(&Vector::new(real(&b[..30]))).unwrap();
+        let x = matrix.solve(&Vector::new(b[..30].to_vec())).unwrap();
+        assert_close(expected.as_vec(), x.as_vec());
+    }
+
+    #[test]
+    fn solve_singular_test() {
+        // The second row is twice the first, both backends refuse alike
+        let matrix = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+        let b = Vector::new(vec![1.0, 1.0]);
+
+        assert_eq!(Error::Singular, matrix.solve(&b).unwrap_err());
+        assert!(matrix.lu().unwrap().is_singular());
+        assert_eq!(
+            Error::Singular,
+            Matrix::new(2, 2, real(&[1.0, 2.0, 2.0, 4.0]))
+                .unwrap()
+                .solve(&Vector::new(real(&[1.0, 1.0])))
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn cholesky_test() {
+        // B^T B + n I is symmetric positive definite
+        let n = 40;
+        let b = Matrix::new(n, n, values(n * n, 5)).unwrap();
+        let mut a = b.transpose().product_matrix(&b).unwrap();
+        for k in 0..n {
+            a[(k, k)] += n as f64;
+        }
+        let complex = Matrix::new(n, n, real(a.as_vec())).unwrap();
+
+        let expected = complex.cholesky().unwrap();
+        let cholesky = a.cholesky().unwrap();
+        assert_close(expected.l().as_vec(), cholesky.l().as_vec());
+
+        let indefinite = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 1.0]).unwrap();
+        assert_eq!(
+            Error::NotPositiveDefinite,
+            indefinite.cholesky().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn other_types_test() {
+        // Integers have no BLAS routines and keep working as before
+        let a = Matrix::new(2, 2, vec![1i64, 2, 3, 4]).unwrap();
+        assert_eq!(&vec![7, 10, 15, 22], a.product_matrix(&a).unwrap().as_vec());
+        assert_eq!(
+            &vec![5, 11],
+            a.product_vector(&Vector::new(vec![1, 2])).unwrap().as_vec()
+        );
+    }
+}
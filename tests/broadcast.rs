@@ -0,0 +1,172 @@
+#[cfg(test)]
+mod broadcast_tests {
+    extern crate linearalgebra;
+
+    use linearalgebra::matrix::*;
+    use linearalgebra::parallel::ExecutionContext;
+    use linearalgebra::vector::*;
+    use linearalgebra::Error;
+
+    fn matrix() -> Matrix<i32> {
+        Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap()
+    }
+
+    #[test]
+    fn add_row_broadcast_test() {
+        let row = Vector::new(vec![10, 20, 30]);
+        assert_eq!(
+            &vec![11, 22, 33, 14, 25, 36],
+            matrix().add_row_broadcast(&row).unwrap().as_vec()
+        );
+        assert_eq!(
+            matrix().add_row_broadcast(&row).unwrap().as_vec(),
+            (matrix() + row.expand(2, Axis::Row)).as_vec()
+        );
+
+        // Views and other layouts give the same result row by row
+        let column_major = matrix().to_layout(Layout::ColumnMajor);
+        assert_eq!(
+            &vec![11, 22, 33, 14, 25, 36],
+            column_major.add_row_broadcast(&row).unwrap().as_vec()
+        );
+        assert_eq!(
+            &vec![11, 24, 12, 25, 13, 26],
+            matrix()
+                .transpose_view()
+                .add_row_broadcast(&Vector::new(vec![10, 20]))
+                .unwrap()
+                .as_vec()
+        );
+    }
+
+    #[test]
+    fn add_col_broadcast_test() {
+        let col = Vector::new(vec![10, 20]);
+        assert_eq!(
+            &vec![11, 12, 13, 24, 25, 26],
+            matrix().add_col_broadcast(&col).unwrap().as_vec()
+        );
+        assert_eq!(
+            matrix().add_col_broadcast(&col).unwrap().as_vec(),
+            (matrix() + col.expand(3, Axis::Column)).as_vec()
+        );
+    }
+
+    #[test]
+    fn add_broadcast_large_test() {
+        // Enough rows to be split over the thread pool
+        let (height, width) = (1000, 70);
+        let a = Matrix::new_of_supplier(width, height, || 1.5);
+        let row = Vector::new((0..width).map(|i| i as f64).collect());
+        let col = Vector::new((0..height).map(|i| i as f64).collect());
+
+        ExecutionContext::default().with_threshold(0).install(|| {
+            let sum = a.add_row_broadcast(&row).unwrap();
+            assert_eq!(1.5 + 69.0, sum[(999, 69)]);
+            let sum = a.add_col_broadcast(&col).unwrap();
+            assert_eq!(1.5 + 999.0, sum[(999, 3)]);
+        });
+    }
+
+    #[test]
+    fn add_broadcast_mismatch_test() {
+        assert_eq!(
+            Error::NotBroadcastable {
+                lhs: (2, 3),
+                rhs: (1, 2)
+            },
+            matrix()
+                .add_row_broadcast(&Vector::new(vec![1, 2]))
+                .unwrap_err()
+        );
+        assert_eq!(
+            Error::NotBroadcastable {
+                lhs: (2, 3),
+                rhs: (3, 1)
+            },
+            matrix()
+                .add_col_broadcast(&Vector::new(vec![1, 2, 3]))
+                .unwrap_err()
+        );
+        assert_eq!(
+            "Shapes (2, 3) and (3, 1) can't be broadcast together",
+            format!(
+                "{}",
+                matrix()
+                    .add_col_broadcast(&Vector::new(vec![1, 2, 3]))
+                    .unwrap_err()
+            )
+        );
+    }
+
+    #[test]
+    fn operator_broadcast_test() {
+        let row = Matrix::new(3, 1, vec![10, 20, 30]).unwrap();
+        let col = Matrix::new(1, 2, vec![100, 200]).unwrap();
+        let one = Matrix::new(1, 1, vec![5]).unwrap();
+
+        assert_eq!(&vec![11, 22, 33, 14, 25, 36], (&matrix() + &row).as_vec());
+        assert_eq!(&vec![9, 18, 27, 6, 15, 24], (&row - &matrix()).as_vec());
+        assert_eq!(
+            &vec![101, 102, 103, 204, 205, 206],
+            (matrix() + &col).as_vec()
+        );
+        assert_eq!(
+            &vec![99, 98, 97, 196, 195, 194],
+            (col.clone() - matrix()).as_vec()
+        );
+        assert_eq!(&vec![6, 7, 8, 9, 10, 11], (&one + matrix()).as_vec());
+
+        // A row and a column stretch each other to a full matrix
+        let outer = &row + &col;
+        assert_eq!((2, 3), outer.shape());
+        assert_eq!(&vec![110, 120, 130, 210, 220, 230], outer.as_vec());
+
+        let mut sum = matrix();
+        sum += &row;
+        sum -= col;
+        assert_eq!(&vec![-89, -78, -67, -186, -175, -164], sum.as_vec());
+
+        let column_major = matrix().to_layout(Layout::ColumnMajor);
+        assert_eq!(
+            (&matrix() + &row).as_vec(),
+            (column_major + &row).to_layout(Layout::RowMajor).as_vec()
+        );
+    }
+
+    #[test]
+    fn view_operator_broadcast_test() {
+        let matrix = matrix();
+        let row = matrix.row_view(0).unwrap();
+        let col = matrix.col_view(2).unwrap();
+
+        assert_eq!(&vec![0, 0, 0, 3, 3, 3], (&matrix - row).as_vec());
+        assert_eq!(&vec![4, 5, 6, 10, 11, 12], (col + &matrix).as_vec());
+        assert_eq!(&vec![4, 5, 6, 7, 8, 9], (row + col).as_vec());
+
+        let mut copy = matrix.clone();
+        copy -= col;
+        assert_eq!(&vec![-2, -1, 0, -2, -1, 0], copy.as_vec());
+    }
+
+    #[test]
+    fn scalar_broadcast_test() {
+        assert_eq!(&vec![3, 4, 5, 6, 7, 8], (matrix() + 2).as_vec());
+        assert_eq!(&vec![0, 1, 2, 3, 4, 5], (&matrix() - 1).as_vec());
+        assert_eq!(&vec![9, 8, 7, 6, 5, 4], (10 - matrix()).as_vec());
+        assert_eq!(&vec![2, 3, 4, 5, 6, 7], (1 + &matrix()).as_vec());
+
+        let mut a = Matrix::new(2, 1, vec![1.5, 2.5]).unwrap();
+        a += 0.5;
+        a -= 1.0;
+        assert_eq!(&vec![1.0, 2.0], a.as_vec());
+    }
+
+    #[test]
+    #[should_panic(expected = "Shapes (1, 3) and (2, 3) can't be broadcast together")]
+    fn assign_operator_broadcast_panic_test() {
+        // The left hand side can't grow to the broadcast shape in place
+        let mut row = Matrix::new(3, 1, vec![1, 2, 3]).unwrap();
+        row += matrix();
+    }
+}
@@ -0,0 +1,41 @@
+// Helpers shared by the integration tests, which each pull them in with
+// `mod common;`. Not every test uses every helper.
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+
+pub const TOLERANCE: f64 = 1e-10;
+
+/**
+ * Asserts the two slices agree entry by entry up to TOLERANCE
+ */
+pub fn assert_close(expected: &[f64], actual: &[f64]) {
+    assert_close_by(expected, actual, |e, a| (e - a).abs());
+}
+
+/**
+ * assert_close for any entries, with distance measuring how far apart two are
+ */
+pub fn assert_close_by<T: Debug>(expected: &[T], actual: &[T], distance: impl Fn(&T, &T) -> f64) {
+    assert_eq!(expected.len(), actual.len());
+    for (e, a) in expected.iter().zip(actual) {
+        assert!(distance(e, a) < TOLERANCE, "{:?} != {:?}", expected, actual);
+    }
+}
+
+/**
+ * len small integers in -6..=6 in a fixed pattern, shifted by seed
+ */
+pub fn integers(len: usize, seed: usize) -> Vec<i64> {
+    (0..len).map(|i| ((i * 7 + seed) % 13) as i64 - 6).collect()
+}
+
+/**
+ * integers(len, seed) in quarters, so the values are exact in f32 and f64
+ */
+pub fn values(len: usize, seed: usize) -> Vec<f64> {
+    integers(len, seed)
+        .iter()
+        .map(|&x| x as f64 / 4.0)
+        .collect()
+}
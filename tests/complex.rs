@@ -0,0 +1,178 @@
+mod common;
+
+#[cfg(test)]
+mod complex_tests {
+    extern crate linearalgebra;
+
+    use super::common::{assert_close_by, TOLERANCE};
+    use linearalgebra::matrix::*;
+    use linearalgebra::vector::*;
+    use linearalgebra::Complex;
+
+    type C = Complex<f64>;
+
+    const fn c(re: f64, im: f64) -> C {
+        Complex::new(re, im)
+    }
+
+    fn assert_close(expected: &[C], actual: &[C]) {
+        assert_close_by(expected, actual, |e, a| (*e - *a).norm());
+    }
+
+    fn hermitian() -> Matrix<C> {
+        Matrix::new(
+            3,
+            3,
+            vec![
+                c(4.0, 0.0),
+                c(1.0, -2.0),
+                c(0.0, 1.0),
+                c(1.0, 2.0),
+                c(6.0, 0.0),
+                c(2.0, 0.0),
+                c(0.0, -1.0),
+                c(2.0, 0.0),
+                c(5.0, 0.0),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn general() -> Matrix<C> {
+        Matrix::new(
+            3,
+            3,
+            vec![
+                c(1.0, 1.0),
+                c(2.0, 0.0),
+                c(0.0, -1.0),
+                c(0.0, 2.0),
+                c(-1.0, 0.5),
+                c(3.0, 0.0),
+                c(1.0, 0.0),
+                c(0.0, 0.0),
+                c(2.0, -2.0),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn complex_arithmetic_test() {
+        let a = c(1.0, 2.0);
+        let b = c(3.0, -1.0);
+
+        assert_eq!(c(4.0, 1.0), a + b);
+        assert_eq!(c(-2.0, 3.0), a - b);
+        assert_eq!(c(5.0, 5.0), a * b);
+        assert_close(&[a], &[(a * b) / b]);
+        assert_eq!("1+2i", a.to_string());
+        assert_eq!("3-1i", b.to_string());
+    }
+
+    #[test]
+    fn complex_product_test() {
+        // Multiplying by i rotates every entry
+        let matrix = Matrix::new(2, 1, vec![c(1.0, 0.0), c(0.0, 1.0)]).unwrap();
+        let i = Matrix::new(1, 1, vec![c(0.0, 1.0)]).unwrap();
+        let product = i.product_matrix(&matrix).unwrap();
+
+        assert_eq!(&vec![c(0.0, 1.0), c(-1.0, 0.0)], product.as_vec());
+        assert_eq!(
+            &vec![c(0.0, 1.0), c(-1.0, 0.0)],
+            (matrix * c(0.0, 1.0)).as_vec()
+        );
+    }
+
+    #[test]
+    fn hermitian_dot_test() {
+        let a = Vector::new(vec![c(1.0, 1.0), c(0.0, 2.0)]);
+        let b = Vector::new(vec![c(2.0, 0.0), c(1.0, 1.0)]);
+
+        // conj(1+i)*2 + conj(2i)*(1+i) = (2-2i) + (2-2i)
+        assert_eq!(c(4.0, -4.0), a.dot(&b).unwrap());
+        // The norm squared is real
+        assert_eq!(c(6.0, 0.0), a.dot(&a).unwrap());
+    }
+
+    #[test]
+    fn adjoint_test() {
+        let matrix = Matrix::new(2, 1, vec![c(1.0, 2.0), c(3.0, -4.0)]).unwrap();
+        let adjoint = matrix.adjoint();
+
+        assert_eq!((2, 1), adjoint.shape());
+        assert_eq!(&vec![c(1.0, -2.0), c(3.0, 4.0)], adjoint.as_vec());
+
+        let real = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+        assert_eq!(real.transpose().as_vec(), real.adjoint().as_vec());
+    }
+
+    #[test]
+    fn complex_lu_test() {
+        let matrix = general();
+        let b = Vector::new(vec![c(1.0, 0.0), c(0.0, 1.0), c(2.0, -1.0)]);
+        let x = matrix.solve(&b).unwrap();
+
+        assert_close(b.as_vec(), matrix.product_vector(&x).unwrap().as_vec());
+        assert_close(
+            Matrix::<C>::identity(3).as_vec(),
+            matrix
+                .product_matrix(&matrix.inverse().unwrap())
+                .unwrap()
+                .as_vec(),
+        );
+    }
+
+    #[test]
+    fn complex_qr_test() {
+        let matrix = general();
+        let qr = matrix.qr();
+        let q = qr.q();
+
+        assert_close(
+            Matrix::<C>::identity(3).as_vec(),
+            q.adjoint().product_matrix(&q).unwrap().as_vec(),
+        );
+        assert_close(matrix.as_vec(), q.product_matrix(&qr.r()).unwrap().as_vec());
+    }
+
+    #[test]
+    fn complex_least_squares_test() {
+        let matrix = Matrix::new(
+            2,
+            3,
+            vec![
+                c(1.0, 0.0),
+                c(0.0, 1.0),
+                c(1.0, 1.0),
+                c(2.0, 0.0),
+                c(0.0, -1.0),
+                c(1.0, 0.0),
+            ],
+        )
+        .unwrap();
+        let expected = Vector::new(vec![c(1.0, -1.0), c(2.0, 0.5)]);
+        let b = matrix.product_vector(&expected).unwrap();
+        let (x, residual) = matrix.least_squares(&b).unwrap();
+
+        assert_close(expected.as_vec(), x.as_vec());
+        assert!(residual < TOLERANCE);
+    }
+
+    #[test]
+    fn complex_cholesky_test() {
+        let matrix = hermitian();
+        let cholesky = matrix.cholesky().unwrap();
+        let l = cholesky.l();
+
+        assert_close(
+            matrix.as_vec(),
+            l.product_matrix(&l.adjoint()).unwrap().as_vec(),
+        );
+        assert_close(&[matrix.determinant().unwrap()], &[cholesky.determinant()]);
+
+        let b = Vector::new(vec![c(1.0, 1.0), c(0.0, 0.0), c(-1.0, 2.0)]);
+        let x = cholesky.solve(&b).unwrap();
+        assert_close(b.as_vec(), matrix.product_vector(&x).unwrap().as_vec());
+    }
+}
@@ -0,0 +1,403 @@
+mod common;
+
+#[cfg(test)]
+mod decomposition_tests {
+    extern crate linearalgebra;
+
+    use super::common::{assert_close, TOLERANCE};
+    use linearalgebra::matrix::*;
+    use linearalgebra::vector::*;
+    use linearalgebra::Error;
+
+    fn example() -> Matrix<f64> {
+        Matrix::new(3, 3, vec![2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0]).unwrap()
+    }
+
+    #[test]
+    fn identity_test() {
+        let identity = Matrix::<i32>::identity(3);
+
+        assert_eq!(&vec![1, 0, 0, 0, 1, 0, 0, 0, 1], identity.as_vec());
+    }
+
+    #[test]
+    fn lu_reconstruct_test() {
+        let matrix = example();
+        let lu = matrix.lu().unwrap();
+
+        let pa = lu.p().product_matrix(&matrix).unwrap();
+        let l_u = lu.l().product_matrix(&lu.u()).unwrap();
+
+        assert_close(pa.as_vec(), l_u.as_vec());
+        assert_eq!(lu.l()[(0, 0)], 1.0);
+        assert_eq!(lu.u()[(1, 0)], 0.0);
+    }
+
+    #[test]
+    fn lu_partial_pivoting_test() {
+        let matrix = example();
+        let lu = matrix.lu().unwrap();
+
+        // The largest entry of the first column is in the second row
+        assert_eq!(1, lu.permutation()[0]);
+    }
+
+    #[test]
+    fn lu_not_square_test() {
+        let matrix = Matrix::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+
+        assert_eq!(Error::NotSquare { shape: (2, 3) }, matrix.lu().unwrap_err());
+    }
+
+    #[test]
+    fn determinant_test() {
+        assert!((example().determinant().unwrap() - -16.0).abs() < TOLERANCE);
+
+        let swapped = Matrix::new(2, 2, vec![0.0, 1.0, 1.0, 0.0]).unwrap();
+        assert_eq!(-1.0, swapped.determinant().unwrap());
+    }
+
+    #[test]
+    fn determinant_singular_test() {
+        let matrix: Matrix<f64> =
+            Matrix::new(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]).unwrap();
+
+        assert!(matrix.determinant().unwrap().abs() < TOLERANCE);
+        assert!(matrix.lu().unwrap().is_singular());
+    }
+
+    #[test]
+    fn solve_test() {
+        let matrix = example();
+        let x = matrix.solve(&Vector::new(vec![5.0, -2.0, 9.0])).unwrap();
+
+        assert_close(&[1.0, 1.0, 2.0], x.as_vec());
+    }
+
+    #[test]
+    fn solve_mismatched_test() {
+        let matrix = example();
+
+        assert_eq!(
+            Error::DimensionMismatch {
+                expected: 3,
+                found: 2
+            },
+            matrix.solve(&Vector::new(vec![1.0, 2.0])).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn solve_singular_test() {
+        let matrix = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+
+        assert_eq!(
+            Error::Singular,
+            matrix.solve(&Vector::new(vec![1.0, 2.0])).unwrap_err()
+        );
+        assert_eq!(Error::Singular, matrix.inverse().unwrap_err());
+    }
+
+    #[test]
+    fn solve_matrix_test() {
+        let matrix = example();
+        let b = Matrix::new(2, 3, vec![5.0, 4.0, -2.0, 4.0, 9.0, 7.0]).unwrap();
+        let x = matrix.solve_matrix(&b).unwrap();
+
+        assert_eq!((3, 2), x.shape());
+        assert_close(b.as_vec(), matrix.product_matrix(&x).unwrap().as_vec());
+    }
+
+    #[test]
+    fn reuse_factorization_test() {
+        let matrix = example();
+        let lu = matrix.lu().unwrap();
+
+        for b in [
+            vec![5.0, -2.0, 9.0],
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 3.0, -1.0],
+        ] {
+            let b = Vector::new(b);
+            let x = lu.solve(&b).unwrap();
+            assert_close(b.as_vec(), matrix.product_vector(&x).unwrap().as_vec());
+        }
+    }
+
+    #[test]
+    fn inverse_test() {
+        let matrix = example();
+        let inverse = matrix.inverse().unwrap();
+
+        assert_close(
+            Matrix::<f64>::identity(3).as_vec(),
+            matrix.product_matrix(&inverse).unwrap().as_vec(),
+        );
+    }
+
+    #[test]
+    fn f32_solve_test() {
+        let matrix = Matrix::new(2, 2, vec![4f32, 3f32, 6f32, 3f32]).unwrap();
+        let x = matrix.solve(&Vector::new(vec![10f32, 12f32])).unwrap();
+
+        assert!((x.as_vec()[0] - 1.0).abs() < 1e-5);
+        assert!((x.as_vec()[1] - 2.0).abs() < 1e-5);
+    }
+
+    fn tall() -> Matrix<f64> {
+        Matrix::new(
+            3,
+            4,
+            vec![1.0, 2.0, 0.0, 3.0, -1.0, 2.0, 0.0, 4.0, 1.0, 2.0, 2.0, -2.0],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn qr_reconstruct_test() {
+        let matrix = tall();
+        let qr = matrix.qr();
+
+        assert_eq!((4, 4), qr.q().shape());
+        assert_eq!((4, 3), qr.r().shape());
+        assert_close(
+            matrix.as_vec(),
+            qr.q().product_matrix(&qr.r()).unwrap().as_vec(),
+        );
+        assert_close(
+            Matrix::<f64>::identity(4).as_vec(),
+            qr.q().transpose().product_matrix(&qr.q()).unwrap().as_vec(),
+        );
+        assert_eq!(0.0, qr.r()[(2, 1)]);
+    }
+
+    #[test]
+    fn qr_thin_test() {
+        let matrix = tall();
+        let qr = matrix.qr();
+
+        assert_eq!((4, 3), qr.thin_q().shape());
+        assert_eq!((3, 3), qr.thin_r().shape());
+        assert_close(
+            matrix.as_vec(),
+            qr.thin_q().product_matrix(&qr.thin_r()).unwrap().as_vec(),
+        );
+    }
+
+    #[test]
+    fn qr_pivoted_reconstruct_test() {
+        let matrix = tall();
+        let qr = matrix.qr_pivoted();
+
+        assert_close(
+            matrix.product_matrix(&qr.p()).unwrap().as_vec(),
+            qr.q().product_matrix(&qr.r()).unwrap().as_vec(),
+        );
+        let r = qr.r();
+        assert!(r[(0, 0)].abs() >= r[(1, 1)].abs());
+        assert!(r[(1, 1)].abs() >= r[(2, 2)].abs());
+        assert_eq!(3, qr.rank());
+    }
+
+    #[test]
+    fn least_squares_exact_test() {
+        let matrix = tall();
+        let b = matrix
+            .product_vector(&Vector::new(vec![1.0, -2.0, 0.5]))
+            .unwrap();
+        let (x, residual) = matrix.least_squares(&b).unwrap();
+
+        assert_close(&[1.0, -2.0, 0.5], x.as_vec());
+        assert!(residual < TOLERANCE);
+    }
+
+    #[test]
+    fn least_squares_line_fit_test() {
+        // Fit y = a + bx through (0, 1), (1, 2), (2, 2), (3, 4)
+        let matrix = Matrix::new(2, 4, vec![1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0]).unwrap();
+        let (x, residual) = matrix
+            .least_squares(&Vector::new(vec![1.0, 2.0, 2.0, 4.0]))
+            .unwrap();
+
+        assert_close(&[0.9, 0.9], x.as_vec());
+        assert!((residual - 0.7f64.sqrt()).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn least_squares_rank_deficient_test() {
+        // The third column is the sum of the first two
+        let matrix = Matrix::new(
+            3,
+            4,
+            vec![1.0, 0.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 1.0, 3.0],
+        )
+        .unwrap();
+        let b = Vector::new(vec![1.0, 2.0, 3.0, 4.0]);
+        let qr = matrix.qr_pivoted();
+        let (x, residual) = qr.least_squares(&b).unwrap();
+
+        assert_eq!(2, qr.rank());
+        let fitted = matrix.product_vector(&x).unwrap();
+        let expected = (b.as_vec().iter().zip(fitted.as_vec()))
+            .map(|(b, f)| (b - f) * (b - f))
+            .sum::<f64>()
+            .sqrt();
+        assert!((residual - expected).abs() < TOLERANCE);
+        assert!(residual < 1.0);
+    }
+
+    #[test]
+    fn least_squares_mismatched_test() {
+        assert_eq!(
+            Error::DimensionMismatch {
+                expected: 4,
+                found: 3
+            },
+            tall()
+                .least_squares(&Vector::new(vec![1.0, 2.0, 3.0]))
+                .unwrap_err()
+        );
+    }
+
+    fn spd() -> Matrix<f64> {
+        Matrix::new(
+            3,
+            3,
+            vec![4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0],
+        )
+        .unwrap()
+    }
+
+    fn indefinite() -> Matrix<f64> {
+        Matrix::new(
+            4,
+            4,
+            vec![
+                0.0, 1.0, 2.0, 3.0, 1.0, 0.0, 4.0, 1.0, 2.0, 4.0, -1.0, 0.0, 3.0, 1.0, 0.0, 2.0,
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn cholesky_test() {
+        let matrix = spd();
+        let cholesky = matrix.cholesky().unwrap();
+
+        assert_close(
+            &[2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0],
+            cholesky.l().as_vec(),
+        );
+        assert!((cholesky.determinant() - 36.0).abs() < TOLERANCE);
+        assert!((cholesky.log_determinant() - 36f64.ln()).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn cholesky_gram_matrix_test() {
+        let matrix = tall();
+        let gram = matrix.transpose().product_matrix(&matrix).unwrap();
+        let cholesky = gram.cholesky().unwrap();
+        let l = cholesky.l();
+
+        assert_close(
+            gram.as_vec(),
+            l.product_matrix(&l.transpose()).unwrap().as_vec(),
+        );
+    }
+
+    #[test]
+    fn cholesky_solve_test() {
+        let matrix = spd();
+        let cholesky = matrix.cholesky().unwrap();
+        let b = Vector::new(vec![1.0, 2.0, 3.0]);
+        let x = cholesky.solve(&b).unwrap();
+
+        assert_close(b.as_vec(), matrix.product_vector(&x).unwrap().as_vec());
+        assert_close(
+            matrix.inverse().unwrap().as_vec(),
+            cholesky.inverse().as_vec(),
+        );
+    }
+
+    #[test]
+    fn cholesky_not_positive_definite_test() {
+        assert_eq!(
+            Error::NotPositiveDefinite,
+            indefinite().cholesky().unwrap_err()
+        );
+
+        let semidefinite = Matrix::new(2, 2, vec![1.0, 1.0, 1.0, 1.0]).unwrap();
+        assert_eq!(
+            Error::NotPositiveDefinite,
+            semidefinite.cholesky().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn ldlt_reconstruct_test() {
+        let matrix = indefinite();
+        let ldlt = matrix.ldlt().unwrap();
+        let l = ldlt.l();
+
+        let pap = ldlt
+            .p()
+            .product_matrix(&matrix)
+            .unwrap()
+            .product_matrix(&ldlt.p().transpose())
+            .unwrap();
+        let ldl = l
+            .product_matrix(&ldlt.d())
+            .unwrap()
+            .product_matrix(&l.transpose())
+            .unwrap();
+
+        assert_close(pap.as_vec(), ldl.as_vec());
+    }
+
+    #[test]
+    fn ldlt_two_by_two_pivot_test() {
+        let matrix = Matrix::new(2, 2, vec![0.0, 1.0, 1.0, 0.0]).unwrap();
+        let ldlt = matrix.ldlt().unwrap();
+
+        assert_close(&[0.0, 1.0, 1.0, 0.0], ldlt.d().as_vec());
+        assert_eq!(-1.0, ldlt.determinant());
+        assert_close(
+            &[2.0, 1.0],
+            ldlt.solve(&Vector::new(vec![1.0, 2.0])).unwrap().as_vec(),
+        );
+    }
+
+    #[test]
+    fn ldlt_solve_test() {
+        let matrix = indefinite();
+        let ldlt = matrix.ldlt().unwrap();
+        let b = Vector::new(vec![1.0, -2.0, 3.0, 0.5]);
+        let x = ldlt.solve(&b).unwrap();
+
+        assert_close(b.as_vec(), matrix.product_vector(&x).unwrap().as_vec());
+    }
+
+    #[test]
+    fn ldlt_determinant_test() {
+        let matrix = indefinite();
+        let ldlt = matrix.ldlt().unwrap();
+        let determinant = matrix.determinant().unwrap();
+        let (sign, log_det) = ldlt.signed_log_determinant();
+
+        assert!((ldlt.determinant() - determinant).abs() < TOLERANCE);
+        assert_eq!(determinant.signum(), sign);
+        assert!((log_det - determinant.abs().ln()).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn ldlt_singular_test() {
+        let matrix = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+        let ldlt = matrix.ldlt().unwrap();
+
+        assert!(ldlt.is_singular());
+        assert_eq!(
+            Error::Singular,
+            ldlt.solve(&Vector::new(vec![1.0, 1.0])).unwrap_err()
+        );
+    }
+}
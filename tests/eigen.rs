@@ -0,0 +1,283 @@
+mod common;
+
+#[cfg(test)]
+mod eigen_tests {
+    extern crate linearalgebra;
+
+    use super::common::{assert_close, TOLERANCE};
+    use linearalgebra::matrix::*;
+    use linearalgebra::{Complex, Error};
+
+    fn symmetric() -> Matrix<f64> {
+        Matrix::new(
+            4,
+            4,
+            vec![
+                4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, 1.0, -2.0, 0.0, 3.0, -2.0, 2.0, 1.0, -2.0, -1.0,
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn symmetric_eigen_diagonal_test() {
+        let matrix = Matrix::new(3, 3, vec![3.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 2.0]).unwrap();
+        let eigen = matrix.symmetric_eigen().unwrap();
+
+        assert_close(&[-1.0, 2.0, 3.0], eigen.eigenvalues().as_vec());
+        assert_close(
+            &[0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+            &eigen
+                .eigenvectors()
+                .as_vec()
+                .iter()
+                .map(|x| x.abs())
+                .collect::<Vec<f64>>(),
+        );
+    }
+
+    #[test]
+    fn symmetric_eigen_2x2_test() {
+        let matrix = Matrix::new(2, 2, vec![2.0, 1.0, 1.0, 2.0]).unwrap();
+        let eigen = matrix.symmetric_eigen().unwrap();
+
+        assert_close(&[1.0, 3.0], eigen.eigenvalues().as_vec());
+    }
+
+    #[test]
+    fn symmetric_eigen_reconstruct_test() {
+        let matrix = symmetric();
+        let eigen = matrix.symmetric_eigen().unwrap();
+        let v = eigen.eigenvectors();
+        let values = eigen.eigenvalues().as_vec();
+
+        // Eigenvalues ascend
+        for pair in values.windows(2) {
+            assert!(pair[0] <= pair[1]);
+        }
+
+        assert_close(
+            Matrix::<f64>::identity(4).as_vec(),
+            v.transpose().product_matrix(v).unwrap().as_vec(),
+        );
+
+        let mut lambda = Matrix::new_of_element(4, 4, 0.0).unwrap();
+        for k in 0..4 {
+            lambda[(k, k)] = values[k];
+        }
+        let reconstructed = v
+            .product_matrix(&lambda)
+            .unwrap()
+            .product_matrix(&v.transpose())
+            .unwrap();
+        assert_close(matrix.as_vec(), reconstructed.as_vec());
+    }
+
+    #[test]
+    fn symmetric_eigenvalues_only_test() {
+        let matrix = symmetric();
+
+        assert_close(
+            matrix.symmetric_eigen().unwrap().eigenvalues().as_vec(),
+            matrix.symmetric_eigenvalues().unwrap().as_vec(),
+        );
+
+        // The eigenvalues add up to the trace
+        let values = matrix.symmetric_eigenvalues().unwrap();
+        assert!((values.as_vec().iter().sum::<f64>() - 8.0).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn symmetric_eigen_largest_test() {
+        let matrix = symmetric();
+        let all = matrix.symmetric_eigen().unwrap();
+        let largest = matrix.symmetric_eigen_largest(2).unwrap();
+        let values = all.eigenvalues().as_vec();
+
+        assert_eq!((4, 2), largest.eigenvectors().shape());
+        assert_close(&[values[3], values[2]], largest.eigenvalues().as_vec());
+
+        let v = largest.eigenvectors().get_col(0).unwrap();
+        let av = matrix.product_vector(&v).unwrap();
+        assert_close(v.scale(values[3]).as_vec(), av.as_vec());
+    }
+
+    #[test]
+    fn symmetric_eigen_smallest_test() {
+        let matrix = symmetric();
+        let all = matrix.symmetric_eigen().unwrap();
+        let smallest = matrix.symmetric_eigen_smallest(1).unwrap();
+
+        assert_eq!((4, 1), smallest.eigenvectors().shape());
+        assert_close(
+            &[all.eigenvalues().as_vec()[0]],
+            smallest.eigenvalues().as_vec(),
+        );
+        assert!(matrix.symmetric_eigen_smallest(5).is_err());
+    }
+
+    #[test]
+    fn symmetric_eigen_not_square_test() {
+        let matrix = Matrix::new(2, 1, vec![1.0, 2.0]).unwrap();
+
+        assert_eq!(
+            Error::NotSquare { shape: (1, 2) },
+            matrix.symmetric_eigen().unwrap_err()
+        );
+    }
+
+    fn general() -> Matrix<f64> {
+        Matrix::new(
+            4,
+            4,
+            vec![
+                1.0, 2.0, 0.0, -1.0, -3.0, 0.5, 2.0, 1.0, 0.0, 4.0, -2.0, 3.0, 1.0, 0.0, 1.0, 1.0,
+            ],
+        )
+        .unwrap()
+    }
+
+    fn assert_eigenpairs(matrix: &Matrix<f64>, eigen: &Eigen<f64>) {
+        let n = matrix.width();
+        let complex = Matrix::new(
+            n,
+            n,
+            matrix
+                .as_vec()
+                .iter()
+                .map(|&x| Complex::from_real(x))
+                .collect(),
+        )
+        .unwrap();
+
+        for k in 0..n {
+            let lambda = eigen.eigenvalues().as_vec()[k];
+            let v = eigen.eigenvectors().get_col(k).unwrap();
+            let av = complex.product_vector(&v).unwrap();
+            for (a, b) in av.as_vec().iter().zip(v.as_vec()) {
+                assert!((*a - lambda * *b).norm() < TOLERANCE);
+            }
+        }
+    }
+
+    #[test]
+    fn hessenberg_test() {
+        let matrix = general();
+        let hessenberg = matrix.hessenberg().unwrap();
+        let (h, q) = (hessenberg.h(), hessenberg.q());
+
+        assert_eq!(0.0, h[(2, 0)]);
+        assert_eq!(0.0, h[(3, 0)]);
+        assert_eq!(0.0, h[(3, 1)]);
+        assert_close(
+            matrix.as_vec(),
+            q.product_matrix(h)
+                .unwrap()
+                .product_matrix(&q.transpose())
+                .unwrap()
+                .as_vec(),
+        );
+    }
+
+    #[test]
+    fn schur_test() {
+        let matrix = general();
+        let schur = matrix.schur().unwrap();
+        let (t, z) = (schur.t(), schur.z());
+
+        assert_close(
+            Matrix::<f64>::identity(4).as_vec(),
+            z.transpose().product_matrix(z).unwrap().as_vec(),
+        );
+        assert_close(
+            matrix.as_vec(),
+            z.product_matrix(t)
+                .unwrap()
+                .product_matrix(&z.transpose())
+                .unwrap()
+                .as_vec(),
+        );
+        for row in 2..4 {
+            for col in 0..row - 1 {
+                assert_eq!(0.0, t[(row, col)]);
+            }
+        }
+    }
+
+    #[test]
+    fn eigenvalues_rotation_test() {
+        // Rotation by 90 degrees has eigenvalues i and -i
+        let matrix = Matrix::new(2, 2, vec![0.0, -1.0, 1.0, 0.0]).unwrap();
+        let eigenvalues = matrix.eigenvalues().unwrap();
+
+        assert_eq!(
+            &vec![Complex::new(0.0, 1.0), Complex::new(0.0, -1.0)],
+            eigenvalues.as_vec()
+        );
+    }
+
+    #[test]
+    fn eigenvalues_stability_test() {
+        // Discrete time system, stable when every eigenvalue lies in the unit circle
+        let transition = Matrix::new(2, 2, vec![0.5, 0.4, -0.4, 0.5]).unwrap();
+        let eigenvalues = transition.eigenvalues().unwrap();
+
+        for lambda in eigenvalues.as_vec() {
+            assert!((lambda.norm() - 0.41f64.sqrt()).abs() < TOLERANCE);
+            assert!(!lambda.is_real());
+        }
+    }
+
+    #[test]
+    fn eigenvalues_triangular_test() {
+        let matrix = Matrix::new(3, 3, vec![2.0, 1.0, 5.0, 0.0, -3.0, 4.0, 0.0, 0.0, 7.0]).unwrap();
+        let mut eigenvalues: Vec<f64> = matrix
+            .eigenvalues()
+            .unwrap()
+            .as_vec()
+            .iter()
+            .map(|lambda| lambda.re)
+            .collect();
+        eigenvalues.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert_close(&[-3.0, 2.0, 7.0], &eigenvalues);
+    }
+
+    #[test]
+    fn eigen_vectors_test() {
+        let matrix = general();
+        let eigen = matrix.eigen().unwrap();
+
+        assert_eigenpairs(&matrix, &eigen);
+        for k in 0..4 {
+            let norm: f64 = eigen
+                .eigenvectors()
+                .get_col(k)
+                .unwrap()
+                .as_vec()
+                .iter()
+                .map(|x| x.norm_sqr())
+                .sum();
+            assert!((norm - 1.0).abs() < TOLERANCE);
+        }
+    }
+
+    #[test]
+    fn eigen_complex_vectors_test() {
+        let matrix = Matrix::new(3, 3, vec![0.0, -2.0, 1.0, 2.0, 0.0, 0.0, 1.0, 0.0, 3.0]).unwrap();
+        let eigen = matrix.eigen().unwrap();
+
+        assert!(eigen.eigenvalues().as_vec().iter().any(|x| !x.is_real()));
+        assert_eigenpairs(&matrix, &eigen);
+    }
+
+    #[test]
+    fn eigen_not_square_test() {
+        let matrix = Matrix::new(2, 1, vec![1.0, 2.0]).unwrap();
+
+        assert_eq!(
+            Error::NotSquare { shape: (1, 2) },
+            matrix.eigen().unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,167 @@
+mod common;
+
+#[cfg(test)]
+mod elementwise_tests {
+    extern crate linearalgebra;
+
+    use super::common::values;
+    use linearalgebra::matrix::*;
+    use linearalgebra::parallel::ExecutionContext;
+    use linearalgebra::vector::*;
+    use linearalgebra::Error;
+
+    // Kept away from zero so the quotients are finite
+    fn positive(len: usize, seed: usize) -> Vec<f64> {
+        values(len, seed).iter().map(|x| x + 2.0).collect()
+    }
+
+    #[test]
+    fn hadamard_test() {
+        let a = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let b = Matrix::new(3, 2, vec![2, 0, -1, 3, 1, 2]).unwrap();
+        assert_eq!(&vec![2, 0, -3, 12, 5, 12], a.hadamard(&b).unwrap().as_vec());
+
+        // Transposed views and other layouts line up by position
+        let column_major = b.to_layout(Layout::ColumnMajor);
+        assert_eq!(
+            &vec![2, 0, -3, 12, 5, 12],
+            a.hadamard(&column_major).unwrap().as_vec()
+        );
+        assert_eq!(
+            &vec![2, 12, 0, 5, -3, 12],
+            a.transpose_view()
+                .hadamard(&b.transpose_view())
+                .unwrap()
+                .as_vec()
+        );
+
+        let u = Vector::new(vec![1, 2, 3]);
+        assert_eq!(&vec![1, 4, 9], u.hadamard(&u).unwrap().as_vec());
+    }
+
+    #[test]
+    fn elementwise_div_test() {
+        // Every length up to a few SIMD widths, so the tails are covered
+        for len in 0..40 {
+            let (a, b) = (positive(len, 1), positive(len, 5));
+            let expected: Vec<f64> = a.iter().zip(&b).map(|(x, y)| x / y).collect();
+            let quotient = Vector::new(a.clone())
+                .elementwise_div(&Vector::new(b.clone()))
+                .unwrap();
+            assert_eq!(&expected, quotient.as_vec());
+
+            let a32: Vec<f32> = a.iter().map(|&x| x as f32).collect();
+            let b32: Vec<f32> = b.iter().map(|&x| x as f32).collect();
+            let expected: Vec<f32> = a32.iter().zip(&b32).map(|(x, y)| x / y).collect();
+            let product: Vec<f32> = a32.iter().zip(&b32).map(|(x, y)| x * y).collect();
+            let (a32, b32) = (Vector::new(a32), Vector::new(b32));
+            assert_eq!(&expected, a32.elementwise_div(&b32).unwrap().as_vec());
+            assert_eq!(&product, a32.hadamard(&b32).unwrap().as_vec());
+        }
+
+        let a = Matrix::new(2, 2, vec![1.0, 4.0, 9.0, 16.0]).unwrap();
+        let b = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+        assert_eq!(
+            &vec![1.0, 2.0, 3.0, 4.0],
+            a.elementwise_div(&b).unwrap().as_vec()
+        );
+    }
+
+    #[test]
+    fn map_test() {
+        let a = Matrix::new(2, 2, vec![1.5, -2.0, 0.0, 3.25]).unwrap();
+        let signs: Matrix<i32> = a.map(|&x| if x < 0.0 { -1 } else { 1 });
+        assert_eq!(&vec![1, -1, 1, 1], signs.as_vec());
+
+        // The layout is kept, so indexing still sees the same entries
+        let column_major = a.to_layout(Layout::ColumnMajor);
+        let doubled = column_major.map(|&x| x * 2.0);
+        assert_eq!(Layout::ColumnMajor, doubled.layout());
+        assert_eq!(-4.0, doubled[(0, 1)]);
+        assert_eq!(&vec![1.5, 0.0], a.col_view(0).unwrap().map(|&x| x).as_vec());
+
+        let lengths = Vector::new(vec!["a", "bcd", ""]).map(|s| s.len());
+        assert_eq!(&vec![1, 3, 0], lengths.as_vec());
+    }
+
+    #[test]
+    fn zip_test() {
+        let a = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let b = Matrix::new(3, 2, vec![1.0, 0.5, 2.0, 0.0, 1.0, 0.25]).unwrap();
+        let zipped = a.zip_map(&b, |&x, &y| x as f64 * y).unwrap();
+        assert_eq!(&vec![1.0, 1.0, 6.0, 0.0, 5.0, 1.5], zipped.as_vec());
+
+        let mut column_major = a.to_layout(Layout::ColumnMajor);
+        column_major
+            .zip_apply(&b, |&x, &y| x + (y * 4.0) as i32)
+            .unwrap();
+        assert_eq!(
+            vec![5, 4, 11, 4, 9, 7],
+            column_major.as_view().iter().copied().collect::<Vec<_>>()
+        );
+
+        let mut u = Vector::new(vec![1, 2, 3]);
+        u.zip_apply(&Vector::new(vec![true, false, true]), |&x, &keep| {
+            if keep {
+                x
+            } else {
+                0
+            }
+        })
+        .unwrap();
+        assert_eq!(&vec![1, 0, 3], u.as_vec());
+        assert_eq!(
+            &vec![2.0, 0.0, 6.0],
+            u.zip_map(&Vector::new(vec![2.0, 1.0, 2.0]), |&x, &y| x as f64 * y)
+                .unwrap()
+                .as_vec()
+        );
+    }
+
+    #[test]
+    fn parallel_test() {
+        // Large enough to be split into chunks over the thread pool
+        let len = 100_003;
+        let (a, b) = (positive(len, 2), positive(len, 3));
+        let expected: Vec<f64> = a.iter().zip(&b).map(|(x, y)| x * y).collect();
+
+        let context = ExecutionContext::default().with_threshold(0);
+        context.install(|| {
+            let (u, v) = (Vector::new(a.clone()), Vector::new(b.clone()));
+            assert_eq!(&expected, u.hadamard(&v).unwrap().as_vec());
+            assert_eq!(&expected, u.zip_map(&v, |x, y| x * y).unwrap().as_vec());
+
+            let mut w = u.clone();
+            w.zip_apply(&v, |x, y| x * y).unwrap();
+            assert_eq!(&expected, w.as_vec());
+            for (x, y) in w.elementwise_div(&v).unwrap().as_vec().iter().zip(&a) {
+                assert!((x - y).abs() < 1e-12);
+            }
+        });
+    }
+
+    #[test]
+    fn mismatch_test() {
+        let a = Matrix::new(3, 2, vec![1.0; 6]).unwrap();
+        let b = Matrix::new(2, 3, vec![1.0; 6]).unwrap();
+        let error = Error::ShapeMismatch {
+            expected: (2, 3),
+            found: (3, 2),
+        };
+        assert_eq!(error, a.hadamard(&b).unwrap_err());
+        assert_eq!(error, a.elementwise_div(&b).unwrap_err());
+        assert_eq!(error, a.zip_map(&b, |x, y| x + y).unwrap_err());
+        let mut c = a.clone();
+        assert_eq!(error, c.zip_apply(&b, |x, y| x + y).unwrap_err());
+
+        let u = Vector::new(vec![1.0; 3]);
+        let v = Vector::new(vec![1.0; 4]);
+        let error = Error::DimensionMismatch {
+            expected: 3,
+            found: 4,
+        };
+        assert_eq!(error, u.hadamard(&v).unwrap_err());
+        assert_eq!(error, u.elementwise_div(&v).unwrap_err());
+        assert_eq!(error, u.zip_map(&v, |x, y| x + y).unwrap_err());
+    }
+}
@@ -0,0 +1,253 @@
+#[cfg(test)]
+mod gemm_tests {
+    extern crate linearalgebra;
+
+    use linearalgebra::matrix::*;
+    use linearalgebra::vector::*;
+    use linearalgebra::{Complex, Error};
+
+    // 2 x 3
+    fn a() -> Matrix<f64> {
+        Matrix::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap()
+    }
+
+    // 3 x 2
+    fn b() -> Matrix<f64> {
+        Matrix::new(2, 3, vec![1.0, -1.0, 0.0, 2.0, 3.0, 1.0]).unwrap()
+    }
+
+    #[test]
+    fn gemm_test() {
+        let mut c = Matrix::new(2, 2, vec![1.0, 1.0, 1.0, 1.0]).unwrap();
+        Matrix::gemm(
+            2.0,
+            &a(),
+            Op::NoTranspose,
+            &b(),
+            Op::NoTranspose,
+            3.0,
+            &mut c,
+        )
+        .unwrap();
+
+        // AB = [[10, 6], [22, 12]]
+        assert_eq!(&vec![23.0, 15.0, 47.0, 27.0], c.as_vec());
+    }
+
+    #[test]
+    fn gemm_transpose_test() {
+        let expected = a().product_matrix(&b()).unwrap();
+        let (a_t, b_t) = (a().transpose(), b().transpose());
+
+        let mut c = Matrix::new_of_element(2, 2, 0.0).unwrap();
+        Matrix::gemm(1.0, &a_t, Op::Transpose, &b(), Op::NoTranspose, 0.0, &mut c).unwrap();
+        assert_eq!(expected.as_vec(), c.as_vec());
+
+        Matrix::gemm(1.0, &a(), Op::NoTranspose, &b_t, Op::Transpose, 0.0, &mut c).unwrap();
+        assert_eq!(expected.as_vec(), c.as_vec());
+
+        // Conjugating real matrices changes nothing
+        Matrix::gemm(
+            1.0,
+            &a_t,
+            Op::ConjugateTranspose,
+            &b_t,
+            Op::Transpose,
+            0.0,
+            &mut c,
+        )
+        .unwrap();
+        assert_eq!(expected.as_vec(), c.as_vec());
+    }
+
+    #[test]
+    fn gemm_beta_zero_test() {
+        // A zero beta ignores what C held, even NaN
+        let mut c = Matrix::new_of_element(2, 2, f64::NAN).unwrap();
+        Matrix::gemm(
+            1.0,
+            &a(),
+            Op::NoTranspose,
+            &b(),
+            Op::NoTranspose,
+            0.0,
+            &mut c,
+        )
+        .unwrap();
+
+        assert_eq!(&vec![10.0, 6.0, 22.0, 12.0], c.as_vec());
+    }
+
+    #[test]
+    fn gemm_column_major_output_test() {
+        let mut c = Matrix::new_with_layout(2, 2, vec![0.0; 4], Layout::ColumnMajor).unwrap();
+        Matrix::gemm(
+            1.0,
+            &a(),
+            Op::NoTranspose,
+            &b(),
+            Op::NoTranspose,
+            0.0,
+            &mut c,
+        )
+        .unwrap();
+
+        assert_eq!(Layout::ColumnMajor, c.layout());
+        assert_eq!(&vec![10.0, 22.0, 6.0, 12.0], c.as_vec());
+    }
+
+    #[test]
+    fn gemm_large_test() {
+        // Big enough for the blocked kernel, with transposed operands
+        let a = Matrix::new_of_supplier(90, 70, || 0.5);
+        let mut counter = 0.0;
+        let b = Matrix::new_of_supplier(90, 80, || {
+            counter += 1.0;
+            counter % 9.0
+        });
+        let expected = a.product_matrix(&b.transpose()).unwrap();
+
+        let mut c = Matrix::new_of_element(80, 70, 1.0).unwrap();
+        Matrix::gemm(1.0, &a, Op::NoTranspose, &b, Op::Transpose, -1.0, &mut c).unwrap();
+        assert_eq!(
+            &expected
+                .as_vec()
+                .iter()
+                .map(|x| x - 1.0)
+                .collect::<Vec<f64>>(),
+            c.as_vec()
+        );
+    }
+
+    #[test]
+    fn gemm_complex_test() {
+        let i = Complex::new(0.0, 1.0);
+        let one = Complex::from_real(1.0);
+        // [[1, i], [0, 1]]
+        let a = Matrix::new(2, 2, vec![one, i, Complex::from_real(0.0), one]).unwrap();
+
+        // A^H A = [[1, i], [-i, 2]]
+        let mut c = Matrix::new_of_element(2, 2, Complex::from_real(0.0)).unwrap();
+        Matrix::gemm(
+            one,
+            &a,
+            Op::ConjugateTranspose,
+            &a,
+            Op::NoTranspose,
+            Complex::from_real(0.0),
+            &mut c,
+        )
+        .unwrap();
+        assert_eq!(&vec![one, i, -i, Complex::from_real(2.0)], c.as_vec());
+        assert_eq!(a.adjoint().product_matrix(&a).unwrap().as_vec(), c.as_vec());
+    }
+
+    #[test]
+    fn gemm_mismatch_test() {
+        let mut c = Matrix::new_of_element(2, 2, 0.0).unwrap();
+
+        assert_eq!(
+            Error::DimensionMismatch {
+                expected: 3,
+                found: 2
+            },
+            Matrix::gemm(
+                1.0,
+                &a(),
+                Op::NoTranspose,
+                &a(),
+                Op::NoTranspose,
+                0.0,
+                &mut c
+            )
+            .unwrap_err()
+        );
+        assert_eq!(
+            Error::ShapeMismatch {
+                expected: (3, 3),
+                found: (2, 2)
+            },
+            Matrix::gemm(
+                1.0,
+                &b(),
+                Op::NoTranspose,
+                &a(),
+                Op::NoTranspose,
+                0.0,
+                &mut c
+            )
+            .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn gemv_test() {
+        let x = Vector::new(vec![1.0, 0.0, -1.0]);
+        let mut y = Vector::new(vec![1.0, 1.0]);
+        Matrix::gemv(2.0, &a(), Op::NoTranspose, &x, 1.0, &mut y).unwrap();
+        assert_eq!(&vec![-3.0, -3.0], y.as_vec());
+
+        // The same through the columns of a column-major matrix
+        let column_major = a().to_layout(Layout::ColumnMajor);
+        let mut y = Vector::new(vec![1.0, 1.0]);
+        Matrix::gemv(2.0, &column_major, Op::NoTranspose, &x, 1.0, &mut y).unwrap();
+        assert_eq!(&vec![-3.0, -3.0], y.as_vec());
+
+        let x = Vector::new(vec![1.0, 1.0]);
+        let mut y = Vector::new(vec![f64::NAN; 3]);
+        Matrix::gemv(1.0, &a(), Op::Transpose, &x, 0.0, &mut y).unwrap();
+        assert_eq!(&vec![5.0, 7.0, 9.0], y.as_vec());
+    }
+
+    #[test]
+    fn gemv_complex_test() {
+        let i = Complex::new(0.0, 1.0);
+        let one = Complex::from_real(1.0);
+        let zero = Complex::from_real(0.0);
+        let a = Matrix::new(2, 2, vec![one, i, zero, one]).unwrap();
+        let x = Vector::new(vec![one, one]);
+
+        let mut y = Vector::new(vec![zero, zero]);
+        Matrix::gemv(one, &a, Op::ConjugateTranspose, &x, zero, &mut y).unwrap();
+        assert_eq!(&vec![one, one - i], y.as_vec());
+
+        Matrix::gemv(one, &a, Op::NoTranspose, &x, zero, &mut y).unwrap();
+        assert_eq!(&vec![one + i, one], y.as_vec());
+    }
+
+    #[test]
+    fn gemv_mismatch_test() {
+        let mut y = Vector::new(vec![0.0; 2]);
+
+        assert_eq!(
+            Error::DimensionMismatch {
+                expected: 3,
+                found: 2
+            },
+            Matrix::gemv(
+                1.0,
+                &a(),
+                Op::NoTranspose,
+                &Vector::new(vec![1.0, 2.0]),
+                0.0,
+                &mut y
+            )
+            .unwrap_err()
+        );
+        assert_eq!(
+            Error::DimensionMismatch {
+                expected: 3,
+                found: 2
+            },
+            Matrix::gemv(
+                1.0,
+                &a(),
+                Op::Transpose,
+                &Vector::new(vec![1.0, 2.0]),
+                0.0,
+                &mut y
+            )
+            .unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,246 @@
+#[cfg(test)]
+mod geometry_tests {
+    extern crate linearalgebra;
+
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use linearalgebra::geometry::*;
+    use linearalgebra::matrix::*;
+    use linearalgebra::vector::*;
+    use linearalgebra::Error;
+
+    fn assert_close<const N: usize>(expected: SVector<f64, N>, found: SVector<f64, N>) {
+        assert!(
+            (expected - found).norm_l2() < 1e-12,
+            "{:?} != {:?}",
+            expected,
+            found
+        );
+    }
+
+    fn assert_matrix_close<const R: usize, const C: usize>(
+        expected: SMatrix<f64, R, C>,
+        found: SMatrix<f64, R, C>,
+    ) {
+        assert!(
+            (expected - found).to_matrix().norm_frobenius() < 1e-12,
+            "{:?} != {:?}",
+            expected,
+            found
+        );
+    }
+
+    fn x() -> SVector<f64, 3> {
+        SVector::new([1.0, 0.0, 0.0])
+    }
+
+    fn y() -> SVector<f64, 3> {
+        SVector::new([0.0, 1.0, 0.0])
+    }
+
+    fn z() -> SVector<f64, 3> {
+        SVector::new([0.0, 0.0, 1.0])
+    }
+
+    #[test]
+    fn cross_test() {
+        assert_eq!(z(), x().cross(&y()));
+        assert_eq!(-z(), y().cross(&x()));
+
+        let a = SVector::new([1, 2, 3]);
+        let b = SVector::new([4, 5, 6]);
+        let c = SVector::new([7, 8, 10]);
+        assert_eq!(SVector::new([-3, 6, -3]), a.cross(&b));
+        assert_eq!(0, a.cross(&b).dot(&a));
+        // The determinant of the rows a, b, c
+        assert_eq!(-3, a.scalar_triple(&b, &c));
+        assert_eq!(b * a.dot(&c) - c * a.dot(&b), a.vector_triple(&b, &c));
+
+        // Dynamic vectors of length 3 work the same
+        let v = Vector::new(vec![1, 2, 3]);
+        let w = Vector::new(vec![4, 5, 6]);
+        assert_eq!(&vec![-3, 6, -3], v.cross(&w).unwrap().as_vec());
+        assert_eq!(Ok(-3), v.scalar_triple(&w, &Vector::new(vec![7, 8, 10])));
+        assert_eq!(
+            Err(Error::DimensionMismatch {
+                expected: 3,
+                found: 2
+            }),
+            v.cross(&Vector::new(vec![1, 2]))
+        );
+    }
+
+    #[test]
+    fn rotation_matrix_test() {
+        let r = SMatrix::rotation_z(FRAC_PI_2);
+        assert_close(y(), r * x());
+        assert_close(-x(), r * y());
+        assert_close(z(), SMatrix::rotation_x(FRAC_PI_2) * y());
+        assert_close(x(), SMatrix::rotation_y(FRAC_PI_2) * z());
+
+        // A third of a turn about the diagonal cycles the axes
+        let diagonal = SVector::new([1.0, 1.0, 1.0]);
+        let r = SMatrix::from_axis_angle(&diagonal, 2.0 * PI / 3.0);
+        assert_close(y(), r * x());
+        assert_close(z(), r * y());
+        assert_matrix_close(SMatrix::identity(), r * r.transpose());
+        assert_matrix_close(
+            SMatrix::rotation_z(0.3),
+            SMatrix::from_axis_angle(&(z() * 5.0), 0.3),
+        );
+
+        let (roll, pitch, yaw) = (0.1, -0.7, 2.5);
+        let r = SMatrix::from_euler_angles(roll, pitch, yaw);
+        assert_matrix_close(
+            SMatrix::rotation_z(yaw) * SMatrix::rotation_y(pitch) * SMatrix::rotation_x(roll),
+            r,
+        );
+        let (r2, p2, y2) = r.to_euler_angles();
+        assert!(
+            (roll - r2).abs() < 1e-12 && (pitch - p2).abs() < 1e-12 && (yaw - y2).abs() < 1e-12
+        );
+
+        // Looking straight up, roll and yaw can't be told apart
+        let r = SMatrix::from_euler_angles(0.4, FRAC_PI_2, 0.2);
+        let (r2, p2, y2) = r.to_euler_angles();
+        assert_eq!(0.0, r2);
+        assert!((FRAC_PI_2 - p2).abs() < 1e-12);
+        assert_matrix_close(r, SMatrix::from_euler_angles(r2, p2, y2));
+    }
+
+    #[test]
+    fn quaternion_test() {
+        let axis = SVector::new([1.0, -2.0, 0.5]);
+        let q: Quaternion<f64> = Quaternion::from_axis_angle(&axis, 1.2);
+        assert!((1.0 - q.coords().norm_l2()).abs() < 1e-15);
+
+        let v = SVector::new([0.3, 4.0, -1.0]);
+        let r = SMatrix::from_axis_angle(&axis, 1.2);
+        assert_matrix_close(r, q.to_rotation_matrix());
+        assert_close(r * v, q * v);
+        assert_close(v, q.inverse() * (q * v));
+
+        let (found_axis, angle) = q.to_axis_angle();
+        assert_close(axis.normalized(), found_axis);
+        assert!((1.2 - angle).abs() < 1e-12);
+
+        // Composing quaternions composes the rotations
+        let p = Quaternion::from_euler_angles(0.1, 0.2, 0.3);
+        assert_matrix_close(
+            SMatrix::from_euler_angles(0.1, 0.2, 0.3),
+            p.to_rotation_matrix(),
+        );
+        assert_close(p * (q * v), (p * q) * v);
+
+        // Back from the matrix, for every branch of Shepperd's method
+        for angle in [0.5, 3.0, PI] {
+            for axis in [axis, x(), y(), z()] {
+                let q = Quaternion::from_axis_angle(&axis, angle);
+                let back = Quaternion::from_rotation_matrix(&q.to_rotation_matrix());
+                assert!((1.0 - q.dot(&back).abs()).abs() < 1e-12);
+            }
+        }
+
+        assert_eq!((x(), 0.0), Quaternion::identity().to_axis_angle());
+        assert!((1.2 - Quaternion::identity().angle_to(&q)).abs() < 1e-12);
+        assert!(Quaternion::new(0.0f64, 0.0, 0.0, 0.0).w().is_nan());
+    }
+
+    #[test]
+    fn slerp_test() {
+        let start = Quaternion::identity();
+        let end = Quaternion::from_axis_angle(&z(), FRAC_PI_2);
+        assert_eq!(start, start.slerp(&end, 0.0));
+        assert!((1.0 - end.dot(&start.slerp(&end, 1.0))).abs() < 1e-12);
+
+        // Constant speed: a third of the way is a third of the angle
+        let third = start.slerp(&end, 1.0 / 3.0);
+        assert!((PI / 6.0 - start.angle_to(&third)).abs() < 1e-12);
+        assert_close(
+            SVector::new([(PI / 6.0).cos(), (PI / 6.0).sin(), 0.0]),
+            third * x(),
+        );
+
+        // -end is the same rotation, and slerp still takes the short way
+        let halfway = start.slerp(&-end, 0.5);
+        assert!((PI / 4.0 - start.angle_to(&halfway)).abs() < 1e-12);
+
+        // Nearly equal quaternions don't divide by a vanishing sine
+        let close = Quaternion::from_axis_angle(&z(), 1e-10);
+        assert!(!start.slerp(&close, 0.5).w().is_nan());
+    }
+
+    #[test]
+    fn isometry_test() {
+        let rotation = Quaternion::from_axis_angle(&z(), FRAC_PI_2);
+        let pose = Isometry::new(rotation, SVector::new([1.0, 2.0, 3.0]));
+
+        // Points move with the translation, vectors only turn
+        assert_close(SVector::new([1.0, 3.0, 3.0]), pose.transform_point(&x()));
+        assert_close(y(), pose.transform_vector(&x()));
+
+        let p = SVector::new([0.5, -1.0, 2.0]);
+        assert_close(p, pose.inverse().transform_point(&pose.transform_point(&p)));
+
+        let other = Isometry::new(
+            Quaternion::from_euler_angles(0.3, 0.2, 0.1),
+            SVector::new([-1.0, 0.0, 4.0]),
+        );
+        assert_close(
+            pose.transform_point(&other.transform_point(&p)),
+            (pose * other).transform_point(&p),
+        );
+
+        let homogeneous = pose.to_homogeneous();
+        let [px, py, pz] = *p.as_array();
+        let moved = homogeneous * SVector::new([px, py, pz, 1.0]);
+        let [mx, my, mz, w] = *moved.as_array();
+        assert_eq!(1.0, w);
+        assert_close(pose.transform_point(&p), SVector::new([mx, my, mz]));
+    }
+
+    #[test]
+    fn transform_test() {
+        let pose = Isometry::new(
+            Quaternion::from_axis_angle(&x(), 0.7),
+            SVector::new([1.0, 2.0, 3.0]),
+        );
+        let scale = Transform::from_scale(SVector::new([2.0, 3.0, 4.0]));
+        let transform = Transform::from(pose) * scale;
+
+        let p = SVector::new([1.0, 1.0, 1.0]);
+        assert_close(
+            pose.transform_point(&SVector::new([2.0, 3.0, 4.0])),
+            transform.transform_point(&p),
+        );
+        assert_close(
+            pose.transform_vector(&SVector::new([2.0, 3.0, 4.0])),
+            transform.transform_vector(&p),
+        );
+        assert_close(p * 2.0, Transform::from_translation(p).transform_point(&p));
+        assert_close(p, Transform::from_translation(p).transform_vector(&p));
+
+        let inverse = transform.inverse().unwrap();
+        assert_close(p, inverse.transform_point(&transform.transform_point(&p)));
+        assert_matrix_close(SMatrix::identity(), *(inverse * transform).matrix());
+        assert_eq!(
+            Err(Error::Singular),
+            Transform::from_scale(SVector::new([1.0, 0.0, 1.0])).inverse()
+        );
+
+        // A projection divides by the fourth entry
+        let mut perspective = SMatrix::identity();
+        perspective[(3, 2)] = 1.0;
+        perspective[(3, 3)] = 0.0;
+        assert_close(
+            SVector::new([0.5, 1.0, 1.0]),
+            Transform::new(perspective).transform_point(&SVector::new([1.0, 2.0, 2.0])),
+        );
+
+        // And it all goes through the dynamic types too
+        let dynamic = Vector::new(vec![1.0, 1.0, 1.0]);
+        let moved = transform.transform_point(&SVector::try_from(&dynamic).unwrap());
+        assert_eq!(3, Vector::from(moved).len());
+        assert_eq!((4, 4), transform.matrix().to_matrix().shape());
+    }
+}
@@ -0,0 +1,137 @@
+mod common;
+
+#[cfg(test)]
+mod layout_tests {
+    extern crate linearalgebra;
+
+    use super::common::{assert_close, TOLERANCE};
+    use linearalgebra::matrix::*;
+    use linearalgebra::vector::*;
+
+    // 2 x 3 with entry (row, col) = 10 * row + col, stored column after column
+    fn column_major() -> Matrix<i32> {
+        Matrix::new_with_layout(3, 2, vec![0, 10, 1, 11, 2, 12], Layout::ColumnMajor).unwrap()
+    }
+
+    fn row_major() -> Matrix<i32> {
+        Matrix::new(3, 2, vec![0, 1, 2, 10, 11, 12]).unwrap()
+    }
+
+    #[test]
+    fn index_test() {
+        let mut matrix = column_major();
+
+        assert_eq!(Layout::ColumnMajor, matrix.layout());
+        assert_eq!(Layout::RowMajor, row_major().layout());
+        assert_eq!((2, 3), matrix.shape());
+        assert_eq!(12, matrix[(1, 2)]);
+        assert_eq!(1, matrix[(0, 1)]);
+
+        matrix[(1, 0)] = 7;
+        assert_eq!(&vec![0, 7, 1, 11, 2, 12], matrix.as_vec());
+        assert!(Matrix::new_with_layout(2, 2, vec![1, 2, 3], Layout::ColumnMajor).is_err());
+    }
+
+    #[test]
+    fn rows_and_cols_test() {
+        let matrix = column_major();
+
+        assert_eq!(&vec![10, 11, 12], matrix.get_row(1).unwrap().as_vec());
+        assert_eq!(&vec![2, 12], matrix.get_col(2).unwrap().as_vec());
+        assert_eq!(&vec![10, 12, 14], matrix.sum_columns().as_vec());
+        assert_eq!(&vec![3, 33], matrix.sum_rows().as_vec());
+    }
+
+    #[test]
+    fn conversion_test() {
+        let matrix = column_major();
+        let converted = matrix.to_layout(Layout::RowMajor);
+
+        assert_eq!(Layout::RowMajor, converted.layout());
+        assert_eq!(row_major().as_vec(), converted.as_vec());
+        assert_eq!(
+            matrix.as_vec(),
+            row_major().into_layout(Layout::ColumnMajor).as_vec()
+        );
+        assert_eq!(
+            matrix.as_vec(),
+            matrix.clone().into_layout(Layout::ColumnMajor).as_vec()
+        );
+    }
+
+    #[test]
+    fn view_test() {
+        let matrix = column_major();
+        let view = matrix.view(.., 1..).unwrap();
+
+        assert_eq!((1, 2), view.strides());
+        assert!(!view.is_contiguous());
+        assert!(matrix.transpose_view().is_contiguous());
+        assert_eq!(&vec![1, 2, 11, 12], view.to_matrix().as_vec());
+        assert_eq!(&vec![0, 10, 1, 11, 2, 12], matrix.transpose().as_vec());
+    }
+
+    #[test]
+    fn product_test() {
+        let expected = row_major()
+            .product_matrix(&row_major().transpose())
+            .unwrap();
+
+        // Every combination of layouts gives the same product
+        for lhs in [row_major(), column_major()] {
+            for rhs in [row_major(), column_major()] {
+                let rhs = rhs.transpose();
+                let res = lhs.product_matrix(&rhs).unwrap();
+                assert_eq!(&vec![5, 35, 35, 365], expected.as_vec());
+                assert_eq!(expected.as_vec(), res.as_vec());
+            }
+        }
+
+        let vector = Vector::new(vec![1, 0, -1]);
+        assert_eq!(
+            &vec![-2, -2],
+            column_major().product_vector(&vector).unwrap().as_vec()
+        );
+    }
+
+    #[test]
+    fn elementwise_test() {
+        let sum = column_major() + row_major();
+
+        assert_eq!((2, 3), sum.shape());
+        assert_eq!(24, sum[(1, 2)]);
+        assert_eq!(2, sum[(0, 1)]);
+
+        let mut difference = column_major();
+        difference -= row_major();
+        assert_eq!(&vec![0; 6], difference.as_vec());
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!(format!("{}", row_major()), format!("{}", column_major()));
+    }
+
+    #[test]
+    fn decomposition_test() {
+        // Column-major storage of [[4, 2], [1, 3]]
+        let matrix =
+            Matrix::new_with_layout(2, 2, vec![4.0, 1.0, 2.0, 3.0], Layout::ColumnMajor).unwrap();
+        let b = Vector::new(vec![8.0, 7.0]);
+
+        assert_close(
+            &[1.0, 2.0],
+            matrix.lu().unwrap().solve(&b).unwrap().as_vec(),
+        );
+        assert!((matrix.determinant().unwrap() - 10.0).abs() < TOLERANCE);
+        assert_close(&[1.0, 2.0], matrix.least_squares(&b).unwrap().0.as_vec());
+        assert_close(
+            matrix
+                .to_layout(Layout::RowMajor)
+                .singular_values()
+                .unwrap()
+                .as_vec(),
+            matrix.singular_values().unwrap().as_vec(),
+        );
+    }
+}
@@ -6,6 +6,7 @@ mod matrix_tests {
 
     use linearalgebra::matrix::*;
     use linearalgebra::vector::*;
+    use linearalgebra::Error;
 
     #[test]
     fn get_col_success_test() {
@@ -18,7 +19,10 @@ mod matrix_tests {
     fn get_col_fail_test() {
         let matrix = Matrix::new(3, 3, vec![1, 1, 2, 3, 4, 5, 6, 1, 2]).unwrap();
 
-        assert!(matrix.get_col(6).is_err());
+        assert_eq!(
+            matrix.get_col(6).unwrap_err(),
+            Error::IndexOutOfBounds { index: 6, bound: 3 }
+        );
     }
 
     #[test]
@@ -93,6 +97,97 @@ mod matrix_tests {
         )
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn matrix_matrix_product_non_square_test() {
+        // 2 x 3 times 3 x 4, where indexing the transposed rhs by its width
+        // instead of its height reads the wrong entries
+        let matrix = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let matrix2 = Matrix::new(4, 3, vec![1, 0, 2, -1, 0, 1, 1, 2, 3, -2, 0, 1]).unwrap();
+        let expected = vec![10, -4, 4, 6, 22, -7, 13, 12];
+
+        let product = matrix.product_matrix(&matrix2).unwrap();
+        assert_eq!((2, 4), product.shape());
+        assert_eq!(&expected, product.as_vec());
+        assert_eq!(
+            &expected,
+            matrix.trivial_product_matrix(&matrix2).unwrap().as_vec()
+        );
+        assert_eq!(
+            &expected,
+            matrix.simple_product_matrix(&matrix2).unwrap().as_vec()
+        );
+    }
+
+    #[test]
+    fn matrix_matrix_product_blocked_test() {
+        // Large and ragged enough to go through the packed kernel with partial tiles
+        let (m, k, n) = (71, 300, 37);
+        let a = Matrix::new(k, m, (0..m * k).map(|i| (i % 13) as i64 - 6).collect()).unwrap();
+        let b = Matrix::new(n, k, (0..k * n).map(|i| (i % 7) as i64 - 3).collect()).unwrap();
+
+        let mut expected = vec![0; m * n];
+        for row in 0..m {
+            for col in 0..n {
+                for index in 0..k {
+                    expected[row * n + col] += a[(row, index)] * b[(index, col)];
+                }
+            }
+        }
+
+        assert_eq!(&expected, a.product_matrix(&b).unwrap().as_vec());
+        assert_eq!(
+            &expected,
+            a.product_matrix(&b.to_layout(Layout::ColumnMajor))
+                .unwrap()
+                .as_vec()
+        );
+    }
+
+    #[test]
+    fn matrix_matrix_product_fail_test() {
+        let matrix = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+
+        assert_eq!(
+            matrix.product_matrix(&matrix).unwrap_err(),
+            Error::DimensionMismatch {
+                expected: 3,
+                found: 2
+            }
+        )
+    }
+
+    #[test]
+    fn new_mismatched_data_test() {
+        let matrix = Matrix::new(2, 2, vec![1, 2, 3]);
+
+        assert_eq!(
+            matrix.unwrap_err(),
+            Error::DimensionMismatch {
+                expected: 4,
+                found: 3
+            }
+        )
+    }
+
+    #[test]
+    fn add_mismatched_shape_test() {
+        let matrix = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let error = matrix.add(&matrix.transpose()).unwrap_err();
+
+        assert_eq!(
+            error,
+            Error::ShapeMismatch {
+                expected: (2, 3),
+                found: (3, 2)
+            }
+        );
+        assert_eq!(
+            "Shapes don't match: expected (2, 3), found (3, 2)",
+            error.to_string()
+        )
+    }
+
     #[test]
     fn transpose_nonsquare_test() {
         let matrix = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
@@ -236,12 +331,94 @@ mod matrix_tests {
     #[test]
     fn max_test() {
         let matrix: Matrix<i32> = Matrix::new(2, 2, vec![-4, -6, -2, 6]).unwrap();
-        assert_eq!(6, matrix.max())
+        assert_eq!(Some(6), matrix.max())
     }
 
     #[test]
     fn min_test() {
         let matrix: Matrix<i32> = Matrix::new(2, 2, vec![-4, -6, -2, 6]).unwrap();
-        assert_eq!(-6, matrix.min())
+        assert_eq!(Some(-6), matrix.min())
+    }
+
+    #[test]
+    fn add_operator_test() {
+        let matrix: Matrix<i32> = Matrix::new(2, 2, vec![1, 2, 3, 4]).unwrap();
+        let matrix_2: Matrix<i32> = Matrix::new(2, 2, vec![3, 2, 1, 4]).unwrap();
+
+        assert_eq!(&vec![4, 4, 4, 8], (&matrix + &matrix_2).as_vec());
+        assert_eq!(&vec![4, 4, 4, 8], (matrix.clone() + &matrix_2).as_vec());
+        assert_eq!(&vec![4, 4, 4, 8], (&matrix + matrix_2.clone()).as_vec());
+        assert_eq!(&vec![4, 4, 4, 8], (matrix + matrix_2).as_vec());
+    }
+
+    #[test]
+    fn sub_operator_test() {
+        let matrix: Matrix<i32> = Matrix::new(2, 2, vec![1, 2, 3, 4]).unwrap();
+        let matrix_2: Matrix<i32> = Matrix::new(2, 2, vec![3, 2, 1, 4]).unwrap();
+
+        assert_eq!(&vec![-2, 0, 2, 0], (&matrix - &matrix_2).as_vec());
+        assert_eq!(&vec![-2, 0, 2, 0], (&matrix - matrix_2.clone()).as_vec());
+        assert_eq!(&vec![2, 0, -2, 0], (matrix_2 - matrix).as_vec());
+    }
+
+    #[test]
+    fn assign_operators_test() {
+        let mut matrix: Matrix<i32> = Matrix::new(2, 2, vec![1, 2, 3, 4]).unwrap();
+        let matrix_2: Matrix<i32> = Matrix::new(2, 2, vec![3, 2, 1, 4]).unwrap();
+
+        matrix += &matrix_2;
+        assert_eq!(&vec![4, 4, 4, 8], matrix.as_vec());
+        matrix -= matrix_2;
+        assert_eq!(&vec![1, 2, 3, 4], matrix.as_vec());
+        matrix *= 2;
+        assert_eq!(&vec![2, 4, 6, 8], matrix.as_vec());
+    }
+
+    #[test]
+    #[should_panic(expected = "Shapes (2, 3) and (3, 2) can't be broadcast together")]
+    fn add_operator_mismatched_shape_test() {
+        let matrix = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+
+        let _ = &matrix + &matrix.transpose();
+    }
+
+    #[test]
+    fn mul_operator_test() {
+        let matrix = Matrix::new(3, 3, vec![1, 1, 2, 3, 4, 5, 6, 1, 2]).unwrap();
+        let matrix2 = Matrix::new(3, 3, vec![1, 9, 2, 7, 1, 5, 3, 8, 2]).unwrap();
+        let vector = Vector::new(vec![1, 4, 1]);
+
+        assert_eq!(
+            (&matrix * &matrix2).as_vec(),
+            &vec![14, 26, 11, 46, 71, 36, 19, 71, 21]
+        );
+        assert_eq!(&matrix * &vector, Vector::new(vec![7, 24, 12]));
+        assert_eq!((&matrix * 2).as_vec(), &vec![2, 2, 4, 6, 8, 10, 12, 2, 4]);
+        assert_eq!((2i32 * matrix).as_vec(), &vec![2, 2, 4, 6, 8, 10, 12, 2, 4]);
+    }
+
+    #[test]
+    fn mul_assign_operator_test() {
+        let mut matrix = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        matrix *= Matrix::new(2, 3, vec![1, 0, 0, 1, 1, 1]).unwrap();
+
+        assert_eq!((2, 2), matrix.shape());
+        assert_eq!(&vec![4, 5, 10, 11], matrix.as_vec());
+    }
+
+    #[test]
+    #[should_panic(expected = "Dimensions don't match: expected 3, found 2")]
+    fn mul_operator_mismatched_test() {
+        let matrix = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+
+        let _ = &matrix * &matrix;
+    }
+
+    #[test]
+    fn neg_operator_test() {
+        let matrix: Matrix<i32> = Matrix::new(2, 2, vec![1, -2, 3, 0]).unwrap();
+
+        assert_eq!(&vec![-1, 2, -3, 0], (-&matrix).as_vec());
+        assert_eq!(&vec![-1, 2, -3, 0], (-matrix).as_vec());
     }
-}
+}
\ No newline at end of file
@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod norm_tests {
+    extern crate linearalgebra;
+
+    use linearalgebra::matrix::*;
+    use linearalgebra::norm::*;
+    use linearalgebra::vector::*;
+    use linearalgebra::Complex;
+
+    fn assert_close(expected: f64, found: f64) {
+        assert!(
+            (expected - found).abs() <= 1e-12 * expected.abs().max(1.0),
+            "{} != {}",
+            expected,
+            found
+        );
+    }
+
+    #[test]
+    fn vector_norm_test() {
+        let v = Vector::new(vec![3.0, -4.0]);
+        assert_eq!(7.0, v.norm_l1());
+        assert_eq!(5.0, v.norm_l2());
+        assert_eq!(4.0, v.norm_inf());
+        assert_close(91f64.powf(1.0 / 3.0), v.norm_p(3.0));
+        assert_close(7.0, v.norm_p(1.0));
+        assert_close(5.0, v.norm_p(2.0));
+        assert_eq!(4.0, v.norm_p(f64::INFINITY));
+
+        assert_eq!(v.norm_l1(), v.norm(VectorNorm::L1));
+        assert_eq!(v.norm_l2(), v.norm(VectorNorm::L2));
+        assert_eq!(v.norm_inf(), v.norm(VectorNorm::Inf));
+        assert_eq!(v.norm_p(3.0), v.norm(VectorNorm::P(3.0)));
+
+        let empty: Vector<f64> = Vector::new(vec![]);
+        assert_eq!(0.0, empty.norm_l2());
+        assert_eq!(0.0, empty.norm_inf());
+        assert_eq!(0.0, empty.norm_p(3.0));
+    }
+
+    #[test]
+    fn l2_scaling_test() {
+        // Squaring either of these on its own overflows or underflows
+        let huge = Vector::new(vec![3e200, 4e200]);
+        assert_close(5e200, huge.norm_l2());
+        assert_close(5e200, huge.norm_p(2.0));
+        let tiny = Vector::new(vec![3e-200, -4e-200]);
+        assert_close(5e-200, tiny.norm_l2());
+
+        let huge = Vector::new(vec![3e30f32, 4e30]);
+        assert!((huge.norm_l2() - 5e30).abs() < 1e24);
+
+        assert!(Vector::new(vec![1.0, f64::NAN]).norm_l2().is_nan());
+        assert_eq!(
+            f64::INFINITY,
+            Vector::new(vec![1.0, f64::INFINITY]).norm_l2()
+        );
+
+        // A second infinity mustn't be scaled by the first, inf / inf is NaN
+        let infinite = Vector::new(vec![f64::INFINITY, 2.0, f64::NEG_INFINITY]);
+        assert_eq!(f64::INFINITY, infinite.norm_l2());
+        assert_eq!(f64::INFINITY, infinite.norm_p(3.0));
+        assert_eq!(
+            f64::INFINITY,
+            Matrix::new(2, 1, vec![f64::INFINITY, f64::INFINITY])
+                .unwrap()
+                .norm_frobenius()
+        );
+        assert!(Vector::new(vec![f64::INFINITY, f64::NAN, f64::INFINITY])
+            .norm_l2()
+            .is_nan());
+    }
+
+    #[test]
+    fn abs_other_types_test() {
+        assert_eq!(5.0f32, Vector::new(vec![3.0f32, 4.0]).abs());
+
+        let complex = Vector::new(vec![Complex::new(3.0, 4.0), Complex::new(0.0, 12.0)]);
+        assert_eq!(13.0, complex.abs());
+        assert_eq!(17.0, complex.norm_l1());
+        assert_eq!(12.0, complex.norm_inf());
+    }
+
+    #[test]
+    fn normalize_test() {
+        let v = Vector::new(vec![3.0, 4.0]);
+        assert_eq!(&vec![0.6, 0.8], v.normalized().as_vec());
+
+        let mut w = Vector::new(vec![0.0f32, -2.0, 0.0]);
+        w.normalize();
+        assert_eq!(&vec![0.0, -1.0, 0.0], w.as_vec());
+
+        let complex = Vector::new(vec![Complex::new(0.0, 2.0)]).normalized();
+        assert_eq!(&vec![Complex::new(0.0, 1.0)], complex.as_vec());
+
+        // A zero vector has no direction
+        assert!(Vector::new(vec![0.0f64, 0.0]).normalized().as_vec()[0].is_nan());
+    }
+
+    #[test]
+    fn matrix_norm_test() {
+        // 1 -2
+        // 3  4
+        let a = Matrix::new(2, 2, vec![1.0, -2.0, 3.0, 4.0]).unwrap();
+        assert_close(30f64.sqrt(), a.norm_frobenius());
+        assert_eq!(6.0, a.norm_one());
+        assert_eq!(7.0, a.norm_inf());
+        // A^T A has eigenvalues 15 ± sqrt(125), and the singular values add
+        // up to sqrt(30 + 2 |det A|)
+        assert_close((15.0 + 125f64.sqrt()).sqrt(), a.norm_spectral().unwrap());
+        assert_close(50f64.sqrt(), a.norm_nuclear().unwrap());
+
+        assert_eq!(a.norm_frobenius(), a.norm(MatrixNorm::Frobenius).unwrap());
+        assert_eq!(6.0, a.norm(MatrixNorm::One).unwrap());
+        assert_eq!(7.0, a.norm(MatrixNorm::Inf).unwrap());
+        assert_eq!(
+            a.norm_spectral().unwrap(),
+            a.norm(MatrixNorm::Spectral).unwrap()
+        );
+        assert_eq!(
+            a.norm_nuclear().unwrap(),
+            a.norm(MatrixNorm::Nuclear).unwrap()
+        );
+
+        // The layout doesn't matter, and neither does transposing for the
+        // norms built on singular values
+        let column_major = a.to_layout(Layout::ColumnMajor);
+        assert_eq!(6.0, column_major.norm_one());
+        assert_eq!(7.0, column_major.norm_inf());
+        assert_close(
+            a.norm_spectral().unwrap(),
+            a.transpose().norm_spectral().unwrap(),
+        );
+    }
+
+    #[test]
+    fn matrix_norm_shapes_test() {
+        let wide = Matrix::new(3, 1, vec![1.0, -2.0, 2.0]).unwrap();
+        assert_eq!(2.0, wide.norm_one());
+        assert_eq!(5.0, wide.norm_inf());
+        assert_close(3.0, wide.norm_spectral().unwrap());
+        assert_close(3.0, wide.norm_nuclear().unwrap());
+        assert_close(3.0, wide.norm_frobenius());
+
+        let empty: Matrix<f64> = Matrix::new(0, 0, vec![]).unwrap();
+        for norm in [
+            MatrixNorm::Frobenius,
+            MatrixNorm::One,
+            MatrixNorm::Inf,
+            MatrixNorm::Spectral,
+            MatrixNorm::Nuclear,
+        ] {
+            assert_eq!(0.0, empty.norm(norm).unwrap());
+        }
+
+        let complex =
+            Matrix::new(1, 2, vec![Complex::new(3.0, 4.0), Complex::new(0.0, 1.0)]).unwrap();
+        assert_eq!(6.0, complex.norm_one());
+        assert_eq!(5.0, complex.norm_inf());
+        assert_close(26f64.sqrt(), complex.norm_frobenius());
+    }
+}
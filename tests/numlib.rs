@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod numlib_tests {
+    extern crate linearalgebra;
+
+    use std::ops::{Add, Mul, Sub};
+
+    use linearalgebra::matrix::*;
+    use linearalgebra::numlib::*;
+    use linearalgebra::vector::*;
+
+    // Integers modulo 7, a scalar type the crate knows nothing about
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Mod7(u8);
+
+    impl Zero for Mod7 {
+        fn zero() -> Mod7 {
+            Mod7(0)
+        }
+    }
+
+    impl One for Mod7 {
+        fn one() -> Mod7 {
+            Mod7(1)
+        }
+    }
+
+    impl Add for Mod7 {
+        type Output = Mod7;
+
+        fn add(self, other: Mod7) -> Mod7 {
+            Mod7((self.0 + other.0) % 7)
+        }
+    }
+
+    impl Sub for Mod7 {
+        type Output = Mod7;
+
+        fn sub(self, other: Mod7) -> Mod7 {
+            Mod7((self.0 + 7 - other.0) % 7)
+        }
+    }
+
+    impl Mul for Mod7 {
+        type Output = Mod7;
+
+        fn mul(self, other: Mod7) -> Mod7 {
+            Mod7((self.0 * other.0) % 7)
+        }
+    }
+
+    impl Scalar for Mod7 {}
+
+    impl Ring for Mod7 {
+        fn conj(self) -> Mod7 {
+            self
+        }
+    }
+
+    fn sum_of_squares<T: Ring>(vector: &Vector<T>) -> T {
+        vector.dot(vector).unwrap()
+    }
+
+    fn hypotenuse<T: RealField>(a: T, b: T) -> T {
+        (a * a + b * b).sqrt()
+    }
+
+    #[test]
+    fn custom_ring_test() {
+        let matrix = Matrix::new(2, 2, vec![Mod7(3), Mod7(4), Mod7(5), Mod7(6)]).unwrap();
+        let product = matrix.product_matrix(&matrix).unwrap();
+
+        // [[3*3 + 4*5, 3*4 + 4*6], [5*3 + 6*5, 5*4 + 6*6]] mod 7
+        assert_eq!(&vec![Mod7(1), Mod7(1), Mod7(3), Mod7(0)], product.as_vec());
+        assert_eq!(
+            Mod7(6),
+            sum_of_squares(&Vector::new(vec![Mod7(3), Mod7(2)]))
+        );
+    }
+
+    #[test]
+    fn generic_real_field_test() {
+        assert_eq!(5.0, hypotenuse(3.0f64, 4.0));
+        assert_eq!(5.0, hypotenuse(3.0f32, 4.0));
+        assert_eq!(0.5f32, f32::from_f64(0.5));
+        assert_eq!(f64::EPSILON, <f64 as ComplexField>::epsilon());
+        assert_eq!(3, sum_of_squares(&Vector::new(vec![1u8, 1, 1])));
+    }
+
+    #[test]
+    fn complex_field_test() {
+        let z = Complex::new(3.0, -4.0);
+
+        assert_eq!(5.0, ComplexField::abs(z));
+        assert_eq!(25.0, ComplexField::norm_sqr(z));
+        assert_eq!(Complex::new(3.0, 4.0), Ring::conj(z));
+        assert_eq!(Complex::new(2.0, -1.0), ComplexField::sqrt(z));
+        assert_eq!(
+            Complex::new(0.0, 1.0),
+            ComplexField::sqrt(Complex::new(-1.0, 0.0))
+        );
+        assert_eq!(Complex::new(1.5, 0.0), Complex::<f64>::from_f64(1.5));
+    }
+}
@@ -0,0 +1,161 @@
+mod common;
+
+#[cfg(test)]
+mod parallel_tests {
+    extern crate linearalgebra;
+
+    use super::common::values;
+    use linearalgebra::matrix::*;
+    use linearalgebra::parallel::*;
+    use linearalgebra::vector::*;
+    use std::panic::AssertUnwindSafe;
+
+    // Threshold zero, so even these small inputs are split up
+    fn contexts() -> Vec<ExecutionContext> {
+        let contexts = vec![
+            ExecutionContext::sequential(),
+            ExecutionContext::new(Parallelism::Global).with_threshold(0),
+            ExecutionContext::new(Parallelism::Threads(3)).with_threshold(0),
+        ];
+        #[cfg(feature = "parallel")]
+        let contexts = {
+            let mut contexts = contexts;
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(2)
+                .build()
+                .unwrap();
+            contexts.push(ExecutionContext::new(Parallelism::Pool(pool.into())).with_threshold(0));
+            contexts
+        };
+        contexts
+    }
+
+    #[test]
+    fn parallel_product_test() {
+        let a = Matrix::new(150, 130, values(150 * 130, 1)).unwrap();
+        let b = Matrix::new(140, 150, values(150 * 140, 2)).unwrap();
+        let x = Vector::new(values(150, 3));
+        let expected = ExecutionContext::sequential().install(|| {
+            (
+                a.product_matrix(&b).unwrap(),
+                a.product_matrix_with(&b, MulAlgorithm::WinogradWithCutoff(32))
+                    .unwrap(),
+                a.product_vector(&x).unwrap(),
+            )
+        });
+
+        for context in contexts() {
+            context.install(|| {
+                assert_eq!(expected.0.as_vec(), a.product_matrix(&b).unwrap().as_vec());
+                assert_eq!(
+                    expected.1.as_vec(),
+                    a.product_matrix_with(&b, MulAlgorithm::WinogradWithCutoff(32))
+                        .unwrap()
+                        .as_vec()
+                );
+                assert_eq!(expected.2.as_vec(), a.product_vector(&x).unwrap().as_vec());
+            });
+        }
+    }
+
+    #[test]
+    fn parallel_elementwise_test() {
+        // Longer than a chunk, and sums come out the same however they are split
+        let x = Vector::new(values(100_000, 4));
+        let y = Vector::new(values(100_000, 5));
+        let expected = ExecutionContext::sequential().install(|| {
+            (
+                x.add(&y).unwrap(),
+                x.scale(2.5),
+                x.sum(),
+                x.dot(&y).unwrap(),
+            )
+        });
+
+        for context in contexts() {
+            context.install(|| {
+                assert_eq!(expected.0.as_vec(), x.add(&y).unwrap().as_vec());
+                assert_eq!(expected.1.as_vec(), x.scale(2.5).as_vec());
+                assert_eq!(expected.2, x.sum());
+                assert_eq!(expected.3, x.dot(&y).unwrap());
+            });
+        }
+    }
+
+    #[test]
+    fn parallel_factorization_test() {
+        let n = 60;
+        let mut data = values(n * n, 6);
+        for k in 0..n {
+            data[k * n + k] += n as f64;
+        }
+        let a = Matrix::new(n, n, data).unwrap();
+        let spd = a.transpose().product_matrix(&a).unwrap();
+        let expected =
+            ExecutionContext::sequential().install(|| (a.lu().unwrap(), spd.cholesky().unwrap()));
+
+        for context in contexts() {
+            context.install(|| {
+                let lu = a.lu().unwrap();
+                assert_eq!(expected.0.permutation(), lu.permutation());
+                assert_eq!(expected.0.u().as_vec(), lu.u().as_vec());
+                assert_eq!(
+                    expected.1.l().as_vec(),
+                    spd.cholesky().unwrap().l().as_vec()
+                );
+            });
+        }
+    }
+
+    #[test]
+    fn install_test() {
+        let context = ExecutionContext::new(Parallelism::Threads(2)).with_threshold(10);
+        assert_eq!(
+            DEFAULT_THRESHOLD,
+            ExecutionContext::sequential().threshold()
+        );
+
+        context.install(|| {
+            assert_eq!(10, ExecutionContext::current().threshold());
+            ExecutionContext::sequential().install(|| {
+                assert!(matches!(
+                    ExecutionContext::current().parallelism(),
+                    Parallelism::Sequential
+                ));
+            });
+            assert!(matches!(
+                ExecutionContext::current().parallelism(),
+                Parallelism::Threads(2)
+            ));
+        });
+
+        // A panic inside still restores the previous context
+        ExecutionContext::sequential().install(|| {
+            let result =
+                std::panic::catch_unwind(AssertUnwindSafe(|| context.install(|| panic!())));
+            assert!(result.is_err());
+            assert!(matches!(
+                ExecutionContext::current().parallelism(),
+                Parallelism::Sequential
+            ));
+        });
+    }
+
+    #[test]
+    fn set_global_test() {
+        ExecutionContext::new(Parallelism::Threads(2))
+            .with_threshold(1 << 20)
+            .set_global();
+
+        // Seen from threads that installed nothing
+        let threshold = std::thread::spawn(|| ExecutionContext::current().threshold())
+            .join()
+            .unwrap();
+        assert_eq!(1 << 20, threshold);
+        ExecutionContext::sequential().install(|| {
+            assert_eq!(DEFAULT_THRESHOLD, ExecutionContext::current().threshold());
+        });
+
+        ExecutionContext::default().set_global();
+    }
+}
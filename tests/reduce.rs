@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod reduce_tests {
+    extern crate linearalgebra;
+
+    use linearalgebra::matrix::*;
+    use linearalgebra::vector::*;
+    use linearalgebra::Complex;
+
+    // 1 2 3
+    // 6 5 4
+    fn matrix() -> Matrix<f64> {
+        Matrix::new(3, 2, vec![1.0, 2.0, 3.0, 6.0, 5.0, 4.0]).unwrap()
+    }
+
+    fn empty() -> Matrix<f64> {
+        Matrix::new(3, 0, vec![]).unwrap()
+    }
+
+    #[test]
+    fn sum_prod_test() {
+        let matrix = matrix();
+        assert_eq!(&vec![7.0, 7.0, 7.0], matrix.sum_axis(Axis::Column).as_vec());
+        assert_eq!(&vec![6.0, 15.0], matrix.sum_axis(Axis::Row).as_vec());
+        assert_eq!(720.0, matrix.prod());
+        assert_eq!(
+            &vec![6.0, 10.0, 12.0],
+            matrix.prod_axis(Axis::Column).as_vec()
+        );
+        assert_eq!(&vec![6.0, 120.0], matrix.prod_axis(Axis::Row).as_vec());
+
+        // Nothing to add up is zero, nothing to multiply is one
+        assert_eq!(&vec![0.0; 3], empty().sum_axis(Axis::Column).as_vec());
+        assert_eq!(&vec![1.0; 3], empty().prod_axis(Axis::Column).as_vec());
+        assert!(empty().sum_axis(Axis::Row).is_empty());
+    }
+
+    #[test]
+    fn statistics_test() {
+        let matrix = matrix();
+        assert_eq!(Some(3.5), matrix.mean());
+        assert_eq!(
+            &vec![3.5, 3.5, 3.5],
+            matrix.mean_axis(Axis::Column).unwrap().as_vec()
+        );
+        assert_eq!(
+            &vec![2.0, 5.0],
+            matrix.mean_axis(Axis::Row).unwrap().as_vec()
+        );
+
+        // Population variance, divided by the count
+        assert_eq!(
+            &vec![6.25, 2.25, 0.25],
+            matrix.var_axis(Axis::Column).unwrap().as_vec()
+        );
+        assert_eq!(
+            &vec![2.5, 1.5, 0.5],
+            matrix.std_axis(Axis::Column).unwrap().as_vec()
+        );
+        assert!((matrix.var().unwrap() - 35.0 / 12.0).abs() < 1e-12);
+        assert_eq!(matrix.var().unwrap().sqrt(), matrix.std().unwrap());
+
+        // The variance of complex entries is real
+        let complex =
+            Matrix::new(2, 1, vec![Complex::new(1.0, 1.0), Complex::new(-1.0, -1.0)]).unwrap();
+        assert_eq!(Some(Complex::new(0.0, 0.0)), complex.mean());
+        assert_eq!(Some(2.0), complex.var());
+    }
+
+    #[test]
+    fn extremes_test() {
+        let matrix = matrix();
+        assert_eq!(Some(6.0), matrix.max());
+        assert_eq!(Some(1.0), matrix.min());
+        assert_eq!(
+            &vec![6.0, 5.0, 4.0],
+            matrix.max_axis(Axis::Column).unwrap().as_vec()
+        );
+        assert_eq!(
+            &vec![1.0, 4.0],
+            matrix.min_axis(Axis::Row).unwrap().as_vec()
+        );
+
+        assert_eq!(Some((1, 0)), matrix.argmax());
+        assert_eq!(Some((0, 0)), matrix.argmin());
+        assert_eq!(
+            &vec![1, 1, 1],
+            matrix.argmax_axis(Axis::Column).unwrap().as_vec()
+        );
+        assert_eq!(&vec![0, 2], matrix.argmin_axis(Axis::Row).unwrap().as_vec());
+
+        // Ties go to the first, in column-major matrices too
+        let ties = Matrix::new(2, 2, vec![3, 3, 1, 3])
+            .unwrap()
+            .to_layout(Layout::ColumnMajor);
+        assert_eq!(Some((0, 0)), ties.argmax());
+        assert_eq!(
+            &vec![0, 0],
+            ties.argmax_axis(Axis::Column).unwrap().as_vec()
+        );
+        assert_eq!(&vec![0, 1], ties.argmax_axis(Axis::Row).unwrap().as_vec());
+    }
+
+    #[test]
+    fn nan_test() {
+        let nan = f64::NAN;
+        let matrix = Matrix::new(3, 2, vec![1.0, nan, 3.0, nan, nan, 2.0]).unwrap();
+
+        // NaN propagates through the plain reductions
+        assert!(matrix.max().unwrap().is_nan());
+        assert!(matrix.min().unwrap().is_nan());
+        assert_eq!(Some((0, 1)), matrix.argmax());
+        assert!(matrix.sum().is_nan());
+        assert!(matrix.mean().unwrap().is_nan());
+        let max = matrix.max_axis(Axis::Column).unwrap();
+        assert!(max.as_vec()[0].is_nan());
+        assert_eq!(3.0, max.as_vec()[2]);
+
+        // and is skipped by the nan_ ones
+        assert_eq!(Some(3.0), matrix.nan_max());
+        assert_eq!(Some(1.0), matrix.nan_min());
+        assert_eq!(Some((0, 2)), matrix.nan_argmax());
+        assert_eq!(6.0, matrix.nan_sum());
+        assert_eq!(Some(2.0), matrix.nan_mean());
+        assert_eq!(Some(2.0 / 3.0), matrix.nan_var());
+        assert_eq!(
+            &vec![1.0, 0.0, 5.0],
+            matrix.nan_sum_axis(Axis::Column).as_vec()
+        );
+        assert_eq!(
+            &vec![2.0, 2.0],
+            matrix.nan_mean_axis(Axis::Row).unwrap().as_vec()
+        );
+        assert_eq!(
+            &vec![2, 2],
+            matrix.nan_argmax_axis(Axis::Row).unwrap().as_vec()
+        );
+        assert_eq!(
+            &vec![1.0, 0.0],
+            matrix.nan_std_axis(Axis::Row).unwrap().as_vec()
+        );
+
+        // The middle column is nothing but NaN, so it has no extreme
+        assert_eq!(None, matrix.nan_max_axis(Axis::Column));
+        assert_eq!(None, matrix.nan_argmin_axis(Axis::Column));
+        assert_eq!(None, matrix.nan_mean_axis(Axis::Column));
+    }
+
+    #[test]
+    fn empty_test() {
+        let empty = empty();
+        assert_eq!(None, empty.mean());
+        assert_eq!(None, empty.var());
+        assert_eq!(None, empty.std());
+        assert_eq!(None, empty.max());
+        assert_eq!(None, empty.min());
+        assert_eq!(None, empty.nan_max());
+        assert_eq!(None, empty.argmax());
+        assert_eq!(None, empty.max_axis(Axis::Column));
+        assert_eq!(None, empty.argmin_axis(Axis::Column));
+        assert_eq!(None, empty.mean_axis(Axis::Column));
+
+        // No rows to reduce is an empty result, not a missing one
+        assert!(empty.max_axis(Axis::Row).unwrap().is_empty());
+        assert!(empty.var_axis(Axis::Row).unwrap().is_empty());
+    }
+
+    #[test]
+    fn fold_test() {
+        let matrix = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(
+            "123456",
+            matrix.fold(String::new(), |acc, x| acc + &x.to_string())
+        );
+        assert_eq!(
+            &vec![5, 7, 9],
+            matrix
+                .fold_axis(Axis::Column, 0, |acc, &x| acc + x)
+                .as_vec()
+        );
+        assert_eq!(
+            &vec![vec![1, 2, 3], vec![4, 5, 6]],
+            matrix
+                .fold_axis(Axis::Row, Vec::new(), |mut acc, &x| {
+                    acc.push(x);
+                    acc
+                })
+                .as_vec()
+        );
+    }
+}
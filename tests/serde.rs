@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod serde_tests {
+    extern crate linearalgebra;
+
+    use linearalgebra::matrix::*;
+    use linearalgebra::vector::*;
+    use linearalgebra::Complex;
+
+    #[test]
+    fn matrix_round_trip_test() {
+        let matrix = Matrix::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let json = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(
+            r#"{"height":2,"width":3,"data":[1.0,2.0,3.0,4.0,5.0,6.0]}"#,
+            json
+        );
+
+        let back: Matrix<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!((2, 3), back.shape());
+        assert_eq!(matrix.as_vec(), back.as_vec());
+
+        // The entries are written row by row whatever the layout
+        let column_major = matrix.to_layout(Layout::ColumnMajor);
+        assert_eq!(json, serde_json::to_string(&column_major).unwrap());
+
+        let empty: Matrix<i32> =
+            serde_json::from_str(r#"{"height":0,"width":4,"data":[]}"#).unwrap();
+        assert_eq!((0, 4), empty.shape());
+
+        let complex = Matrix::new(1, 1, vec![Complex::new(1.0, -2.0)]).unwrap();
+        let back: Matrix<Complex<f64>> =
+            serde_json::from_str(&serde_json::to_string(&complex).unwrap()).unwrap();
+        assert_eq!(complex.as_vec(), back.as_vec());
+    }
+
+    #[test]
+    fn matrix_shape_mismatch_test() {
+        let err =
+            serde_json::from_str::<Matrix<f64>>(r#"{"height":2,"width":3,"data":[1,2,3,4,5]}"#)
+                .unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("invalid length 5, expected 6 entries for a 2 x 3 matrix"),
+            "{}",
+            err
+        );
+
+        let err = serde_json::from_str::<Matrix<f64>>(&format!(
+            r#"{{"height":{},"width":2,"data":[]}}"#,
+            usize::MAX
+        ))
+        .unwrap_err();
+        assert!(err.to_string().contains("too many entries"), "{}", err);
+
+        assert!(serde_json::from_str::<Matrix<f64>>(r#"{"height":1,"data":[1]}"#).is_err());
+    }
+
+    #[test]
+    fn vector_test() {
+        let vector = Vector::new(vec![1, 2, 3]);
+        let json = serde_json::to_string(&vector).unwrap();
+        assert_eq!(r#"{"size":3,"data":[1,2,3]}"#, json);
+        assert_eq!(vector, serde_json::from_str(&json).unwrap());
+
+        let err = serde_json::from_str::<Vector<i32>>(r#"{"size":3,"data":[1,2]}"#).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("invalid length 2, expected 3 entries, the size of the vector"),
+            "{}",
+            err
+        );
+    }
+}
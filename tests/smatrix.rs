@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod smatrix_tests {
+    extern crate linearalgebra;
+
+    use linearalgebra::matrix::*;
+    use linearalgebra::vector::*;
+    use linearalgebra::Error;
+
+    #[test]
+    fn construction_test() {
+        let a = SMatrix::new([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!((2, 3), a.shape());
+        assert_eq!(6, a[(1, 2)]);
+        assert_eq!(a, SMatrix::from_fn(|row, col| 3 * row + col + 1));
+        assert_eq!(SMatrix::new([[1, 4], [2, 5], [3, 6]]), a.transpose());
+        assert_eq!(SVector::new([4, 5, 6]), a.row(1));
+        assert_eq!(SVector::new([3, 6]), a.col(2));
+
+        assert_eq!(
+            SMatrix::new([[1.0, 0.0], [0.0, 1.0]]),
+            SMatrix::<f64, 2, 2>::identity()
+        );
+        assert_eq!(SMatrix::new([[0; 2]; 3]), SMatrix::<i32, 3, 2>::zeroes());
+        assert_eq!(15, SMatrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]).trace());
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        let a = SMatrix::new([[1, 2], [3, 4]]);
+        let b = SMatrix::new([[5, 6], [7, 8]]);
+        assert_eq!(SMatrix::new([[6, 8], [10, 12]]), a + b);
+        assert_eq!(SMatrix::new([[-4, -4], [-4, -4]]), a - b);
+        assert_eq!(SMatrix::new([[-1, -2], [-3, -4]]), -a);
+        assert_eq!(SMatrix::new([[2, 4], [6, 8]]), a * 2);
+        assert_eq!(a * 2, 2 * a);
+        assert_eq!(SMatrix::new([[5, 12], [21, 32]]), a.hadamard(&b));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        c *= 3;
+        assert_eq!(b * 3, c);
+
+        let v = SVector::new([1.0, 2.0, 3.0]);
+        let w = SVector::new([4.0, 5.0, 6.0]);
+        assert_eq!(32.0, v.dot(&w));
+        assert_eq!(SVector::new([5.0, 7.0, 9.0]), v + w);
+        assert_eq!(SVector::new([2.0, 4.0, 6.0]), 2.0 * v);
+        assert_eq!(6.0, v.sum());
+    }
+
+    #[test]
+    fn product_test() {
+        // A 2 x 3 by 3 x 2 product gives a 2 x 2 matrix
+        let a = SMatrix::new([[1, 2, 3], [4, 5, 6]]);
+        let b = SMatrix::new([[7, 8], [9, 10], [11, 12]]);
+        assert_eq!(SMatrix::new([[58, 64], [139, 154]]), a * b);
+        assert_eq!(
+            SMatrix::new([[39, 54, 69], [49, 68, 87], [59, 82, 105]]),
+            b * a
+        );
+        assert_eq!(SVector::new([14, 32]), a * SVector::new([1, 2, 3]));
+
+        let mut square = SMatrix::new([[1, 1], [0, 1]]);
+        square *= SMatrix::new([[1, 1], [0, 1]]);
+        assert_eq!(SMatrix::new([[1, 2], [0, 1]]), square);
+
+        // The same product as the dynamic matrices give, up to 6 x 6
+        let a = SMatrix::<f64, 6, 6>::from_fn(|row, col| (row * 6 + col) as f64);
+        let b = SMatrix::<f64, 6, 6>::from_fn(|row, col| row as f64 - col as f64);
+        let product = a.to_matrix().product_matrix(&b.to_matrix()).unwrap();
+        assert_eq!(product.as_vec(), (a * b).to_matrix().as_vec());
+    }
+
+    #[test]
+    fn conversion_test() {
+        let a = SMatrix::new([[1, 2, 3], [4, 5, 6]]);
+        let matrix = Matrix::from(a);
+        assert_eq!((2, 3), matrix.shape());
+        assert_eq!(&vec![1, 2, 3, 4, 5, 6], matrix.as_vec());
+        assert_eq!(Ok(a), SMatrix::try_from(&matrix));
+
+        // Any layout and any view of the right shape converts
+        let column_major = matrix.to_layout(Layout::ColumnMajor);
+        assert_eq!(Ok(a), SMatrix::try_from(&column_major));
+        assert_eq!(
+            Ok(a.transpose()),
+            SMatrix::try_from(matrix.as_view().transpose())
+        );
+
+        assert_eq!(
+            Err(Error::ShapeMismatch {
+                expected: (3, 2),
+                found: (2, 3)
+            }),
+            SMatrix::<i32, 3, 2>::try_from(&matrix)
+        );
+
+        // A static matrix is a view too, so it mixes with dynamic ones
+        assert_eq!(&vec![2, 4, 6, 8, 10, 12], matrix.add(&a).unwrap().as_vec());
+
+        let v = SVector::new([1, 2, 3]);
+        let vector = Vector::from(v);
+        assert_eq!(&vec![1, 2, 3], vector.as_vec());
+        assert_eq!(Ok(v), SVector::try_from(&vector));
+        assert_eq!(Ok(v), SVector::try_from(vector.clone()));
+        assert_eq!(
+            Err(Error::DimensionMismatch {
+                expected: 2,
+                found: 3
+            }),
+            SVector::<i32, 2>::try_from(vector)
+        );
+    }
+}
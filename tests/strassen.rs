@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod strassen_tests {
+    extern crate linearalgebra;
+
+    use linearalgebra::matrix::*;
+    use linearalgebra::Error;
+
+    const ALGORITHMS: [MulAlgorithm; 4] = [
+        MulAlgorithm::StrassenWithCutoff(1),
+        MulAlgorithm::StrassenWithCutoff(8),
+        MulAlgorithm::WinogradWithCutoff(1),
+        MulAlgorithm::WinogradWithCutoff(8),
+    ];
+
+    // Integers, so every algorithm has to agree exactly
+    fn matrix(height: usize, width: usize, seed: i64) -> Matrix<i64> {
+        let mut counter = seed;
+        Matrix::new_of_supplier(width, height, || {
+            counter += 1;
+            (counter * 7) % 11 - 5
+        })
+    }
+
+    #[test]
+    fn strassen_square_test() {
+        for n in [1, 2, 4, 16, 64] {
+            let (a, b) = (matrix(n, n, 0), matrix(n, n, 3));
+            let expected = a.product_matrix(&b).unwrap();
+            for algorithm in ALGORITHMS {
+                assert_eq!(
+                    expected.as_vec(),
+                    a.product_matrix_with(&b, algorithm).unwrap().as_vec(),
+                    "{:?} {}",
+                    algorithm,
+                    n
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn strassen_padding_test() {
+        // Odd and unequal sides are padded up to an even split at every level
+        for (m, k, n) in [(3, 5, 7), (17, 33, 20), (45, 37, 51)] {
+            let (a, b) = (matrix(m, k, 1), matrix(k, n, 2));
+            let expected = a.product_matrix(&b).unwrap();
+            for algorithm in ALGORITHMS {
+                let product = a.product_matrix_with(&b, algorithm).unwrap();
+                assert_eq!((m, n), product.shape());
+                assert_eq!(expected.as_vec(), product.as_vec(), "{:?}", algorithm);
+            }
+        }
+    }
+
+    #[test]
+    fn strassen_default_cutoff_test() {
+        let (a, b) = (matrix(300, 300, 4), matrix(300, 300, 5));
+        let expected = a.product_matrix(&b).unwrap();
+
+        for algorithm in [
+            MulAlgorithm::Blocked,
+            MulAlgorithm::Strassen,
+            MulAlgorithm::Winograd,
+        ] {
+            assert_eq!(
+                expected.as_vec(),
+                a.product_matrix_with(&b, algorithm).unwrap().as_vec()
+            );
+        }
+    }
+
+    #[test]
+    fn strassen_views_test() {
+        let (a, b) = (matrix(40, 30, 6), matrix(40, 30, 7));
+        let a_t = a.transpose_view();
+        let expected = a.transpose().product_matrix(&b).unwrap();
+
+        for algorithm in ALGORITHMS {
+            assert_eq!(
+                expected.as_vec(),
+                a_t.product_matrix_with(&b, algorithm).unwrap().as_vec()
+            );
+        }
+
+        let column_major = b.to_layout(Layout::ColumnMajor);
+        assert_eq!(
+            expected.as_vec(),
+            a.transpose()
+                .product_matrix_with(&column_major, MulAlgorithm::WinogradWithCutoff(4))
+                .unwrap()
+                .as_vec()
+        );
+    }
+
+    #[test]
+    fn strassen_float_test() {
+        let n = 100;
+        let a = Matrix::new_of_supplier(n, n, || 0.5);
+        let b = Matrix::new(n, n, (0..n * n).map(|i| (i % 9) as f64).collect()).unwrap();
+        let expected = a.product_matrix(&b).unwrap();
+
+        for algorithm in ALGORITHMS {
+            let product = a.product_matrix_with(&b, algorithm).unwrap();
+            for (x, y) in expected.as_vec().iter().zip(product.as_vec()) {
+                assert!((x - y).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn strassen_mismatch_test() {
+        assert_eq!(
+            Error::DimensionMismatch {
+                expected: 3,
+                found: 2
+            },
+            matrix(2, 3, 0)
+                .product_matrix_with(&matrix(2, 3, 0), MulAlgorithm::Strassen)
+                .unwrap_err()
+        );
+    }
+}
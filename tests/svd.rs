@@ -0,0 +1,188 @@
+mod common;
+
+#[cfg(test)]
+mod svd_tests {
+    extern crate linearalgebra;
+
+    use super::common::{assert_close, TOLERANCE};
+    use linearalgebra::matrix::*;
+
+    fn assert_orthonormal_columns(matrix: &Matrix<f64>) {
+        assert_close(
+            Matrix::<f64>::identity(matrix.width()).as_vec(),
+            matrix.transpose().product_matrix(matrix).unwrap().as_vec(),
+        );
+    }
+
+    fn tall() -> Matrix<f64> {
+        Matrix::new(
+            3,
+            4,
+            vec![2.0, 0.0, 1.0, -1.0, 3.0, 0.0, 4.0, 1.0, -2.0, 0.0, 2.0, 5.0],
+        )
+        .unwrap()
+    }
+
+    // The third column is the sum of the first two
+    fn rank_deficient() -> Matrix<f64> {
+        Matrix::new(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 9.0, 7.0, 8.0, 15.0]).unwrap()
+    }
+
+    #[test]
+    fn svd_reconstruct_test() {
+        let matrix = tall();
+        let svd = matrix.svd().unwrap();
+
+        assert_eq!((4, 3), svd.u().shape());
+        assert_eq!((3, 3), svd.v().shape());
+        assert_orthonormal_columns(svd.u());
+        assert_orthonormal_columns(svd.v());
+        for pair in svd.singular_values().as_vec().windows(2) {
+            assert!(pair[0] >= pair[1]);
+        }
+
+        let reconstructed = svd
+            .u()
+            .product_matrix(&svd.sigma())
+            .unwrap()
+            .product_matrix(&svd.v_transpose())
+            .unwrap();
+        assert_close(matrix.as_vec(), reconstructed.as_vec());
+    }
+
+    #[test]
+    fn svd_full_test() {
+        let matrix = tall().transpose();
+        let svd = matrix.svd_full().unwrap();
+
+        assert_eq!((3, 3), svd.u().shape());
+        assert_eq!((3, 4), svd.sigma().shape());
+        assert_eq!((4, 4), svd.v().shape());
+        assert_orthonormal_columns(svd.u());
+        assert_orthonormal_columns(svd.v());
+
+        let reconstructed = svd
+            .u()
+            .product_matrix(&svd.sigma())
+            .unwrap()
+            .product_matrix(&svd.v_transpose())
+            .unwrap();
+        assert_close(matrix.as_vec(), reconstructed.as_vec());
+    }
+
+    #[test]
+    fn singular_values_test() {
+        let matrix = Matrix::new(2, 2, vec![3.0, 0.0, 4.0, 5.0]).unwrap();
+
+        assert_close(
+            &[45f64.sqrt(), 5f64.sqrt()],
+            matrix.singular_values().unwrap().as_vec(),
+        );
+    }
+
+    #[test]
+    fn svd_f32_test() {
+        let matrix: Matrix<f32> = Matrix::new(2, 2, vec![3.0, 0.0, 4.0, 5.0]).unwrap();
+        let values = matrix.singular_values().unwrap();
+
+        assert!((values.as_vec()[0] - 45f32.sqrt()).abs() < 1e-5);
+        assert!((values.as_vec()[1] - 5f32.sqrt()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn rank_test() {
+        assert_eq!(3, tall().rank(1e-10).unwrap());
+        assert_eq!(2, rank_deficient().rank(1e-10).unwrap());
+        assert_eq!(
+            0,
+            Matrix::new_of_element(2, 3, 0.0)
+                .unwrap()
+                .rank(1e-10)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn condition_number_test() {
+        let matrix: Matrix<f64> = Matrix::new(2, 2, vec![2.0, 0.0, 0.0, 0.5]).unwrap();
+
+        assert!((matrix.condition_number().unwrap() - 4.0).abs() < TOLERANCE);
+        assert!(rank_deficient().condition_number().unwrap() > 1e12);
+    }
+
+    #[test]
+    fn pseudo_inverse_test() {
+        let matrix = tall();
+        let pinv = matrix.pseudo_inverse().unwrap();
+
+        // Full column rank, so the pseudoinverse is a left inverse
+        assert_eq!((3, 4), pinv.shape());
+        assert_close(
+            Matrix::<f64>::identity(3).as_vec(),
+            pinv.product_matrix(&matrix).unwrap().as_vec(),
+        );
+    }
+
+    #[test]
+    fn pseudo_inverse_rank_deficient_test() {
+        let matrix = rank_deficient();
+        let pinv = matrix.pseudo_inverse().unwrap();
+
+        assert_close(
+            matrix.as_vec(),
+            matrix
+                .product_matrix(&pinv)
+                .unwrap()
+                .product_matrix(&matrix)
+                .unwrap()
+                .as_vec(),
+        );
+        assert_close(
+            pinv.as_vec(),
+            pinv.product_matrix(&matrix)
+                .unwrap()
+                .product_matrix(&pinv)
+                .unwrap()
+                .as_vec(),
+        );
+    }
+
+    #[test]
+    fn null_space_test() {
+        let matrix = rank_deficient();
+        let null_space = matrix.null_space(1e-10).unwrap();
+
+        assert_eq!((3, 1), null_space.shape());
+        assert_close(
+            &[0.0, 0.0, 0.0],
+            matrix.product_matrix(&null_space).unwrap().as_vec(),
+        );
+
+        let wide = Matrix::new(3, 1, vec![1.0, 1.0, 1.0]).unwrap();
+        let null_space = wide.null_space(1e-10).unwrap();
+        assert_eq!((3, 2), null_space.shape());
+        assert_orthonormal_columns(&null_space);
+        assert_close(
+            &[0.0, 0.0],
+            wide.product_matrix(&null_space).unwrap().as_vec(),
+        );
+    }
+
+    #[test]
+    fn column_space_test() {
+        let matrix = rank_deficient();
+        let column_space = matrix.column_space(1e-10).unwrap();
+
+        assert_eq!((3, 2), column_space.shape());
+        assert_orthonormal_columns(&column_space);
+
+        // Projecting the columns onto the column space leaves them unchanged
+        let projection = column_space
+            .product_matrix(&column_space.transpose())
+            .unwrap();
+        assert_close(
+            matrix.as_vec(),
+            projection.product_matrix(&matrix).unwrap().as_vec(),
+        );
+    }
+}
@@ -3,6 +3,7 @@ mod vector_tests {
     extern crate linearalgebra;
 
     use linearalgebra::vector::*;
+    use linearalgebra::Error;
 
     #[test]
     fn length_test() {
@@ -23,7 +24,13 @@ mod vector_tests {
         let v = Vector::new(vec![3, 4, 5]);
         let v2 = Vector::new(vec![1, 3, 9, 5, 1, 12, 3, 12, 12, 33, 12, 3, 12]);
 
-        assert_eq!(v.add(&v2).is_err(), true);
+        assert_eq!(
+            v.add(&v2).unwrap_err(),
+            Error::DimensionMismatch {
+                expected: 3,
+                found: 13
+            }
+        );
     }
 
     #[test]
@@ -41,7 +48,13 @@ mod vector_tests {
         let v = Vector::new(vec![3, 4, 5]);
         let v2 = Vector::new(vec![1, 3, 9, 5, 1, 12, 3, 12, 12, 33, 12, 3, 12]);
 
-        assert_eq!(v.sub(&v2).is_err(), true);
+        assert_eq!(
+            v.sub(&v2).unwrap_err(),
+            Error::DimensionMismatch {
+                expected: 3,
+                found: 13
+            }
+        );
     }
 
     #[test]
@@ -79,6 +92,13 @@ mod vector_tests {
     fn unit_vector_fail_test() {
         let v = Vector::<i32>::cartesian_unit_vector(6, 5);
 
+        assert_eq!(v.unwrap_err(), Error::IndexOutOfBounds { index: 6, bound: 5 })
+    }
+
+    #[test]
+    fn unit_vector_zero_fail_test() {
+        let v = Vector::<i32>::cartesian_unit_vector(0, 5);
+
         assert!(v.is_err())
     }
 
@@ -143,4 +163,50 @@ mod vector_tests {
 
         assert_eq!(&vec![10, 10, 10, 10, 10, 10, 10, 10, 10, 10], v.as_vec());
     }
-}
+
+    #[test]
+    fn add_sub_operator_test() {
+        let v = Vector::new(vec![3, 4, 5]);
+        let v2 = Vector::new(vec![1, 3, 9]);
+
+        assert_eq!(&v + &v2, Vector::new(vec![4, 7, 14]));
+        assert_eq!(&v - v2.clone(), Vector::new(vec![2, 1, -4]));
+        assert_eq!(v2 - v, Vector::new(vec![-2, -1, 4]));
+    }
+
+    #[test]
+    fn assign_operators_test() {
+        let mut v = Vector::new(vec![3, 4, 5]);
+
+        v += Vector::new(vec![1, 1, 1]);
+        assert_eq!(&vec![4, 5, 6], v.as_vec());
+        v -= &Vector::new(vec![2, 2, 2]);
+        assert_eq!(&vec![2, 3, 4], v.as_vec());
+        v *= 3;
+        assert_eq!(&vec![6, 9, 12], v.as_vec());
+    }
+
+    #[test]
+    fn scalar_operator_test() {
+        let v = Vector::new(vec![3.0, 4.0]);
+
+        assert_eq!(&v * 2.0, Vector::new(vec![6.0, 8.0]));
+        assert_eq!(0.5 * v, Vector::new(vec![1.5, 2.0]));
+    }
+
+    #[test]
+    fn neg_operator_test() {
+        let v = Vector::new(vec![3, -4, 0]);
+
+        assert_eq!(-v, Vector::new(vec![-3, 4, 0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Dimensions don't match: expected 3, found 4")]
+    fn add_operator_mismatched_length_test() {
+        let v = Vector::new(vec![3, 4, 5]);
+        let v2 = Vector::new(vec![1, 3, 9, 1]);
+
+        let _ = v + v2;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,202 @@
+#[cfg(test)]
+mod view_tests {
+    extern crate linearalgebra;
+
+    use linearalgebra::matrix::*;
+    use linearalgebra::vector::*;
+    use linearalgebra::Error;
+
+    // 3 x 4 with entry (row, col) = 10 * row + col
+    fn matrix() -> Matrix<i32> {
+        Matrix::new(4, 3, vec![0, 1, 2, 3, 10, 11, 12, 13, 20, 21, 22, 23]).unwrap()
+    }
+
+    #[test]
+    fn view_test() {
+        let matrix = matrix();
+        let view = matrix.view(1..3, 1..=2).unwrap();
+
+        assert_eq!((2, 2), view.shape());
+        assert_eq!(11, view[(0, 0)]);
+        assert_eq!(22, view[(1, 1)]);
+        assert_eq!(&vec![11, 12, 21, 22], view.to_matrix().as_vec());
+        assert_eq!(None, view.get(2, 0));
+
+        let all = matrix.view(.., ..).unwrap();
+        assert!(all.is_contiguous());
+        assert!(!view.is_contiguous());
+        assert_eq!(matrix.as_vec(), all.to_matrix().as_vec());
+    }
+
+    #[test]
+    fn view_of_view_test() {
+        let matrix = matrix();
+        let view = matrix.view(1.., 1..).unwrap();
+        let inner = view.view(1..2, ..2).unwrap();
+
+        assert_eq!(&vec![21, 22], inner.to_matrix().as_vec());
+    }
+
+    #[test]
+    fn view_out_of_bounds_test() {
+        let matrix = matrix();
+
+        assert_eq!(
+            Error::IndexOutOfBounds { index: 3, bound: 3 },
+            matrix.view(0..4, ..).unwrap_err()
+        );
+        assert_eq!(
+            Error::IndexOutOfBounds { index: 4, bound: 4 },
+            matrix.col_view(4).unwrap_err()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 2 is out of bounds for length 2")]
+    fn view_index_panic_test() {
+        let matrix = matrix();
+        let view = matrix.view(.., 0..2).unwrap();
+
+        // Within the matrix, but not the view
+        let _ = view[(0, 2)];
+    }
+
+    #[test]
+    fn transpose_view_test() {
+        let matrix = matrix();
+        let transposed = matrix.transpose_view();
+
+        assert_eq!((4, 3), transposed.shape());
+        assert_eq!(12, transposed[(2, 1)]);
+        assert_eq!(matrix.transpose().as_vec(), transposed.to_matrix().as_vec());
+        assert_eq!(
+            &vec![2, 12],
+            transposed.view(2..3, ..2).unwrap().to_matrix().as_vec()
+        );
+    }
+
+    #[test]
+    fn row_col_view_test() {
+        let matrix = matrix();
+
+        assert_eq!(
+            &vec![10, 11, 12, 13],
+            matrix.row_view(1).unwrap().to_matrix().as_vec()
+        );
+        assert_eq!(
+            &vec![3, 13, 23],
+            matrix.col_view(3).unwrap().to_matrix().as_vec()
+        );
+        assert_eq!((3, 1), matrix.col_view(3).unwrap().shape());
+    }
+
+    #[test]
+    fn view_mut_test() {
+        let mut matrix = matrix();
+        {
+            let mut view = matrix.view_mut(.., 1..3).unwrap();
+            view[(0, 0)] = -1;
+            view.view_mut(2..3, ..).unwrap().fill(0);
+            view *= 2;
+        }
+
+        assert_eq!(
+            &vec![0, -2, 4, 3, 10, 22, 24, 13, 20, 0, 0, 23],
+            matrix.as_vec()
+        );
+    }
+
+    #[test]
+    fn view_mut_transpose_test() {
+        let mut matrix = Matrix::new(2, 2, vec![1, 2, 3, 4]).unwrap();
+        let source = Matrix::new(2, 2, vec![5, 6, 7, 8]).unwrap();
+        matrix.as_view_mut().transpose().copy_from(&source).unwrap();
+
+        assert_eq!(&vec![5, 7, 6, 8], matrix.as_vec());
+        assert_eq!(
+            Error::ShapeMismatch {
+                expected: (2, 2),
+                found: (1, 2)
+            },
+            matrix
+                .as_view_mut()
+                .copy_from(&source.row_view(0).unwrap())
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn view_arithmetic_test() {
+        let matrix = matrix();
+        let left = matrix.view(.., 0..2).unwrap();
+        let right = matrix.view(.., 2..4).unwrap();
+        let ones = Matrix::new_of_element(2, 3, 1).unwrap();
+
+        assert_eq!(&vec![2, 4, 22, 24, 42, 44], (left + right).as_vec());
+        assert_eq!(&vec![-2, -2, -2, -2, -2, -2], (left - right).as_vec());
+        assert_eq!(&vec![1, 2, 11, 12, 21, 22], (left + &ones).as_vec());
+        assert_eq!(&vec![1, 0, -9, -10, -19, -20], (&ones - left).as_vec());
+        assert_eq!(
+            left.add(&right).unwrap().as_vec(),
+            ones.scale(0)
+                .add(&left)
+                .unwrap()
+                .add(&right)
+                .unwrap()
+                .as_vec()
+        );
+        assert_eq!(
+            Error::ShapeMismatch {
+                expected: (3, 4),
+                found: (3, 2)
+            },
+            matrix.add(&left).unwrap_err()
+        );
+
+        let mut accumulator = ones.clone();
+        accumulator += left;
+        accumulator -= right;
+        assert_eq!(&vec![-1, -1, -1, -1, -1, -1], accumulator.as_vec());
+        assert_eq!(&vec![0, -1, -10, -11, -20, -21], (-left).as_vec());
+        assert_eq!(&vec![0, 3, 30, 33, 60, 63], (left * 3).as_vec());
+    }
+
+    #[test]
+    fn view_product_test() {
+        let matrix = matrix();
+        let block = matrix.view(..2, ..2).unwrap();
+
+        // [[0, 1], [10, 11]] squared
+        assert_eq!(&vec![10, 11, 110, 131], (block * block).as_vec());
+        assert_eq!(
+            (block * block).as_vec(),
+            block.to_matrix().product_matrix(&block).unwrap().as_vec()
+        );
+
+        // A^T A through a transposed view never copies A itself
+        let gram = matrix.transpose_view() * &matrix;
+        assert_eq!(
+            matrix.transpose().product_matrix(&matrix).unwrap().as_vec(),
+            gram.as_vec()
+        );
+        assert_eq!(
+            Error::DimensionMismatch {
+                expected: 4,
+                found: 3
+            },
+            matrix.product_matrix(&matrix.as_view()).unwrap_err()
+        );
+
+        let vector = Vector::new(vec![1, 1]);
+        assert_eq!(&vec![1, 21], (block * &vector).as_vec());
+        assert_eq!(
+            &vec![3, 23, 43],
+            matrix
+                .view(.., 1..3)
+                .unwrap()
+                .product_vector(&vector)
+                .unwrap()
+                .as_vec()
+        );
+    }
+}